@@ -1,14 +1,89 @@
 /// Surge unified client
 ///
-/// Application layer - Coordinates three infrastructure layers: HTTP/CLI/System
-/// Implements fallback strategy: HTTP API → CLI → System
-use crate::config::Config;
+/// Application layer - Coordinates the backends (HTTP API, surge-cli) and the system client
+/// Each operation goes to the first backend supporting it (HTTP API, then CLI);
+/// the CLI takes over entirely when the HTTP API is unreachable
+use crate::config::{Config, Recording, TestingConfig};
 use crate::domain::{
-    entities::{Alert, AppSnapshot},
+    backend::{unsupported, Operation, SurgeBackend},
+    entities::{Alert, AppSnapshot, TrafficHistory},
     errors::{Result, SurgeError},
     models::*,
+    profile,
 };
-use crate::infrastructure::{SurgeCliClient, SurgeHttpClient, SurgeSystemClient};
+use crate::infrastructure::{
+    certificate, Recorder, Replayer, SurgeCliClient, SurgeHttpClient, SurgeSystemClient, TlsOptions,
+};
+use futures::stream::{self, StreamExt};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Maximum concurrent requests when fanning out per-group lookups
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// How long a cached policy group selection is trusted before re-fetching
+///
+/// Selections made through this client update the cache immediately; the TTL
+/// only bounds how long changes made elsewhere (Surge UI, other clients) take to show
+const SELECTION_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// How often feature switches are re-read (changes made here update the cache
+/// immediately)
+const FEATURE_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a failed MITM CA download is remembered before retrying
+/// (a manual refresh retries right away)
+const MITM_CA_RETRY_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Polls for the Surge process to exit before restarting it (10 × 500 ms)
+const RESTART_WAIT_STEPS: usize = 10;
+const RESTART_WAIT_STEP: Duration = Duration::from_millis(500);
+
+/// How long a new error event stays in the alert bar
+const EVENT_ALERT_DURATION: Duration = Duration::from_secs(30);
+
+/// Cached selected policy of a policy group
+#[derive(Debug, Clone)]
+struct CachedSelection {
+    policy: Option<String>,
+    fetched_at: Instant,
+}
+
+/// Events already fetched
+#[derive(Debug, Default)]
+struct SeenEvents {
+    /// When each event was first fetched (key: identifier); `None` for events
+    /// that were already there at the first fetch
+    first_seen: HashMap<String, Option<Instant>>,
+    /// Whether the first fetch happened
+    seeded: bool,
+}
+
+/// Last known feature switches
+#[derive(Debug, Default)]
+struct FeatureCache {
+    states: BTreeMap<Feature, bool>,
+    fetched_at: Option<Instant>,
+}
+
+/// Last MITM CA download
+#[derive(Debug, Default)]
+struct MitmCaCache {
+    certificate: Option<CaCertificate>,
+    /// When the last download failed
+    failed_at: Option<Instant>,
+}
+
+/// Traffic samples shared between clones
+#[derive(Debug, Default)]
+struct TrafficTracker {
+    history: TrafficHistory,
+    /// Byte counters (in, out) of active connections at the last sample (key: id)
+    connections: HashMap<u64, (u64, u64)>,
+    sampled_at: Option<Instant>,
+}
 
 /// Client mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,302 +97,1297 @@ pub enum ClientMode {
 /// Surge unified client
 #[derive(Clone)]
 pub struct SurgeClient {
-    mode: ClientMode,
-    http_client: SurgeHttpClient,
-    cli_client: SurgeCliClient,
-    system_client: SurgeSystemClient,
+    /// Detected mode, shared between clones (the background poller detects it,
+    /// UI actions on other clones follow it)
+    mode: Arc<Mutex<ClientMode>>,
+    /// Remote host: HTTP API only, no local CLI/system fallbacks
+    remote: bool,
+    /// Policy group selections shared between clones (key: group name)
+    selection_cache: Arc<Mutex<HashMap<String, CachedSelection>>>,
+    /// Events already fetched, shared between clones
+    seen_events: Arc<Mutex<SeenEvents>>,
+    /// Throughput ring buffer, shared between clones
+    traffic: Arc<Mutex<TrafficTracker>>,
+    /// MITM CA certificate, fetched once and shared between clones
+    mitm_ca: Arc<Mutex<MitmCaCache>>,
+    /// Feature switches, re-read every `FEATURE_REFRESH_INTERVAL`
+    features: Arc<Mutex<FeatureCache>>,
+    /// Polled endpoints this Surge version answers with 404 (no longer polled)
+    missing_endpoints: Arc<Mutex<HashSet<Operation>>>,
+    /// Preferred backend (HTTP API)
+    primary: Arc<dyn SurgeBackend>,
+    /// Fallback backend (surge-cli), absent in remote mode
+    fallback: Option<Arc<dyn SurgeBackend>>,
+    /// Local process control, absent in remote mode
+    system_client: Option<SurgeSystemClient>,
+    /// Latency test settings (`[testing]`)
+    testing: TestingConfig,
 }
 
 impl SurgeClient {
     /// Create new client
-    pub fn new(config: Config) -> Self {
+    ///
+    /// Fails when the API key is missing (except when replaying), the TLS
+    /// settings are invalid (bad fingerprint or CA file) or the recording
+    /// directory cannot be used
+    pub fn new(config: Config) -> Result<Self> {
+        // A replayed session needs no key
+        let replay = matches!(config.recording, Some(Recording::Replay(_)));
+        if config.surge.http_api_key.is_empty() && !replay {
+            return Err(SurgeError::ConfigError {
+                message: "HTTP API key not configured".to_string(),
+            });
+        }
+
+        let tls = config.surge.tls.then(|| TlsOptions {
+            fingerprint: config.surge.tls_fingerprint.clone(),
+            ca_file: config.surge.tls_ca_file.clone(),
+        });
+
         let http_client = SurgeHttpClient::new(
             config.surge.http_api_host.clone(),
             config.surge.http_api_port,
             config.surge.http_api_key.clone(),
-        );
+            tls,
+        )?;
+        let http_client = match config.recording {
+            Some(Recording::Record(ref dir)) => http_client.with_recorder(Recorder::create(dir)?),
+            Some(Recording::Replay(ref dir)) => http_client.with_replayer(Replayer::load(dir)?),
+            None => http_client,
+        };
 
-        let cli_client = SurgeCliClient::new(config.surge.cli_path.clone());
-        let system_client = SurgeSystemClient::new();
+        // Remote hosts have no usable CLI/system fallback: surge-cli, pgrep and
+        // open would act on the local machine instead of the target Mac.
+        // A replayed session has no live Surge at all, so it is treated the same way.
+        let remote = config.surge.is_remote() || replay;
+        let (fallback, system_client) = if remote {
+            (None, None)
+        } else {
+            let cli_client: Arc<dyn SurgeBackend> =
+                Arc::new(SurgeCliClient::new(config.surge.cli_path.clone()));
+            (Some(cli_client), Some(SurgeSystemClient::new()))
+        };
 
-        Self {
-            mode: ClientMode::HttpApi, // Default to HTTP API
-            http_client,
-            cli_client,
+        Ok(Self {
+            mode: Arc::new(Mutex::new(ClientMode::HttpApi)), // Default to HTTP API
+            remote,
+            selection_cache: Arc::new(Mutex::new(HashMap::new())),
+            seen_events: Arc::new(Mutex::new(SeenEvents::default())),
+            traffic: Arc::new(Mutex::new(TrafficTracker::default())),
+            mitm_ca: Arc::new(Mutex::new(MitmCaCache::default())),
+            features: Arc::new(Mutex::new(FeatureCache::default())),
+            missing_endpoints: Arc::new(Mutex::new(HashSet::new())),
+            primary: Arc::new(http_client),
+            fallback,
             system_client,
+            testing: config.testing,
+        })
+    }
+
+    /// Create a client over a single backend (e.g. `MemoryBackend`)
+    ///
+    /// Behaves like remote mode: liveness comes from the backend and there is
+    /// no fallback or local process control
+    pub fn with_backend(backend: Arc<dyn SurgeBackend>) -> Self {
+        Self {
+            mode: Arc::new(Mutex::new(ClientMode::HttpApi)),
+            remote: true,
+            selection_cache: Arc::new(Mutex::new(HashMap::new())),
+            seen_events: Arc::new(Mutex::new(SeenEvents::default())),
+            traffic: Arc::new(Mutex::new(TrafficTracker::default())),
+            mitm_ca: Arc::new(Mutex::new(MitmCaCache::default())),
+            features: Arc::new(Mutex::new(FeatureCache::default())),
+            missing_endpoints: Arc::new(Mutex::new(HashSet::new())),
+            primary: backend,
+            fallback: None,
+            system_client: None,
+            testing: TestingConfig::default(),
         }
     }
 
+    /// Add a fallback backend behind the primary one (e.g. a `MemoryBackend`
+    /// restricted to surge-cli's operations)
+    pub fn with_fallback(mut self, fallback: Arc<dyn SurgeBackend>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Use these latency test settings
+    pub fn with_testing(mut self, testing: TestingConfig) -> Self {
+        self.testing = testing;
+        self
+    }
+
     /// Get current mode
     pub fn mode(&self) -> ClientMode {
-        self.mode
+        *self.mode.lock().unwrap()
+    }
+
+    /// Whether connected to a remote host
+    pub fn is_remote(&self) -> bool {
+        self.remote
     }
 
     /// Detect and switch to best available mode
-    pub async fn detect_mode(&mut self) -> ClientMode {
-        // Try HTTP API first
-        if self.http_client.is_available().await {
-            self.mode = ClientMode::HttpApi;
+    pub async fn detect_mode(&self) -> ClientMode {
+        // Without a fallback there is nothing to switch to
+        let mode = if self.fallback.is_none() || self.primary.is_available().await {
+            // Try HTTP API first
+            ClientMode::HttpApi
         } else {
             // Fallback to CLI
-            self.mode = ClientMode::Cli;
+            ClientMode::Cli
+        };
+        *self.mode.lock().unwrap() = mode;
+        mode
+    }
+
+    /// Backends usable in the current mode, in fallback order
+    fn backends(&self) -> Vec<&Arc<dyn SurgeBackend>> {
+        match self.mode() {
+            ClientMode::HttpApi => std::iter::once(&self.primary)
+                .chain(self.fallback.as_ref())
+                .collect(),
+            // HTTP API is unreachable, only the fallback is tried
+            ClientMode::Cli => self.fallback.iter().collect(),
         }
-        self.mode
+    }
+
+    /// First backend able to perform the operation in the current mode
+    fn backend_for(&self, operation: Operation) -> Result<&Arc<dyn SurgeBackend>> {
+        let backends = self.backends();
+        backends
+            .iter()
+            .find(|b| b.supports(operation))
+            .copied()
+            .ok_or_else(|| {
+                let name = backends.first().map_or("none", |b| b.name());
+                unsupported(name, operation)
+            })
+    }
+
+    /// Whether the operation is available in the current mode
+    pub fn supports(&self, operation: Operation) -> bool {
+        self.backend_for(operation).is_ok()
+    }
+
+    /// Operations available in the current mode
+    pub fn capabilities(&self) -> Vec<Operation> {
+        let mut operations: Vec<Operation> = Vec::new();
+        for backend in self.backends() {
+            for operation in backend.capabilities() {
+                if !operations.contains(operation) {
+                    operations.push(*operation);
+                }
+            }
+        }
+        operations
     }
 
     /// Get application snapshot (includes all states and alerts)
-    pub async fn get_snapshot(&mut self) -> AppSnapshot {
+    pub async fn get_snapshot(&self) -> AppSnapshot {
         let mut snapshot = AppSnapshot::new();
+        snapshot.remote_mode = self.remote;
 
-        // 1. Check Surge process
-        snapshot.surge_running = self.system_client.is_surge_running().await;
+        // 1. Check Surge process (remote: liveness comes from the HTTP API)
+        snapshot.surge_running = self.is_surge_running().await;
         if !snapshot.surge_running {
-            snapshot.add_alert(Alert::surge_not_running());
+            if self.remote {
+                snapshot.add_alert(Alert::surge_unreachable());
+            } else {
+                snapshot.add_alert(Alert::surge_not_running());
+            }
             return snapshot;
         }
 
         // 2. Detect best mode
         self.detect_mode().await;
+        snapshot.capabilities = self.capabilities();
 
         // 3. Check HTTP API availability
-        snapshot.http_api_available = matches!(self.mode, ClientMode::HttpApi);
+        snapshot.http_api_available = matches!(self.mode(), ClientMode::HttpApi);
         if !snapshot.http_api_available {
             snapshot.add_alert(Alert::http_api_disabled());
         }
 
-        // 4. Get outbound mode
-        if let Ok(mode) = self.get_outbound_mode().await {
-            snapshot.outbound_mode = Some(mode);
-        }
+        // 4. Fetch everything concurrently (a refresh costs about one round trip).
+        // Unsupported operations fail immediately without a request.
+        // Selections of known groups with an expired cache entry are refreshed alongside.
+        let stale_groups = self.stale_selection_groups();
+        let (
+            outbound,
+            global,
+            features,
+            mitm_ca,
+            groups,
+            rules,
+            recent,
+            active,
+            dns,
+            modules,
+            events,
+            traffic,
+            refreshed,
+        ) = tokio::join!(
+            self.get_outbound_mode(),
+            self.get_global_policy(),
+            self.cached_features(),
+            self.cached_mitm_ca(),
+            self.get_policy_groups(),
+            self.poll(Operation::Rules, self.get_rules()),
+            self.get_recent_requests(),
+            self.get_active_connections(),
+            self.get_dns_cache(),
+            self.poll(Operation::Modules, self.get_modules()),
+            self.poll(Operation::Events, self.get_events()),
+            self.poll(Operation::Traffic, self.get_traffic()),
+            self.fetch_selections(&stale_groups),
+        );
+        self.store_selections(refreshed);
+
+        snapshot.outbound_mode = outbound.ok();
+        snapshot.global_policy = global.ok();
+        snapshot.features = features.unwrap_or_default();
+        snapshot.mitm_ca = mitm_ca;
+
+        // 5. Policy groups: only groups never seen before need an extra round trip
+        match groups {
+            Ok(mut groups) => {
+                tracing::debug!("Fetched {} policy groups", groups.len());
+                let unknown_groups: Vec<String> = {
+                    let cache = self.selection_cache.lock().unwrap();
+                    groups
+                        .iter()
+                        .filter(|g| !cache.contains_key(&g.name))
+                        .map(|g| g.name.clone())
+                        .collect()
+                };
+                if !unknown_groups.is_empty() {
+                    let fetched = self.fetch_selections(&unknown_groups).await;
+                    self.store_selections(fetched);
+                }
 
-        // 5. Get MITM and Capture status (HTTP API mode only)
-        if snapshot.http_api_available {
-            if let Ok(mitm) = self.get_mitm_status().await {
-                snapshot.mitm_enabled = Some(mitm);
+                let mut cache = self.selection_cache.lock().unwrap();
+                // Drop groups that disappeared (e.g. after a profile reload)
+                cache.retain(|name, _| groups.iter().any(|g| &g.name == name));
+                for group in &mut groups {
+                    group.selected = cache.get(&group.name).and_then(|c| c.policy.clone());
+                }
+                snapshot.policy_groups = groups;
+
+                // Note: the policy group list carries no latency data
+                // Latency comes from test_policy_latency() (HTTP API, then CLI)
+                // When user presses T key, background test will be triggered and update snapshot.policies
             }
-            if let Ok(capture) = self.get_capture_status().await {
-                snapshot.capture_enabled = Some(capture);
+            Err(e) => log_fetch_error("policy groups", &e),
+        }
+
+        match rules {
+            Some(Ok(rules)) => {
+                tracing::debug!("Fetched {} rules", rules.len());
+                snapshot.rules = rules;
             }
+            Some(Err(e)) => log_fetch_error("rules", &e),
+            None => {}
         }
 
-        // 6. Get policy information (HTTP API mode only)
-        if snapshot.http_api_available {
-            // Get policy groups
-            match self.http_client.get_policy_groups().await {
-                Ok(groups) => {
-                    tracing::debug!("Fetched {} policy groups", groups.len());
-                    snapshot.policy_groups = groups;
+        match recent {
+            Ok(requests) => {
+                tracing::debug!("Fetched {} recent requests", requests.len());
+                snapshot.recent_requests = requests;
+            }
+            Err(e) => log_fetch_error("recent requests", &e),
+        }
 
-                    // Note: HTTP API does not provide latency data
-                    // Latency data can only be obtained through CLI's test_all_policies_with_latency()
-                    // When user presses T key, background test will be triggered and update snapshot.policies
-                }
-                Err(e) => tracing::error!("Failed to fetch policy groups: {}", e),
+        let active_fetched = active.is_ok();
+        match active {
+            Ok(connections) => {
+                tracing::debug!("Fetched {} active connections", connections.len());
+                snapshot.active_connections = connections;
             }
+            Err(e) => log_fetch_error("active connections", &e),
+        }
 
-            match self.http_client.get_recent_requests().await {
-                Ok(requests) => {
-                    tracing::debug!("Fetched {} recent requests", requests.len());
-                    snapshot.recent_requests = requests;
-                }
-                Err(e) => tracing::error!("Failed to fetch recent requests: {}", e),
+        // Traffic: estimated from connection byte counters where the endpoint is missing
+        let traffic = traffic.and_then(|r| r.inspect_err(|e| log_fetch_error("traffic", e)).ok());
+        let connections = active_fetched.then_some(snapshot.active_connections.as_slice());
+        let (traffic, history) = self.sample_traffic(traffic, connections);
+        snapshot.traffic = traffic;
+        snapshot.traffic_history = history;
+
+        match dns {
+            Ok(dns_cache) => {
+                tracing::debug!("Fetched {} DNS cache entries", dns_cache.len());
+                snapshot.dns_cache = dns_cache;
             }
+            Err(e) => log_fetch_error("DNS cache", &e),
+        }
 
-            match self.http_client.get_active_connections().await {
-                Ok(connections) => {
-                    tracing::debug!("Fetched {} active connections", connections.len());
-                    snapshot.active_connections = connections;
-                }
-                Err(e) => tracing::error!("Failed to fetch active connections: {}", e),
+        match modules {
+            Some(Ok(modules)) => {
+                tracing::debug!("Fetched {} modules", modules.len());
+                snapshot.modules = modules;
             }
+            Some(Err(e)) => log_fetch_error("modules", &e),
+            None => {}
+        }
 
-            // Get DNS cache
-            match self.http_client.get_dns_cache().await {
-                Ok(dns_cache) => {
-                    tracing::debug!("Fetched {} DNS cache entries", dns_cache.len());
-                    snapshot.dns_cache = dns_cache;
+        match events {
+            Some(Ok(events)) => {
+                tracing::debug!("Fetched {} events", events.len());
+                for content in self.new_error_events(&events) {
+                    snapshot.add_alert(Alert::event_error(content));
                 }
-                Err(e) => tracing::error!("Failed to fetch DNS cache: {}", e),
+                snapshot.events = events;
             }
+            Some(Err(e)) => log_fetch_error("events", &e),
+            None => {}
+        }
+
+        // Views hide what this Surge version does not serve
+        {
+            let missing = self.missing_endpoints.lock().unwrap();
+            snapshot.capabilities.retain(|op| !missing.contains(op));
         }
 
         snapshot
     }
 
+    /// Fetch an endpoint older Surge versions may not have
+    ///
+    /// A 404 marks the endpoint missing for the lifetime of the client: it is
+    /// logged once and `None` is returned from then on without a request
+    async fn poll<T>(
+        &self,
+        operation: Operation,
+        fetch: impl Future<Output = Result<T>>,
+    ) -> Option<Result<T>> {
+        if self.missing_endpoints.lock().unwrap().contains(&operation) {
+            return None;
+        }
+        match fetch.await {
+            // Unsupported in the current mode only (e.g. surge-cli), keep polling
+            Err(e) if e.is_not_found() && !matches!(e, SurgeError::Unsupported { .. }) => {
+                tracing::debug!("{} not supported by this Surge version: {}", operation, e);
+                self.missing_endpoints.lock().unwrap().insert(operation);
+                None
+            }
+            result => Some(result),
+        }
+    }
+
+    /// Record a traffic sample and return it with the updated history
+    ///
+    /// Without fetched statistics the speed is derived from the byte counters
+    /// of active connections since the previous sample (connections closed in
+    /// between are missed, so this is a lower bound)
+    fn sample_traffic(
+        &self,
+        fetched: Option<TrafficStats>,
+        connections: Option<&[Request]>,
+    ) -> (Option<TrafficStats>, TrafficHistory) {
+        let now = Instant::now();
+        let mut tracker = self.traffic.lock().unwrap();
+        let previous = tracker.sampled_at.replace(now);
+
+        let stats = match (fetched, connections, previous) {
+            (Some(stats), _, _) => Some(stats),
+            (None, Some(connections), Some(previous)) => {
+                let unix_now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+                estimate_traffic(&tracker.connections, connections, now - previous, unix_now)
+            }
+            _ => None,
+        };
+
+        if let Some(connections) = connections {
+            tracker.connections = connections
+                .iter()
+                .map(|c| (c.id, (c.in_bytes, c.out_bytes)))
+                .collect();
+        }
+        if let Some(ref stats) = stats {
+            tracker.history.push(stats);
+        }
+        (stats, tracker.history.clone())
+    }
+
+    /// Contents of error events first fetched less than `EVENT_ALERT_DURATION` ago
+    ///
+    /// Events already there at the first fetch are old news and never alert
+    fn new_error_events(&self, events: &[Event]) -> Vec<String> {
+        let now = Instant::now();
+        let mut seen = self.seen_events.lock().unwrap();
+        if !seen.seeded {
+            seen.seeded = true;
+            seen.first_seen = events
+                .iter()
+                .map(|event| (event.identifier.clone(), None))
+                .collect();
+            return Vec::new();
+        }
+
+        // Forget dismissed events
+        seen.first_seen
+            .retain(|id, _| events.iter().any(|e| &e.identifier == id));
+        events
+            .iter()
+            .filter(|event| {
+                let first_seen = *seen
+                    .first_seen
+                    .entry(event.identifier.clone())
+                    .or_insert(Some(now));
+                event.level == EventLevel::Error
+                    && first_seen.is_some_and(|at| now.duration_since(at) < EVENT_ALERT_DURATION)
+            })
+            .map(|event| event.content.clone())
+            .collect()
+    }
+
+    /// Fetch the selected policy of several groups concurrently
+    ///
+    /// At most `MAX_CONCURRENT_REQUESTS` lookups are in flight at once;
+    /// failed lookups are left out, so the cache keeps the previous selection
+    /// and they are retried on the next refresh
+    async fn fetch_selections(&self, group_names: &[String]) -> HashMap<String, Option<String>> {
+        let Ok(backend) = self.backend_for(Operation::PolicyGroups) else {
+            return HashMap::new();
+        };
+
+        let lookups: Vec<_> = group_names
+            .iter()
+            .map(|name| async move { (name, backend.get_policy_group_selected(name).await) })
+            .collect();
+
+        stream::iter(lookups)
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .filter_map(|(name, selected)| async move {
+                selected
+                    .inspect_err(|e| tracing::debug!("Selection of {} not fetched: {}", name, e))
+                    .ok()
+                    .map(|selected| (name.clone(), selected))
+            })
+            .collect()
+            .await
+    }
+
+    /// Known groups whose cached selection has expired
+    fn stale_selection_groups(&self) -> Vec<String> {
+        let cache = self.selection_cache.lock().unwrap();
+        cache
+            .iter()
+            .filter(|(_, c)| c.fetched_at.elapsed() >= SELECTION_CACHE_TTL)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Store freshly fetched selections in the cache
+    fn store_selections(&self, selections: HashMap<String, Option<String>>) {
+        let now = Instant::now();
+        let mut cache = self.selection_cache.lock().unwrap();
+        for (name, policy) in selections {
+            cache.insert(
+                name,
+                CachedSelection {
+                    policy,
+                    fetched_at: now,
+                },
+            );
+        }
+    }
+
     // ===== Outbound mode =====
 
     /// Get outbound mode
     pub async fn get_outbound_mode(&self) -> Result<OutboundMode> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.get_outbound_mode().await,
-            ClientMode::Cli => {
-                // CLI does not directly support getting outbound mode, need to parse dump policy
-                Err(SurgeError::HttpApiUnavailable {
-                    reason: "CLI mode does not support this operation".to_string(),
-                })
-            }
-        }
+        self.backend_for(Operation::OutboundMode)?
+            .get_outbound_mode()
+            .await
     }
 
     /// Set outbound mode
     pub async fn set_outbound_mode(&self, mode: OutboundMode) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.set_outbound_mode(mode).await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
-        }
+        self.backend_for(Operation::OutboundMode)?
+            .set_outbound_mode(mode)
+            .await
+    }
+
+    /// Get global policy (used in proxy mode)
+    pub async fn get_global_policy(&self) -> Result<String> {
+        self.backend_for(Operation::GlobalPolicy)?
+            .get_global_policy()
+            .await
+    }
+
+    /// Set global policy (used in proxy mode)
+    pub async fn set_global_policy(&self, policy: &str) -> Result<()> {
+        self.backend_for(Operation::GlobalPolicy)?
+            .set_global_policy(policy)
+            .await
     }
 
     // ===== Policy management =====
 
+    /// Get all policy names (proxies, then policy groups)
+    pub async fn get_policies(&self) -> Result<Vec<String>> {
+        self.backend_for(Operation::PolicyGroups)?
+            .get_policies()
+            .await
+    }
+
+    /// Get policy groups (selections are filled in by `get_snapshot`)
+    pub async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        self.backend_for(Operation::PolicyGroups)?
+            .get_policy_groups()
+            .await
+    }
+
     /// Test policy latency
     pub async fn test_policy(&self, name: &str) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.test_policy(name).await,
-            ClientMode::Cli => {
-                self.cli_client.test_policy(name).await?;
-                Ok(())
-            }
+        self.backend_for(Operation::TestPolicy)?
+            .test_policy(name, &self.latency_test(None))
+            .await
+    }
+
+    /// Latency test settings, with the group's URL override if any
+    fn latency_test(&self, group_name: Option<&str>) -> LatencyTest {
+        LatencyTest {
+            url: self.testing.url_for(group_name).to_string(),
+            timeout: Duration::from_secs(self.testing.timeout),
+            parallelism: self.testing.parallelism.max(1),
         }
     }
 
     /// Select policy in policy group
     pub async fn select_policy_group(&self, group_name: &str, policy: &str) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => {
-                self.http_client
-                    .select_policy_group(group_name, policy)
-                    .await
-            }
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
-        }
+        self.backend_for(Operation::SelectPolicy)?
+            .select_policy_group(group_name, policy)
+            .await?;
+        // Next snapshot shows the new selection without waiting for the TTL
+        self.store_selections(HashMap::from([(
+            group_name.to_string(),
+            Some(policy.to_string()),
+        )]));
+        Ok(())
     }
 
     /// Test policy group, return available policy list
+    ///
+    /// The list is empty when the backend does not report it (surge-cli)
     pub async fn test_policy_group(&self, group_name: &str) -> Result<Vec<String>> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.test_policy_group(group_name).await,
-            ClientMode::Cli => {
-                self.cli_client.test_group(group_name).await?;
-                // CLI mode does not return available list
-                Ok(Vec::new())
+        self.backend_for(Operation::TestPolicyGroup)?
+            .test_policy_group(group_name)
+            .await
+    }
+
+    /// Test all policies and return latency data
+    pub async fn test_all_policies_with_latency(&self) -> Result<Vec<PolicyDetail>> {
+        self.backend_for(Operation::TestAllPolicies)?
+            .test_all_policies()
+            .await
+    }
+
+    /// Test the policies of a group and return their latency
+    ///
+    /// Tries each backend in turn (the HTTP API first, then surge-cli). The
+    /// report tells when a custom test URL could not be used
+    pub async fn test_policy_latency(
+        &self,
+        group_name: &str,
+        names: &[String],
+    ) -> Result<LatencyReport> {
+        let test = self.latency_test(Some(group_name));
+        let mut last_error = None;
+        for backend in self.backends() {
+            if !backend.supports(Operation::TestPolicyLatency) {
+                continue;
+            }
+            match backend.test_policy_latency(names, &test).await {
+                Ok(results) => {
+                    return Ok(LatencyReport {
+                        results,
+                        url_ignored: !backend.uses_test_url()
+                            && self.testing.has_custom_url_for(group_name),
+                    })
+                }
+                Err(e) => {
+                    tracing::warn!("{} latency test failed: {}", backend.name(), e);
+                    last_error = Some(e);
+                }
             }
         }
+        Err(last_error
+            .unwrap_or_else(|| unsupported(self.primary.name(), Operation::TestPolicyLatency)))
     }
 
-    /// Test all policies and return latency data (CLI mode only)
-    pub async fn test_all_policies_with_latency(&self) -> Result<Vec<PolicyDetail>> {
-        // Only CLI mode supports latency retrieval
-        let test_results = self.cli_client.test_all_policies().await?;
+    // ===== Rules =====
 
-        let policies = test_results
-            .into_iter()
-            .map(|(name, latency, alive)| PolicyDetail {
-                name,
-                policy_type: PolicyType::Direct, // Temporary value
-                alive,
-                latency,
-                last_test_at: None,
-            })
-            .collect();
+    /// Get the ordered rule list
+    pub async fn get_rules(&self) -> Result<Vec<Rule>> {
+        self.backend_for(Operation::Rules)?.get_rules().await
+    }
+
+    // ===== Request management =====
 
-        Ok(policies)
+    /// Get recent requests
+    pub async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        self.backend_for(Operation::RecentRequests)?
+            .get_recent_requests()
+            .await
+    }
+
+    /// Get active connections
+    pub async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        self.backend_for(Operation::ActiveConnections)?
+            .get_active_connections()
+            .await
     }
 
     // ===== Connection management =====
 
     /// Kill connection
     pub async fn kill_connection(&self, id: u64) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.kill_connection(id).await,
-            ClientMode::Cli => self.cli_client.kill_connection(id).await,
-        }
+        self.backend_for(Operation::KillConnection)?
+            .kill_connection(id)
+            .await
     }
 
     // ===== Configuration management =====
 
     /// Reload configuration
     pub async fn reload_config(&self) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.reload_config().await,
-            ClientMode::Cli => self.cli_client.reload_config().await,
+        self.backend_for(Operation::ReloadConfig)?
+            .reload_config()
+            .await
+    }
+
+    /// Get available profile names
+    ///
+    /// Falls back to the local profile directory when no backend can list them
+    pub async fn get_profiles(&self) -> Result<Vec<String>> {
+        let result = match self.backend_for(Operation::Profiles) {
+            Ok(backend) => backend.get_profiles().await,
+            Err(e) => Err(e),
+        };
+        match (result, self.system_client) {
+            (Ok(profiles), _) => Ok(profiles),
+            (Err(e), Some(system_client)) => {
+                tracing::debug!("Listing profiles from disk: {}", e);
+                system_client.list_profiles().await
+            }
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    /// Get the current profile
+    pub async fn get_current_profile(&self, show_sensitive: bool) -> Result<ProfileInfo> {
+        self.backend_for(Operation::Profiles)?
+            .get_current_profile(show_sensitive)
+            .await
+    }
+
+    /// Get the current profile text
+    ///
+    /// The original profile comes from the HTTP API when possible (it masks
+    /// sensitive values itself), otherwise from `dump profile`; the effective
+    /// profile needs `dump profile`. Unless `show_sensitive` is set, sensitive
+    /// values are masked locally as well.
+    pub async fn get_profile_text(&self, effective: bool, show_sensitive: bool) -> Result<String> {
+        let mut http_error = None;
+        let mut text = None;
+        if !effective && self.supports(Operation::Profiles) {
+            match self.get_current_profile(show_sensitive).await {
+                Ok(profile) => text = profile.content,
+                Err(e) => http_error = Some(e),
+            }
+        }
+        let text = match (text, self.backend_for(Operation::ProfileDump)) {
+            (Some(text), _) => text,
+            (None, Ok(backend)) => backend.dump_profile(effective).await?,
+            // Report why the HTTP API failed rather than the missing dump
+            (None, Err(e)) => return Err(http_error.unwrap_or(e)),
+        };
+
+        if show_sensitive {
+            Ok(text)
+        } else {
+            Ok(profile::mask_sensitive(&text))
         }
     }
 
+    /// Switch profile
+    ///
+    /// Every backend supporting the switch is tried in order (HTTP API, then
+    /// surge-cli); the switch endpoint is macOS-only, so the CLI may succeed
+    /// where the HTTP API fails
+    pub async fn switch_profile(&self, name: &str) -> Result<()> {
+        let mut last_error = None;
+        for backend in self.backends() {
+            if !backend.supports(Operation::SwitchProfile) {
+                continue;
+            }
+            match backend.switch_profile(name).await {
+                Ok(()) => {
+                    tracing::info!("Switched to profile {} via {}", name, backend.name());
+                    return Ok(());
+                }
+                Err(e) => {
+                    tracing::warn!("{} failed to switch profile: {}", backend.name(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| unsupported("none", Operation::SwitchProfile)))
+    }
+
     // ===== DNS =====
 
     /// Get DNS cache
     pub async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.get_dns_cache().await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
-        }
+        self.backend_for(Operation::DnsCache)?.get_dns_cache().await
     }
 
     /// Flush DNS cache
     pub async fn flush_dns(&self) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.flush_dns().await,
-            ClientMode::Cli => self.cli_client.flush_dns().await,
-        }
+        self.backend_for(Operation::FlushDns)?.flush_dns().await
+    }
+
+    /// Test upstream DNS server delay
+    pub async fn test_dns_delay(&self) -> Result<Vec<DnsDelay>> {
+        self.backend_for(Operation::DnsDelay)?
+            .test_dns_delay()
+            .await
     }
 
     // ===== Feature toggles =====
 
-    /// Get MITM status
-    pub async fn get_mitm_status(&self) -> Result<bool> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.get_mitm_status().await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
+    /// Get the status of every feature
+    ///
+    /// At most `MAX_CONCURRENT_REQUESTS` requests are in flight at once.
+    /// Features the Surge version does not know (404, e.g. `enhanced_mode` on
+    /// iOS) are left out of the map; on other errors the last known status is
+    /// kept
+    pub async fn get_features(&self) -> Result<BTreeMap<Feature, bool>> {
+        let backend = self.backend_for(Operation::Features)?;
+        let lookups: Vec<_> = Feature::ALL
+            .iter()
+            .map(|feature| async move { (*feature, backend.get_feature(*feature).await) })
+            .collect();
+        let statuses: Vec<_> = stream::iter(lookups)
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+
+        let mut cache = self.features.lock().unwrap();
+        for (feature, status) in statuses {
+            match status {
+                Ok(enabled) => {
+                    cache.states.insert(feature, enabled);
+                }
+                Err(e) if e.is_not_found() => {
+                    tracing::debug!("Feature {} unsupported: {}", feature.as_str(), e);
+                    cache.states.remove(&feature);
+                }
+                Err(e) => {
+                    tracing::warn!("Feature {} not fetched: {}", feature.as_str(), e);
+                }
+            }
         }
+        cache.fetched_at = Some(Instant::now());
+        Ok(cache.states.clone())
     }
 
-    /// Set MITM status
-    pub async fn set_mitm_status(&self, enabled: bool) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.set_mitm_status(enabled).await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
+    /// Feature statuses, re-read at most every `FEATURE_REFRESH_INTERVAL`
+    async fn cached_features(&self) -> Result<BTreeMap<Feature, bool>> {
+        {
+            let cache = self.features.lock().unwrap();
+            if cache
+                .fetched_at
+                .is_some_and(|at| at.elapsed() < FEATURE_REFRESH_INTERVAL)
+            {
+                return Ok(cache.states.clone());
+            }
         }
+        self.get_features().await
     }
 
-    /// Get traffic capture status
-    pub async fn get_capture_status(&self) -> Result<bool> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.get_capture_status().await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
+    /// Get feature status
+    pub async fn get_feature(&self, feature: Feature) -> Result<bool> {
+        self.backend_for(Operation::Features)?
+            .get_feature(feature)
+            .await
+    }
+
+    /// Set feature status
+    pub async fn set_feature(&self, feature: Feature, enabled: bool) -> Result<()> {
+        self.backend_for(Operation::Features)?
+            .set_feature(feature, enabled)
+            .await?;
+        // Next snapshot shows the new status without waiting for the interval
+        self.features
+            .lock()
+            .unwrap()
+            .states
+            .insert(feature, enabled);
+        Ok(())
+    }
+
+    // ===== MITM =====
+
+    /// Download and parse the MITM CA certificate
+    pub async fn get_mitm_ca(&self) -> Result<CaCertificate> {
+        let backend = self.backend_for(Operation::MitmCa)?;
+        let result = match backend.get_mitm_ca().await {
+            Ok(der) => certificate::parse_certificate(der),
+            Err(e) => Err(e),
+        };
+        let mut cache = self.mitm_ca.lock().unwrap();
+        match result {
+            Ok(ref ca) => {
+                cache.certificate = Some(ca.clone());
+                cache.failed_at = None;
+            }
+            Err(_) => cache.failed_at = Some(Instant::now()),
         }
+        result
     }
 
-    /// Set traffic capture status
-    pub async fn set_capture_status(&self, enabled: bool) -> Result<()> {
-        match self.mode {
-            ClientMode::HttpApi => self.http_client.set_capture_status(enabled).await,
-            ClientMode::Cli => Err(SurgeError::HttpApiUnavailable {
-                reason: "CLI mode does not support this operation".to_string(),
-            }),
+    /// MITM CA certificate, downloaded on first use
+    ///
+    /// The CA only changes when the user regenerates it in Surge, so refreshes
+    /// reuse the first download. A failed download is logged once and retried
+    /// after `MITM_CA_RETRY_INTERVAL` or `retry_mitm_ca`
+    async fn cached_mitm_ca(&self) -> Option<CaCertificate> {
+        {
+            let cache = self.mitm_ca.lock().unwrap();
+            if cache.certificate.is_some() {
+                return cache.certificate.clone();
+            }
+            if cache
+                .failed_at
+                .is_some_and(|at| at.elapsed() < MITM_CA_RETRY_INTERVAL)
+            {
+                return None;
+            }
         }
+        self.get_mitm_ca()
+            .await
+            .inspect_err(|e| log_fetch_error("MITM CA", e))
+            .ok()
+    }
+
+    /// Download the MITM CA again at the next refresh if the last attempt failed
+    pub fn retry_mitm_ca(&self) {
+        self.mitm_ca.lock().unwrap().failed_at = None;
+    }
+
+    // ===== Modules =====
+
+    /// Get available modules and their enabled state
+    pub async fn get_modules(&self) -> Result<Vec<Module>> {
+        self.backend_for(Operation::Modules)?.get_modules().await
+    }
+
+    /// Enable or disable a module
+    pub async fn set_module_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        self.backend_for(Operation::Modules)?
+            .set_module_enabled(name, enabled)
+            .await
+    }
+
+    // ===== Events =====
+
+    /// Get event center entries
+    pub async fn get_events(&self) -> Result<Vec<Event>> {
+        self.backend_for(Operation::Events)?.get_events().await
+    }
+
+    // ===== Traffic =====
+
+    /// Get traffic statistics
+    pub async fn get_traffic(&self) -> Result<TrafficStats> {
+        self.backend_for(Operation::Traffic)?.get_traffic().await
+    }
+
+    // ===== Scripting =====
+
+    /// Evaluate a script with a mocked context
+    pub async fn evaluate_script(
+        &self,
+        script: &str,
+        mock_type: ScriptMockType,
+        timeout_secs: u64,
+    ) -> Result<ScriptEvaluation> {
+        self.backend_for(Operation::Scripting)?
+            .evaluate_script(script, mock_type, timeout_secs)
+            .await
+    }
+
+    // ===== Engine control =====
+
+    /// Stop Surge
+    ///
+    /// Tries the HTTP API (`/v1/stop`), then `surge-cli stop`, then
+    /// `killall Surge` on the local machine
+    pub async fn stop_surge(&self) -> Result<()> {
+        let mut last_error = None;
+        for backend in self.backends() {
+            if !backend.supports(Operation::StopSurge) {
+                continue;
+            }
+            match backend.stop_surge().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!("{} could not stop Surge: {}", backend.name(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match (self.system_client, last_error) {
+            (Some(system_client), _) => system_client.stop_surge().await,
+            (None, Some(e)) => Err(e),
+            (None, None) => Err(unsupported(self.primary.name(), Operation::StopSurge)),
+        }
+    }
+
+    /// Stop Surge, wait for the process to exit and start it again (local only)
+    ///
+    /// Fails without starting Surge when the old process is still running
+    /// after the wait, rather than launching a second instance
+    pub async fn restart_surge(&self) -> Result<()> {
+        let system_client = *self.local_system("restart Surge")?;
+        self.stop_surge().await?;
+
+        for _ in 0..RESTART_WAIT_STEPS {
+            if !system_client.is_surge_running().await {
+                return system_client.start_surge().await;
+            }
+            tokio::time::sleep(RESTART_WAIT_STEP).await;
+        }
+        if system_client.is_surge_running().await {
+            return Err(SurgeError::SurgeStillRunning);
+        }
+        system_client.start_surge().await
+    }
+
+    /// Set the Surge log level
+    pub async fn set_log_level(&self, level: LogLevel) -> Result<()> {
+        self.backend_for(Operation::LogLevel)?
+            .set_log_level(level)
+            .await
+    }
+
+    // ===== Diagnostics =====
+
+    /// Run the network diagnostics
+    pub async fn run_diagnostics(&self) -> Result<Vec<DiagnosticCheck>> {
+        self.backend_for(Operation::Diagnostics)?
+            .run_diagnostics()
+            .await
     }
 
     // ===== System-level operations =====
 
     /// Start Surge
     pub async fn start_surge(&self) -> Result<()> {
-        self.system_client.start_surge().await
+        self.local_system("start Surge")?.start_surge().await
     }
 
     /// Check if Surge is running
     pub async fn is_surge_running(&self) -> bool {
-        self.system_client.is_surge_running().await
+        match self.system_client {
+            Some(ref system_client) => system_client.is_surge_running().await,
+            None => self.primary.is_available().await,
+        }
+    }
+
+    /// System client, rejecting operations that need local access to the Surge machine
+    fn local_system(&self, operation: &str) -> Result<&SurgeSystemClient> {
+        self.system_client
+            .as_ref()
+            .ok_or_else(|| SurgeError::RemoteModeUnsupported {
+                operation: operation.to_string(),
+            })
+    }
+}
+
+/// Traffic of active connections since the previous sample
+///
+/// A connection not seen before counts with the share of its bytes that fits
+/// the window, assuming a steady rate since its `start_date` (all of them if
+/// it started within the window, none without a start date). `unix_now` is
+/// the current time in Unix seconds.
+fn estimate_traffic(
+    previous: &HashMap<u64, (u64, u64)>,
+    connections: &[Request],
+    elapsed: Duration,
+    unix_now: f64,
+) -> Option<TrafficStats> {
+    let secs = elapsed.as_secs_f64();
+    if secs <= 0.0 {
+        return None;
+    }
+
+    let mut stats = TrafficStats::default();
+    let (mut uploaded, mut downloaded) = (0, 0);
+    for connection in connections {
+        match previous.get(&connection.id) {
+            Some(&(prev_in, prev_out)) => {
+                downloaded += connection.in_bytes.saturating_sub(prev_in);
+                uploaded += connection.out_bytes.saturating_sub(prev_out);
+            }
+            None => {
+                let share = connection.start_date.map_or(0.0, |start| {
+                    let age = unix_now - start;
+                    if age <= secs {
+                        1.0
+                    } else {
+                        secs / age
+                    }
+                });
+                downloaded += (connection.in_bytes as f64 * share) as u64;
+                uploaded += (connection.out_bytes as f64 * share) as u64;
+            }
+        }
+        stats.download += connection.in_bytes;
+        stats.upload += connection.out_bytes;
+    }
+    stats.upload_speed = (uploaded as f64 / secs) as u64;
+    stats.download_speed = (downloaded as f64 / secs) as u64;
+    Some(stats)
+}
+
+/// Log a failed snapshot fetch (unsupported operations are expected, not errors)
+fn log_fetch_error(what: &str, error: &SurgeError) {
+    match error {
+        SurgeError::Unsupported { .. } => tracing::debug!("Skipped {}: {}", what, error),
+        _ => tracing::error!("Failed to fetch {}: {}", what, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::{MemoryBackend, MemoryState};
+
+    fn connection(id: u64, start_date: Option<f64>, in_bytes: u64, out_bytes: u64) -> Request {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "startDate": start_date,
+            "inBytes": in_bytes,
+            "outBytes": out_bytes,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn estimate_traffic_counts_growth_of_known_connections() {
+        let previous = HashMap::from([(1, (1_000, 100))]);
+        let connections = [connection(1, Some(0.0), 3_000, 300)];
+        let stats =
+            estimate_traffic(&previous, &connections, Duration::from_secs(2), 1_000.0).unwrap();
+        assert_eq!(stats.download_speed, 1_000);
+        assert_eq!(stats.upload_speed, 100);
+        assert_eq!(stats.download, 3_000);
+    }
+
+    #[test]
+    fn estimate_traffic_prorates_new_long_lived_connections() {
+        // Open for 100 s with 100 KB: only the last 2 s worth counts
+        let connections = [connection(1, Some(900.0), 100_000, 0)];
+        let stats = estimate_traffic(
+            &HashMap::new(),
+            &connections,
+            Duration::from_secs(2),
+            1_000.0,
+        )
+        .unwrap();
+        assert_eq!(stats.download_speed, 1_000);
+        assert_eq!(stats.download, 100_000);
+    }
+
+    #[test]
+    fn estimate_traffic_counts_new_connections_started_in_window() {
+        let connections = [
+            connection(1, Some(999.0), 4_000, 0),
+            // No start date: the rate is unknown, it is skipped
+            connection(2, None, 50_000, 0),
+        ];
+        let stats = estimate_traffic(
+            &HashMap::new(),
+            &connections,
+            Duration::from_secs(2),
+            1_000.0,
+        )
+        .unwrap();
+        assert_eq!(stats.download_speed, 2_000);
+    }
+
+    fn policy_item(name: &str) -> PolicyItem {
+        PolicyItem {
+            is_group: false,
+            name: name.to_string(),
+            type_description: "Shadowsocks".to_string(),
+            line_hash: String::new(),
+            enabled: true,
+        }
+    }
+
+    fn memory_state() -> MemoryState {
+        MemoryState {
+            outbound_mode: OutboundMode::Proxy,
+            proxies: vec!["HK".to_string(), "JP".to_string()],
+            policy_groups: vec![PolicyGroup {
+                name: "Proxy".to_string(),
+                policies: vec![policy_item("HK"), policy_item("JP")],
+                selected: Some("HK".to_string()),
+                available_policies: None,
+            }],
+            features: BTreeMap::from([(Feature::Mitm, true), (Feature::Capture, false)]),
+            ..MemoryState::default()
+        }
+    }
+
+    /// Client over a memory backend; the backend is returned for inspection
+    fn memory_client(state: MemoryState) -> (SurgeClient, Arc<MemoryBackend>) {
+        let backend = Arc::new(MemoryBackend::new(state));
+        (SurgeClient::with_backend(backend.clone()), backend)
+    }
+
+    #[tokio::test]
+    async fn snapshot_reads_backend_state() {
+        let (client, _) = memory_client(memory_state());
+        let snapshot = client.get_snapshot().await;
+
+        assert!(snapshot.surge_running);
+        assert!(snapshot.alerts.is_empty());
+        assert_eq!(snapshot.outbound_mode, Some(OutboundMode::Proxy));
+        assert_eq!(snapshot.policy_groups.len(), 1);
+        assert_eq!(snapshot.policy_groups[0].selected.as_deref(), Some("HK"));
+        assert_eq!(snapshot.feature(Feature::Mitm), Some(true));
+        assert_eq!(snapshot.feature(Feature::Capture), Some(false));
+    }
+
+    #[tokio::test]
+    async fn snapshot_reports_unreachable_backend() {
+        let (client, _) = memory_client(MemoryState {
+            available: false,
+            ..memory_state()
+        });
+        let snapshot = client.get_snapshot().await;
+
+        assert!(!snapshot.surge_running);
+        assert_eq!(snapshot.alerts.len(), 1);
+        assert_eq!(snapshot.alerts[0].message, "surge_unreachable");
+    }
+
+    #[tokio::test]
+    async fn writes_reach_backend() {
+        let (client, backend) = memory_client(memory_state());
+
+        client.select_policy_group("Proxy", "JP").await.unwrap();
+        client.set_outbound_mode(OutboundMode::Rule).await.unwrap();
+
+        let state = backend.state();
+        assert_eq!(
+            state.calls,
+            ["select_policy_group Proxy JP", "set_outbound_mode Rule"]
+        );
+        assert_eq!(state.policy_groups[0].selected.as_deref(), Some("JP"));
+        assert_eq!(state.outbound_mode, OutboundMode::Rule);
+        // The selection is visible right away, without waiting for the cache to expire
+        let snapshot = client.get_snapshot().await;
+        assert_eq!(snapshot.policy_groups[0].selected.as_deref(), Some("JP"));
+    }
+
+    #[tokio::test]
+    async fn unsupported_operation_is_rejected_without_a_call() {
+        let backend = Arc::new(
+            MemoryBackend::new(memory_state()).with_capabilities(&[Operation::OutboundMode]),
+        );
+        let client = SurgeClient::with_backend(backend.clone());
+
+        assert!(!client.supports(Operation::FlushDns));
+        let error = client.flush_dns().await.unwrap_err();
+        assert!(matches!(error, SurgeError::Unsupported { .. }));
+        assert!(backend.state().calls.is_empty());
+    }
+
+    #[tokio::test]
+    async fn operations_missing_from_primary_use_fallback() {
+        let primary = Arc::new(
+            MemoryBackend::new(memory_state()).with_capabilities(&[Operation::OutboundMode]),
+        );
+        let fallback = Arc::new(MemoryBackend::new(memory_state()));
+        let client = SurgeClient::with_backend(primary.clone()).with_fallback(fallback.clone());
+
+        client
+            .set_outbound_mode(OutboundMode::Direct)
+            .await
+            .unwrap();
+        client.flush_dns().await.unwrap();
+
+        assert_eq!(primary.state().calls, ["set_outbound_mode Direct"]);
+        assert_eq!(fallback.state().calls, ["flush_dns"]);
+    }
+
+    #[tokio::test]
+    async fn unreachable_primary_switches_to_fallback() {
+        let primary = Arc::new(MemoryBackend::new(MemoryState {
+            available: false,
+            ..memory_state()
+        }));
+        let fallback = Arc::new(MemoryBackend::new(memory_state()));
+        let client = SurgeClient::with_backend(primary.clone()).with_fallback(fallback.clone());
+
+        assert_eq!(client.detect_mode().await, ClientMode::Cli);
+        client
+            .set_outbound_mode(OutboundMode::Direct)
+            .await
+            .unwrap();
+
+        assert!(primary.state().calls.is_empty());
+        assert_eq!(fallback.state().calls, ["set_outbound_mode Direct"]);
+    }
+
+    #[tokio::test]
+    async fn primary_errors_are_not_retried_on_fallback() {
+        let primary = Arc::new(MemoryBackend::new(memory_state()));
+        let fallback = Arc::new(MemoryBackend::new(memory_state()));
+        let client = SurgeClient::with_backend(primary.clone()).with_fallback(fallback.clone());
+
+        let error = client
+            .select_policy_group("Missing", "HK")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SurgeError::PolicyGroupNotFound { .. }));
+        assert!(fallback.state().calls.is_empty());
+    }
+
+    #[tokio::test]
+    async fn latency_test_reports_ignored_custom_url() {
+        let primary = Arc::new(MemoryBackend::new(MemoryState {
+            available: false,
+            ..memory_state()
+        }));
+        let fallback = Arc::new(MemoryBackend::new(memory_state()).without_test_url());
+        let mut testing = TestingConfig::default();
+        testing
+            .group_urls
+            .insert("Streaming".to_string(), "https://example.com".to_string());
+        let client = SurgeClient::with_backend(primary)
+            .with_fallback(fallback)
+            .with_testing(testing);
+        client.detect_mode().await;
+
+        let names = ["HK".to_string()];
+        let report = client
+            .test_policy_latency("Streaming", &names)
+            .await
+            .unwrap();
+        assert!(report.url_ignored);
+        // Groups tested with the default URL lose nothing
+        let report = client.test_policy_latency("Proxy", &names).await.unwrap();
+        assert!(!report.url_ignored);
     }
 }
@@ -0,0 +1,58 @@
+/// surge-mock - Mock Surge HTTP API
+///
+/// Serves fixture data on the `/v1/*` endpoints so surge-tui can be developed
+/// and tested without a Mac running Surge.
+///
+/// Usage: surge-mock [--listen ADDR] [--key KEY] [--fixture FILE]
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use surge_tui::mock::{MockServer, MockState};
+
+const USAGE: &str = "Usage: surge-mock [--listen ADDR] [--key KEY] [--fixture FILE]
+
+  --listen ADDR    Address to listen on (default: 127.0.0.1:6171)
+  --key KEY        Required X-Key (default: $SURGE_HTTP_API_KEY or \"mock-key\")
+  --fixture FILE   State fixture JSON (default: bundled fixtures/surge-mock.json)";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(std::env::var("RUST_LOG").unwrap_or_else(|_| "surge_tui=info".to_string()))
+        .init();
+
+    let mut listen: SocketAddr = "127.0.0.1:6171".parse()?;
+    let mut key = std::env::var("SURGE_HTTP_API_KEY").unwrap_or_else(|_| "mock-key".to_string());
+    let mut fixture: Option<PathBuf> = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow::anyhow!("{} needs a value\n\n{}", arg, USAGE))
+        };
+        match arg.as_str() {
+            "--listen" => listen = value()?.parse()?,
+            "--key" => key = value()?,
+            "--fixture" => fixture = Some(PathBuf::from(value()?)),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => anyhow::bail!("unknown argument: {}\n\n{}", arg, USAGE),
+        }
+    }
+
+    let state = match fixture {
+        Some(path) => MockState::from_file(&path)?,
+        None => MockState::default(),
+    };
+
+    let server = MockServer::start(listen, key, state).await?;
+    println!("surge-mock listening on http://{}", server.addr());
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = server.wait() => {}
+    }
+    Ok(())
+}
@@ -2,13 +2,43 @@
 ///
 /// Load configuration from config file or environment variables
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Main configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    #[serde(default)]
     pub surge: SurgeConfig,
     pub ui: UiConfig,
+    /// Latency tests
+    #[serde(default)]
+    pub testing: TestingConfig,
+    /// Named Surge instances (`[[instances]]`); `[surge]` is used when empty
+    #[serde(default)]
+    pub instances: Vec<InstanceConfig>,
+    /// Session recording (command line only)
+    #[serde(skip)]
+    pub recording: Option<Recording>,
+}
+
+/// Session recording mode (`--record <dir>` / `--replay <dir>`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recording {
+    /// Save every raw HTTP API response to the directory
+    Record(PathBuf),
+    /// Answer HTTP API requests from a recorded directory (no live Surge)
+    Replay(PathBuf),
+}
+
+/// Named Surge instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceConfig {
+    /// Display name (shown in the instance picker and status bar)
+    pub name: String,
+    /// Connection settings (same keys as `[surge]`)
+    #[serde(flatten)]
+    pub surge: SurgeConfig,
 }
 
 /// Surge-related configuration
@@ -29,6 +59,22 @@ pub struct SurgeConfig {
     /// surge-cli path
     #[serde(default = "default_cli_path")]
     pub cli_path: Option<String>,
+
+    /// Use HTTPS (Surge `http-api-tls = true`)
+    #[serde(default)]
+    pub tls: bool,
+
+    /// Pinned SHA-256 fingerprint of Surge's certificate (takes precedence over tls_ca_file)
+    #[serde(default)]
+    pub tls_fingerprint: Option<String>,
+
+    /// CA certificate file (PEM or DER) that signed Surge's certificate
+    #[serde(default)]
+    pub tls_ca_file: Option<String>,
+
+    /// Force remote mode on/off (default: detected from http_api_host)
+    #[serde(default)]
+    pub remote: Option<bool>,
 }
 
 /// UI-related configuration
@@ -43,6 +89,102 @@ pub struct UiConfig {
     pub max_requests: usize,
 }
 
+/// Latency test configuration (used by both the HTTP API and surge-cli)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestingConfig {
+    /// URL requested through each policy
+    #[serde(default = "default_test_url")]
+    pub url: String,
+
+    /// Seconds before a policy counts as unavailable
+    #[serde(default = "default_test_timeout")]
+    pub timeout: u64,
+
+    /// Policies tested at once by surge-cli (the HTTP API tests a group in
+    /// one request)
+    #[serde(default = "default_test_parallelism")]
+    pub parallelism: usize,
+
+    /// Test URL per policy group (overrides `url`)
+    #[serde(default)]
+    pub group_urls: HashMap<String, String>,
+}
+
+impl SurgeConfig {
+    /// Overlay the `SURGE_*` variables returned by `var`
+    fn apply_env(&mut self, var: &impl Fn(&str) -> Option<String>) {
+        if let Some(host) = var("SURGE_HTTP_API_HOST") {
+            self.http_api_host = host;
+        }
+        if let Some(port) = var("SURGE_HTTP_API_PORT") {
+            if let Ok(port) = port.parse() {
+                self.http_api_port = port;
+            }
+        }
+        if let Some(key) = var("SURGE_HTTP_API_KEY") {
+            self.http_api_key = key;
+        }
+        if let Some(path) = var("SURGE_CLI_PATH") {
+            self.cli_path = Some(path);
+        }
+        if let Some(tls) = var("SURGE_HTTP_API_TLS") {
+            self.tls = matches!(tls.as_str(), "1" | "true" | "yes");
+        }
+        if let Some(fingerprint) = var("SURGE_HTTP_API_TLS_FINGERPRINT") {
+            self.tls_fingerprint = Some(fingerprint);
+        }
+        if let Some(path) = var("SURGE_HTTP_API_TLS_CA_FILE") {
+            self.tls_ca_file = Some(path);
+        }
+        if let Some(remote) = var("SURGE_REMOTE") {
+            self.remote = Some(matches!(remote.as_str(), "1" | "true" | "yes"));
+        }
+    }
+
+    /// Whether the HTTP API host is another machine (not loopback)
+    ///
+    /// In remote mode only the HTTP API is used: pgrep/open/killall and
+    /// surge-cli would act on the local machine instead of the target Mac
+    pub fn is_remote(&self) -> bool {
+        if let Some(remote) = self.remote {
+            return remote;
+        }
+
+        let host = self
+            .http_api_host
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+
+        if host.eq_ignore_ascii_case("localhost") {
+            return false;
+        }
+
+        match host.parse::<std::net::IpAddr>() {
+            Ok(ip) => !ip.is_loopback(),
+            Err(_) => true,
+        }
+    }
+}
+
+impl TestingConfig {
+    /// Test URL for a policy group (the default URL without a group)
+    pub fn url_for(&self, group_name: Option<&str>) -> &str {
+        group_name
+            .and_then(|name| self.group_urls.get(name))
+            .unwrap_or(&self.url)
+    }
+
+    /// Whether a test URL other than the default is configured
+    pub fn has_custom_url(&self) -> bool {
+        self.url != default_test_url() || !self.group_urls.is_empty()
+    }
+
+    /// Whether a policy group is tested with a URL other than the default
+    pub fn has_custom_url_for(&self, group_name: &str) -> bool {
+        self.url_for(Some(group_name)) != default_test_url()
+    }
+}
+
 // === Default values ===
 
 fn default_http_api_host() -> String {
@@ -65,19 +207,55 @@ fn default_max_requests() -> usize {
     100
 }
 
+fn default_test_url() -> String {
+    "http://www.gstatic.com/generate_204".to_string()
+}
+
+fn default_test_timeout() -> u64 {
+    5 // 5 seconds
+}
+
+fn default_test_parallelism() -> usize {
+    8
+}
+
+impl Default for TestingConfig {
+    fn default() -> Self {
+        Self {
+            url: default_test_url(),
+            timeout: default_test_timeout(),
+            parallelism: default_test_parallelism(),
+            group_urls: HashMap::new(),
+        }
+    }
+}
+
+impl Default for SurgeConfig {
+    fn default() -> Self {
+        Self {
+            http_api_host: default_http_api_host(),
+            http_api_port: default_http_api_port(),
+            http_api_key: String::new(), // Must be provided by user
+            cli_path: default_cli_path(),
+            tls: false,
+            tls_fingerprint: None,
+            tls_ca_file: None,
+            remote: None,
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
-            surge: SurgeConfig {
-                http_api_host: default_http_api_host(),
-                http_api_port: default_http_api_port(),
-                http_api_key: String::new(), // Must be provided by user
-                cli_path: default_cli_path(),
-            },
+            surge: SurgeConfig::default(),
             ui: UiConfig {
                 refresh_interval: default_refresh_interval(),
                 max_requests: default_max_requests(),
             },
+            testing: TestingConfig::default(),
+            instances: Vec::new(),
+            recording: None,
         }
     }
 }
@@ -117,22 +295,64 @@ impl Config {
         let mut config = file_config.unwrap_or_default();
 
         // Always overlay env vars (env takes precedence over file for key/host/port)
-        if let Ok(host) = std::env::var("SURGE_HTTP_API_HOST") {
-            config.surge.http_api_host = host;
-        }
-        if let Ok(port) = std::env::var("SURGE_HTTP_API_PORT") {
-            if let Ok(port) = port.parse() {
-                config.surge.http_api_port = port;
-            }
+        config.apply_env(&|name| std::env::var(name).ok());
+
+        // surge-cli has no URL option: local instances fall back to it for latency tests
+        if config.testing.has_custom_url()
+            && config.instance_list().iter().any(|i| !i.surge.is_remote())
+        {
+            tracing::warn!(
+                "[testing] url and group_urls only apply to the HTTP API; \
+                 surge-cli latency tests use the profile's proxy-test-url"
+            );
         }
-        if let Ok(key) = std::env::var("SURGE_HTTP_API_KEY") {
-            config.surge.http_api_key = key;
+
+        Ok(config)
+    }
+
+    /// Overlay the `SURGE_*` variables on `[surge]` and on the startup instance
+    ///
+    /// Other `[[instances]]` entries keep their own settings, so one exported
+    /// host or key does not turn every instance into the same connection
+    fn apply_env(&mut self, var: &impl Fn(&str) -> Option<String>) {
+        self.surge.apply_env(var);
+        let startup = self.startup_instance_by(var);
+        if let Some(instance) = self.instances.get_mut(startup) {
+            instance.surge.apply_env(var);
         }
-        if let Ok(path) = std::env::var("SURGE_CLI_PATH") {
-            config.surge.cli_path = Some(path);
+    }
+
+    /// Index of the instance to start with in `instance_list()`
+    /// (`SURGE_INSTANCE` picks one by name, otherwise the first)
+    pub fn startup_instance(&self) -> usize {
+        self.startup_instance_by(&|name| std::env::var(name).ok())
+    }
+
+    fn startup_instance_by(&self, var: &impl Fn(&str) -> Option<String>) -> usize {
+        var("SURGE_INSTANCE")
+            .and_then(|name| self.instance_list().iter().position(|i| i.name == name))
+            .unwrap_or(0)
+    }
+
+    /// All selectable instances
+    ///
+    /// Without `[[instances]]`, the `[surge]` table is the only instance ("default")
+    pub fn instance_list(&self) -> Vec<InstanceConfig> {
+        if self.instances.is_empty() {
+            vec![InstanceConfig {
+                name: "default".to_string(),
+                surge: self.surge.clone(),
+            }]
+        } else {
+            self.instances.clone()
         }
+    }
 
-        Ok(config)
+    /// Copy of this config with `[surge]` replaced by the given instance
+    pub fn with_instance(&self, instance: &InstanceConfig) -> Self {
+        let mut config = self.clone();
+        config.surge = instance.surge.clone();
+        config
     }
 
     /// Generate example config file
@@ -146,13 +366,120 @@ http_api_key = "your-secret-key"  # required
 # surge-cli path (optional, auto-detected by default)
 # cli_path = "/Applications/Surge.app/Contents/Applications/surge-cli"
 
+# HTTPS (requires http-api-tls = true in Surge)
+# tls = true
+# Trust Surge's self-signed certificate by SHA-256 fingerprint...
+# tls_fingerprint = "AB:CD:..."
+# ...or by the CA that signed it
+# tls_ca_file = "/path/to/surge-ca.pem"
+
+# Force remote mode (HTTP API only), e.g. against surge-mock on localhost
+# remote = true
+
 [ui]
 # UI refresh interval (seconds)
 refresh_interval = 1
 
 # Maximum request history count
 max_requests = 100
+
+[testing]
+# URL requested through each policy in latency tests
+url = "http://www.gstatic.com/generate_204"
+
+# Seconds before a policy counts as unavailable
+timeout = 5
+
+# Policies tested at once by surge-cli (the HTTP API tests a group in one request)
+parallelism = 8
+
+# Test URL per policy group (optional)
+# [testing.group_urls]
+# "Streaming" = "https://www.netflix.com"
+
+# Multiple Surge instances (optional, replaces [surge]; switch with w)
+# [[instances]]
+# name = "gateway"
+# http_api_host = "192.168.1.10"
+# http_api_port = 6171
+# http_api_key = "gateway-key"
+#
+# [[instances]]
+# name = "laptop"
+# http_api_key = "laptop-key"
 "#
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(name: &str, host: &str, key: &str) -> InstanceConfig {
+        InstanceConfig {
+            name: name.to_string(),
+            surge: SurgeConfig {
+                http_api_host: host.to_string(),
+                http_api_key: key.to_string(),
+                ..SurgeConfig::default()
+            },
+        }
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn env_overrides_only_the_startup_instance() {
+        let mut config = Config {
+            instances: vec![
+                instance("gateway", "10.0.0.1", "gateway-key"),
+                instance("build", "10.0.0.2", "build-key"),
+            ],
+            ..Config::default()
+        };
+        config.apply_env(&env(&[
+            ("SURGE_HTTP_API_HOST", "192.168.1.9"),
+            ("SURGE_HTTP_API_KEY", "env-key"),
+        ]));
+
+        let instances = config.instance_list();
+        assert_eq!(instances[0].surge.http_api_host, "192.168.1.9");
+        assert_eq!(instances[0].surge.http_api_key, "env-key");
+        assert_eq!(instances[1].surge.http_api_host, "10.0.0.2");
+        assert_eq!(instances[1].surge.http_api_key, "build-key");
+    }
+
+    #[test]
+    fn env_follows_surge_instance() {
+        let mut config = Config {
+            instances: vec![
+                instance("gateway", "10.0.0.1", "gateway-key"),
+                instance("build", "10.0.0.2", "build-key"),
+            ],
+            ..Config::default()
+        };
+        let var = env(&[
+            ("SURGE_INSTANCE", "build"),
+            ("SURGE_HTTP_API_KEY", "env-key"),
+        ]);
+        config.apply_env(&var);
+
+        assert_eq!(config.startup_instance_by(&var), 1);
+        assert_eq!(config.instances[0].surge.http_api_key, "gateway-key");
+        assert_eq!(config.instances[1].surge.http_api_key, "env-key");
+    }
+
+    #[test]
+    fn env_overrides_single_surge_table() {
+        let mut config = Config::default();
+        config.apply_env(&env(&[("SURGE_HTTP_API_PORT", "7000")]));
+        assert_eq!(config.instance_list()[0].surge.http_api_port, 7000);
+    }
+}
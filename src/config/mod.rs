@@ -1,5 +1,6 @@
 /// Configuration management module
+#[allow(clippy::module_inception)]
 pub mod config;
 
 // Re-export
-pub use config::{Config, SurgeConfig, UiConfig};
+pub use config::{Config, InstanceConfig, Recording, SurgeConfig, TestingConfig, UiConfig};
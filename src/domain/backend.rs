@@ -0,0 +1,332 @@
+/// Surge backend abstraction
+///
+/// A backend is one way of talking to Surge (HTTP API, surge-cli, an in-memory
+/// double, ...). Backends declare which operations they support; for each
+/// operation the application layer uses the first backend in its chain that
+/// supports it, and the UI queries the result. An error from that backend is
+/// returned as is: the next backend is only tried when the operation is
+/// unsupported or the whole backend is unreachable.
+use crate::domain::errors::{Result, SurgeError};
+use crate::domain::models::*;
+use async_trait::async_trait;
+use std::fmt;
+
+/// Operation a backend may support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Get/set outbound mode
+    OutboundMode,
+    /// List policies, policy groups and their selected policy
+    PolicyGroups,
+    /// Select a policy within a policy group
+    SelectPolicy,
+    /// Get/set the global policy used in proxy mode
+    GlobalPolicy,
+    /// Test a single policy
+    TestPolicy,
+    /// Re-test a policy group
+    TestPolicyGroup,
+    /// Test all policies and report latency
+    TestAllPolicies,
+    /// Test the given policies and report their latency
+    TestPolicyLatency,
+    /// Ordered rule list
+    Rules,
+    /// Recent requests
+    RecentRequests,
+    /// Active connections
+    ActiveConnections,
+    /// Kill a connection
+    KillConnection,
+    /// Reload configuration
+    ReloadConfig,
+    /// List profiles and read the current one
+    Profiles,
+    /// Switch to another profile
+    SwitchProfile,
+    /// Dump the original or effective profile text
+    ProfileDump,
+    /// DNS cache
+    DnsCache,
+    /// Flush DNS cache
+    FlushDns,
+    /// Measure upstream DNS server delay
+    DnsDelay,
+    /// Get/set `/v1/features/*` switches
+    Features,
+    /// Download the MITM CA certificate
+    MitmCa,
+    /// List and toggle modules
+    Modules,
+    /// Event center entries
+    Events,
+    /// Traffic statistics per interface
+    Traffic,
+    /// Evaluate a script in a mocked environment
+    Scripting,
+    /// Stop the Surge engine
+    StopSurge,
+    /// Change the Surge log level
+    LogLevel,
+    /// Network diagnostics (DNS, router, internet, proxy)
+    Diagnostics,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::OutboundMode => "outbound mode",
+            Self::PolicyGroups => "policy groups",
+            Self::SelectPolicy => "select policy",
+            Self::GlobalPolicy => "global policy",
+            Self::TestPolicy => "test policy",
+            Self::TestPolicyGroup => "test policy group",
+            Self::TestAllPolicies => "test all policies",
+            Self::TestPolicyLatency => "test policy latency",
+            Self::Rules => "rules",
+            Self::RecentRequests => "recent requests",
+            Self::ActiveConnections => "active connections",
+            Self::KillConnection => "kill connection",
+            Self::ReloadConfig => "reload config",
+            Self::Profiles => "profiles",
+            Self::SwitchProfile => "switch profile",
+            Self::ProfileDump => "dump profile",
+            Self::DnsCache => "DNS cache",
+            Self::FlushDns => "flush DNS",
+            Self::DnsDelay => "DNS delay test",
+            Self::Features => "feature toggles",
+            Self::MitmCa => "MITM CA certificate",
+            Self::Modules => "modules",
+            Self::Events => "events",
+            Self::Traffic => "traffic",
+            Self::Scripting => "scripting",
+            Self::StopSurge => "stop Surge",
+            Self::LogLevel => "log level",
+            Self::Diagnostics => "network diagnostics",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Surge backend
+///
+/// Every operation has a default implementation returning
+/// `SurgeError::Unsupported`, so a backend only implements what it lists in
+/// `capabilities()`.
+#[async_trait]
+pub trait SurgeBackend: Send + Sync {
+    /// Backend name (used in errors and logs)
+    fn name(&self) -> &'static str;
+
+    /// Operations this backend supports
+    fn capabilities(&self) -> &'static [Operation];
+
+    /// Whether latency tests request `LatencyTest::url`
+    fn uses_test_url(&self) -> bool {
+        true
+    }
+
+    /// Whether this backend supports the operation
+    fn supports(&self, operation: Operation) -> bool {
+        self.capabilities().contains(&operation)
+    }
+
+    /// Whether the backend can currently reach Surge
+    async fn is_available(&self) -> bool;
+
+    // ===== Outbound mode =====
+
+    async fn get_outbound_mode(&self) -> Result<OutboundMode> {
+        Err(unsupported(self.name(), Operation::OutboundMode))
+    }
+
+    async fn set_outbound_mode(&self, _mode: OutboundMode) -> Result<()> {
+        Err(unsupported(self.name(), Operation::OutboundMode))
+    }
+
+    /// Policy used for all traffic in proxy mode
+    async fn get_global_policy(&self) -> Result<String> {
+        Err(unsupported(self.name(), Operation::GlobalPolicy))
+    }
+
+    async fn set_global_policy(&self, _policy: &str) -> Result<()> {
+        Err(unsupported(self.name(), Operation::GlobalPolicy))
+    }
+
+    // ===== Policies =====
+
+    /// Names of all proxies followed by all policy groups
+    async fn get_policies(&self) -> Result<Vec<String>> {
+        Err(unsupported(self.name(), Operation::PolicyGroups))
+    }
+
+    /// Policy groups, without selections (see `get_policy_group_selected`)
+    async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        Err(unsupported(self.name(), Operation::PolicyGroups))
+    }
+
+    /// Selected policy of a group (`None` for groups without a manual selection)
+    async fn get_policy_group_selected(&self, _group_name: &str) -> Result<Option<String>> {
+        Err(unsupported(self.name(), Operation::PolicyGroups))
+    }
+
+    async fn select_policy_group(&self, _group_name: &str, _policy: &str) -> Result<()> {
+        Err(unsupported(self.name(), Operation::SelectPolicy))
+    }
+
+    async fn test_policy(&self, _name: &str, _test: &LatencyTest) -> Result<()> {
+        Err(unsupported(self.name(), Operation::TestPolicy))
+    }
+
+    /// Re-test a group, returning its available policies (may be empty)
+    async fn test_policy_group(&self, _group_name: &str) -> Result<Vec<String>> {
+        Err(unsupported(self.name(), Operation::TestPolicyGroup))
+    }
+
+    async fn test_all_policies(&self) -> Result<Vec<PolicyDetail>> {
+        Err(unsupported(self.name(), Operation::TestAllPolicies))
+    }
+
+    /// Test the given policies, returning their latency
+    async fn test_policy_latency(
+        &self,
+        _names: &[String],
+        _test: &LatencyTest,
+    ) -> Result<Vec<PolicyDetail>> {
+        Err(unsupported(self.name(), Operation::TestPolicyLatency))
+    }
+
+    // ===== Rules =====
+
+    async fn get_rules(&self) -> Result<Vec<Rule>> {
+        Err(unsupported(self.name(), Operation::Rules))
+    }
+
+    // ===== Requests and connections =====
+
+    async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        Err(unsupported(self.name(), Operation::RecentRequests))
+    }
+
+    async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        Err(unsupported(self.name(), Operation::ActiveConnections))
+    }
+
+    async fn kill_connection(&self, _id: u64) -> Result<()> {
+        Err(unsupported(self.name(), Operation::KillConnection))
+    }
+
+    // ===== Configuration =====
+
+    async fn reload_config(&self) -> Result<()> {
+        Err(unsupported(self.name(), Operation::ReloadConfig))
+    }
+
+    /// Names of the available profiles
+    async fn get_profiles(&self) -> Result<Vec<String>> {
+        Err(unsupported(self.name(), Operation::Profiles))
+    }
+
+    async fn get_current_profile(&self, _show_sensitive: bool) -> Result<ProfileInfo> {
+        Err(unsupported(self.name(), Operation::Profiles))
+    }
+
+    async fn switch_profile(&self, _name: &str) -> Result<()> {
+        Err(unsupported(self.name(), Operation::SwitchProfile))
+    }
+
+    /// Profile text as written (`effective = false`) or with modules and
+    /// overrides applied (`effective = true`)
+    async fn dump_profile(&self, _effective: bool) -> Result<String> {
+        Err(unsupported(self.name(), Operation::ProfileDump))
+    }
+
+    // ===== DNS =====
+
+    async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
+        Err(unsupported(self.name(), Operation::DnsCache))
+    }
+
+    async fn flush_dns(&self) -> Result<()> {
+        Err(unsupported(self.name(), Operation::FlushDns))
+    }
+
+    async fn test_dns_delay(&self) -> Result<Vec<DnsDelay>> {
+        Err(unsupported(self.name(), Operation::DnsDelay))
+    }
+
+    // ===== Feature toggles =====
+
+    /// Fails when the Surge version does not know the feature
+    async fn get_feature(&self, _feature: Feature) -> Result<bool> {
+        Err(unsupported(self.name(), Operation::Features))
+    }
+
+    async fn set_feature(&self, _feature: Feature, _enabled: bool) -> Result<()> {
+        Err(unsupported(self.name(), Operation::Features))
+    }
+
+    /// DER-encoded MITM CA certificate
+    async fn get_mitm_ca(&self) -> Result<Vec<u8>> {
+        Err(unsupported(self.name(), Operation::MitmCa))
+    }
+
+    // ===== Modules =====
+
+    async fn get_modules(&self) -> Result<Vec<Module>> {
+        Err(unsupported(self.name(), Operation::Modules))
+    }
+
+    async fn set_module_enabled(&self, _name: &str, _enabled: bool) -> Result<()> {
+        Err(unsupported(self.name(), Operation::Modules))
+    }
+
+    // ===== Events =====
+
+    async fn get_events(&self) -> Result<Vec<Event>> {
+        Err(unsupported(self.name(), Operation::Events))
+    }
+
+    // ===== Traffic =====
+
+    async fn get_traffic(&self) -> Result<TrafficStats> {
+        Err(unsupported(self.name(), Operation::Traffic))
+    }
+
+    // ===== Scripting =====
+
+    /// Run a script with a mocked context, giving up after `timeout_secs`
+    async fn evaluate_script(
+        &self,
+        _script: &str,
+        _mock_type: ScriptMockType,
+        _timeout_secs: u64,
+    ) -> Result<ScriptEvaluation> {
+        Err(unsupported(self.name(), Operation::Scripting))
+    }
+
+    // ===== Engine control =====
+
+    async fn stop_surge(&self) -> Result<()> {
+        Err(unsupported(self.name(), Operation::StopSurge))
+    }
+
+    async fn set_log_level(&self, _level: LogLevel) -> Result<()> {
+        Err(unsupported(self.name(), Operation::LogLevel))
+    }
+
+    // ===== Diagnostics =====
+
+    /// Run the network diagnostics and report every check
+    async fn run_diagnostics(&self) -> Result<Vec<DiagnosticCheck>> {
+        Err(unsupported(self.name(), Operation::Diagnostics))
+    }
+}
+
+/// Error for an operation the backend does not implement
+pub fn unsupported(backend: &str, operation: Operation) -> SurgeError {
+    SurgeError::Unsupported {
+        backend: backend.to_string(),
+        operation: operation.to_string(),
+    }
+}
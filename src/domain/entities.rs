@@ -1,7 +1,15 @@
 /// Domain business entities
 ///
 /// Zero dependency - Pure business logic objects
-use super::models::{DnsRecord, OutboundMode, PolicyDetail, PolicyGroup, Request};
+use super::backend::Operation;
+use super::models::{
+    CaCertificate, DnsRecord, Event, Feature, Module, OutboundMode, PolicyDetail, PolicyGroup,
+    Request, Rule, TrafficStats,
+};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+/// Number of traffic samples kept for the sparklines
+pub const TRAFFIC_HISTORY_LEN: usize = 120;
 
 /// UI view mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +24,18 @@ pub enum ViewMode {
     ActiveConnections,
     /// DNS cache
     Dns,
+    /// Modules
+    Modules,
+    /// Profile text
+    Profile,
+    /// Event center
+    Events,
+    /// Script console
+    Scripting,
+    /// Network diagnostics
+    Diagnostics,
+    /// Rule list with hit counts
+    Rules,
 }
 
 impl ViewMode {
@@ -26,6 +46,12 @@ impl ViewMode {
             Self::Requests,
             Self::ActiveConnections,
             Self::Dns,
+            Self::Modules,
+            Self::Profile,
+            Self::Events,
+            Self::Scripting,
+            Self::Diagnostics,
+            Self::Rules,
         ]
     }
 }
@@ -74,6 +100,15 @@ impl Alert {
         }
     }
 
+    /// Remote Surge not reachable over the HTTP API
+    pub fn surge_unreachable() -> Self {
+        Self {
+            level: AlertLevel::Error,
+            message: "surge_unreachable".to_string(), // Message key for i18n
+            action: AlertAction::None,
+        }
+    }
+
     /// HTTP API unavailable
     pub fn http_api_disabled() -> Self {
         Self {
@@ -92,6 +127,15 @@ impl Alert {
         }
     }
 
+    /// Error reported by Surge's event center
+    pub fn event_error(content: String) -> Self {
+        Self {
+            level: AlertLevel::Error,
+            message: content,
+            action: AlertAction::None,
+        }
+    }
+
     /// General warning
     pub fn warning(message: String) -> Self {
         Self {
@@ -111,6 +155,39 @@ impl Alert {
     }
 }
 
+/// Recent upload/download speeds, oldest first (ring buffer)
+#[derive(Debug, Clone, Default)]
+pub struct TrafficHistory {
+    upload: VecDeque<u64>,
+    download: VecDeque<u64>,
+}
+
+impl TrafficHistory {
+    /// Record a sample, dropping the oldest beyond `TRAFFIC_HISTORY_LEN`
+    pub fn push(&mut self, stats: &TrafficStats) {
+        if self.upload.len() == TRAFFIC_HISTORY_LEN {
+            self.upload.pop_front();
+            self.download.pop_front();
+        }
+        self.upload.push_back(stats.upload_speed);
+        self.download.push_back(stats.download_speed);
+    }
+
+    /// Upload speeds (bytes/s)
+    pub fn upload(&self) -> Vec<u64> {
+        self.upload.iter().copied().collect()
+    }
+
+    /// Download speeds (bytes/s)
+    pub fn download(&self) -> Vec<u64> {
+        self.download.iter().copied().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upload.is_empty()
+    }
+}
+
 /// Application state snapshot
 #[derive(Debug, Clone)]
 pub struct AppSnapshot {
@@ -118,24 +195,40 @@ pub struct AppSnapshot {
     pub surge_running: bool,
     /// Whether HTTP API is available
     pub http_api_available: bool,
+    /// Whether connected to a remote host (HTTP API only, no local CLI/system actions)
+    pub remote_mode: bool,
     /// Current outbound mode
     pub outbound_mode: Option<OutboundMode>,
-    /// Whether MITM is enabled
-    pub mitm_enabled: Option<bool>,
-    /// Whether Capture is enabled
-    pub capture_enabled: Option<bool>,
+    /// Global policy (used in proxy mode)
+    pub global_policy: Option<String>,
+    /// Feature switches (missing: not supported by this Surge version)
+    pub features: BTreeMap<Feature, bool>,
+    /// MITM CA certificate
+    pub mitm_ca: Option<CaCertificate>,
     /// Policy list
     pub policies: Vec<PolicyDetail>,
     /// Policy group list
     pub policy_groups: Vec<PolicyGroup>,
+    /// Rules in profile order
+    pub rules: Vec<Rule>,
     /// Recent requests
     pub recent_requests: Vec<Request>,
     /// Active connections
     pub active_connections: Vec<Request>,
     /// DNS cache
     pub dns_cache: Vec<DnsRecord>,
+    /// Modules (available and enabled)
+    pub modules: Vec<Module>,
+    /// Event center entries
+    pub events: Vec<Event>,
+    /// Latest traffic statistics
+    pub traffic: Option<TrafficStats>,
+    /// Speeds of previous snapshots
+    pub traffic_history: TrafficHistory,
     /// Current alerts
     pub alerts: Vec<Alert>,
+    /// Operations available in the current mode
+    pub capabilities: Vec<Operation>,
 }
 
 impl AppSnapshot {
@@ -143,15 +236,23 @@ impl AppSnapshot {
         Self {
             surge_running: false,
             http_api_available: false,
+            remote_mode: false,
             outbound_mode: None,
-            mitm_enabled: None,
-            capture_enabled: None,
+            global_policy: None,
+            features: BTreeMap::new(),
+            mitm_ca: None,
             policies: Vec::new(),
             policy_groups: Vec::new(),
+            rules: Vec::new(),
             recent_requests: Vec::new(),
             active_connections: Vec::new(),
             dns_cache: Vec::new(),
+            modules: Vec::new(),
+            events: Vec::new(),
+            traffic: None,
+            traffic_history: TrafficHistory::default(),
             alerts: Vec::new(),
+            capabilities: Vec::new(),
         }
     }
 
@@ -160,6 +261,73 @@ impl AppSnapshot {
         self.alerts.push(alert);
     }
 
+    /// Whether the operation was available when the snapshot was taken
+    pub fn supports(&self, operation: Operation) -> bool {
+        self.capabilities.contains(&operation)
+    }
+
+    /// Whether the feature is enabled (None when unsupported or unknown)
+    pub fn feature(&self, feature: Feature) -> Option<bool> {
+        self.features.get(&feature).copied()
+    }
+
+    /// Recursively find the final real policy selected in a policy group (not another group)
+    ///
+    /// Example: Proxy → US_Servers → us-bwg-la-dc1-vmess
+    /// Returns: Some("us-bwg-la-dc1-vmess")
+    pub fn resolve_final_policy(&self, policy_name: &str) -> Option<String> {
+        self.resolve_policy(policy_name, &mut HashSet::new())
+    }
+
+    fn resolve_policy(&self, policy_name: &str, visited: &mut HashSet<String>) -> Option<String> {
+        // Prevent circular references
+        if visited.contains(policy_name) || visited.len() > 10 {
+            return None;
+        }
+        visited.insert(policy_name.to_string());
+
+        // Check if this is a policy group
+        if let Some(group) = self.policy_groups.iter().find(|g| g.name == policy_name) {
+            // It is a group: recursively find its selected policy
+            // (None when the group has no selected policy)
+            return group
+                .selected
+                .as_ref()
+                .and_then(|selected| self.resolve_policy(selected, visited));
+        }
+
+        // Not a group: this is a real policy
+        Some(policy_name.to_string())
+    }
+
+    /// Real policies reachable from a policy group through its members
+    ///
+    /// Nested groups are expanded (each policy listed once, in member order)
+    pub fn group_leaf_policies(&self, group_name: &str) -> Vec<String> {
+        let mut policies = Vec::new();
+        self.collect_leaf_policies(group_name, &mut HashSet::new(), &mut policies);
+        policies
+    }
+
+    fn collect_leaf_policies(
+        &self,
+        policy_name: &str,
+        visited: &mut HashSet<String>,
+        policies: &mut Vec<String>,
+    ) {
+        if !visited.insert(policy_name.to_string()) {
+            return;
+        }
+        match self.policy_groups.iter().find(|g| g.name == policy_name) {
+            Some(group) => {
+                for member in &group.policies {
+                    self.collect_leaf_policies(&member.name, visited, policies);
+                }
+            }
+            None => policies.push(policy_name.to_string()),
+        }
+    }
+
     /// Clear all alerts
     pub fn clear_alerts(&mut self) {
         self.alerts.clear();
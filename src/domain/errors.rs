@@ -9,9 +9,15 @@ pub enum SurgeError {
     /// Surge process not running
     SurgeNotRunning,
 
+    /// Surge process did not exit in time (restart aborted)
+    SurgeStillRunning,
+
     /// HTTP API unavailable (not enabled or cannot connect)
     HttpApiUnavailable { reason: String },
 
+    /// HTTP API answered with a non-2xx status
+    HttpStatus { path: String, status: u16 },
+
     /// CLI execution failed
     CliExecutionFailed { command: String, error: String },
 
@@ -27,6 +33,12 @@ pub enum SurgeError {
     /// Connection not found
     ConnectionNotFound { id: u64 },
 
+    /// Module not found
+    ModuleNotFound { name: String },
+
+    /// Profile not found
+    ProfileNotFound { name: String },
+
     /// Parse error (JSON or text)
     ParseError { source: String, error: String },
 
@@ -36,6 +48,12 @@ pub enum SurgeError {
     /// Permission denied
     PermissionDenied { message: String },
 
+    /// Operation needs local access to the Surge machine (remote mode)
+    RemoteModeUnsupported { operation: String },
+
+    /// Operation not supported by the backend
+    Unsupported { backend: String, operation: String },
+
     /// Other unknown error
     Unknown { message: String },
 }
@@ -46,9 +64,19 @@ impl fmt::Display for SurgeError {
             Self::SurgeNotRunning => {
                 write!(f, "Surge is not running")
             }
+            Self::SurgeStillRunning => {
+                write!(f, "Surge is still running after stop")
+            }
             Self::HttpApiUnavailable { reason } => {
                 write!(f, "HTTP API unavailable: {}", reason)
             }
+            Self::HttpStatus { path, status } => {
+                write!(
+                    f,
+                    "HTTP API unavailable: HTTP {} returned status {}",
+                    path, status
+                )
+            }
             Self::CliExecutionFailed { command, error } => {
                 write!(f, "CLI command failed: {} - {}", command, error)
             }
@@ -64,6 +92,12 @@ impl fmt::Display for SurgeError {
             Self::ConnectionNotFound { id } => {
                 write!(f, "Connection not found: #{}", id)
             }
+            Self::ModuleNotFound { name } => {
+                write!(f, "Module not found: {}", name)
+            }
+            Self::ProfileNotFound { name } => {
+                write!(f, "Profile not found: {}", name)
+            }
             Self::ParseError { source, error } => {
                 write!(f, "Parse error ({}): {}", source, error)
             }
@@ -73,6 +107,12 @@ impl fmt::Display for SurgeError {
             Self::PermissionDenied { message } => {
                 write!(f, "Permission denied: {}", message)
             }
+            Self::RemoteModeUnsupported { operation } => {
+                write!(f, "Not available in remote mode: {}", operation)
+            }
+            Self::Unsupported { backend, operation } => {
+                write!(f, "{} does not support: {}", backend, operation)
+            }
             Self::Unknown { message } => {
                 write!(f, "Unknown error: {}", message)
             }
@@ -80,6 +120,23 @@ impl fmt::Display for SurgeError {
     }
 }
 
+impl SurgeError {
+    /// Whether the HTTP API rejected the request itself (4xx), as opposed to
+    /// a network failure or server error
+    pub fn is_client_error(&self) -> bool {
+        matches!(self, Self::HttpStatus { status, .. } if (400..500).contains(status))
+    }
+
+    /// Whether the operation or resource does not exist (HTTP 404, or an
+    /// operation the backend does not support)
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::HttpStatus { status: 404, .. } | Self::Unsupported { .. }
+        )
+    }
+}
+
 impl std::error::Error for SurgeError {}
 
 /// Result type alias
@@ -1,11 +1,15 @@
 /// Domain layer - Core business logic
 ///
 /// Zero dependency principle: No dependencies on infrastructure layer (HTTP, CLI, System)
+pub mod backend;
 pub mod entities;
 pub mod errors;
 pub mod models;
+pub mod profile;
+pub mod rule_match;
 
 // Re-export commonly used types
+pub use backend::{Operation, SurgeBackend};
 pub use entities::{Alert, AlertAction, AlertLevel, AppSnapshot, ViewMode};
 pub use errors::{Result, SurgeError};
 pub use models::*;
@@ -1,7 +1,10 @@
 /// Domain data models
 ///
 /// Corresponds to Surge HTTP API response structures
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
 /// Outbound mode
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -25,12 +28,120 @@ pub struct OutboundModeResponse {
     pub mode: OutboundMode,
 }
 
+/// Global outbound policy response (`/v1/outbound/global`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalPolicyResponse {
+    pub policy: String,
+}
+
 /// Feature toggle status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureStatus {
     pub enabled: bool,
 }
 
+/// Switch under `/v1/features/*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Feature {
+    Mitm,
+    Capture,
+    Rewrite,
+    Scripting,
+    /// macOS only
+    SystemProxy,
+    /// macOS only
+    EnhancedMode,
+}
+
+impl Feature {
+    /// Every feature, in display order
+    pub const ALL: [Self; 6] = [
+        Self::Mitm,
+        Self::Capture,
+        Self::Rewrite,
+        Self::Scripting,
+        Self::SystemProxy,
+        Self::EnhancedMode,
+    ];
+
+    /// API name (e.g. "system_proxy")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mitm => "mitm",
+            Self::Capture => "capture",
+            Self::Rewrite => "rewrite",
+            Self::Scripting => "scripting",
+            Self::SystemProxy => "system_proxy",
+            Self::EnhancedMode => "enhanced_mode",
+        }
+    }
+}
+
+/// Area covered by a network diagnostics check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiagnosticSection {
+    Dns,
+    Router,
+    Internet,
+    Proxy,
+    /// Checks that mention none of the above
+    Other,
+}
+
+impl DiagnosticSection {
+    /// Every section, in display order
+    pub const ALL: [Self; 5] = [
+        Self::Dns,
+        Self::Router,
+        Self::Internet,
+        Self::Proxy,
+        Self::Other,
+    ];
+}
+
+/// Outcome of a diagnostics check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStatus {
+    Pass,
+    Fail,
+    /// Informational line without a verdict
+    Info,
+}
+
+/// One check reported by `surge-cli diagnostics` or `test-network`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticCheck {
+    pub section: DiagnosticSection,
+    pub name: String,
+    /// Result text as printed (e.g. "12 ms", "Failed")
+    pub detail: String,
+    pub status: DiagnosticStatus,
+}
+
+/// Surge log level (`surge-cli set-log-level`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Verbose,
+    Info,
+    Notify,
+    Warning,
+}
+
+impl LogLevel {
+    /// Every level, most detailed first
+    pub const ALL: [Self; 4] = [Self::Verbose, Self::Info, Self::Notify, Self::Warning];
+
+    /// Name understood by Surge (e.g. "notify")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Verbose => "verbose",
+            Self::Info => "info",
+            Self::Notify => "notify",
+            Self::Warning => "warning",
+        }
+    }
+}
+
 /// Policy type
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -80,6 +191,26 @@ impl PolicyType {
     }
 }
 
+/// How a latency test is run (from `[testing]`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyTest {
+    /// URL requested through each policy
+    pub url: String,
+    /// A policy slower than this counts as unavailable
+    pub timeout: Duration,
+    /// Policies tested at once where each is tested separately (at least 1)
+    pub parallelism: usize,
+}
+
+/// Latency test outcome
+#[derive(Debug, Clone)]
+pub struct LatencyReport {
+    pub results: Vec<PolicyDetail>,
+    /// The configured test URL was not used (surge-cli tests with the
+    /// profile's `proxy-test-url`)
+    pub url_ignored: bool,
+}
+
 /// Policy detail
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyDetail {
@@ -133,6 +264,98 @@ pub struct PolicyGroupSelectResponse {
     pub policy: String,
 }
 
+/// Test result of one policy (`/v1/policies/test`, `/v1/policy_groups/test_results`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyTestResult {
+    #[serde(default)]
+    pub available: bool,
+    /// Round-trip time (ms), present when available
+    #[serde(default)]
+    pub rtt: Option<u32>,
+    /// Failure reason ("timeout", ...)
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Policy test response (policy name → result)
+pub type PolicyTestResponse = std::collections::HashMap<String, PolicyTestResult>;
+
+/// Policy group test results (group name → member name → result)
+pub type PolicyGroupTestResults = std::collections::HashMap<String, PolicyTestResponse>;
+
+/// Rule from the profile's `[Rule]` section, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// Rule type, upper case ("DOMAIN-SUFFIX", "FINAL", ...)
+    pub rule_type: String,
+    /// Matched value (`None` for FINAL)
+    pub value: Option<String>,
+    pub policy: String,
+    /// Trailing options ("no-resolve", "extended-matching", ...)
+    pub options: Vec<String>,
+}
+
+impl Rule {
+    /// Parse a rule line ("DOMAIN-SUFFIX,google.com,Proxy,no-resolve")
+    ///
+    /// Commas inside parentheses or quotes do not split fields, so logical
+    /// rules (`AND,((DOMAIN,a.com),(DEST-PORT,443)),Proxy`) stay whole.
+    /// Comments and lines without a policy return `None`.
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+            return None;
+        }
+
+        let mut fields = Vec::new();
+        let (mut depth, mut quoted, mut start) = (0usize, false, 0);
+        for (i, c) in line.char_indices() {
+            match c {
+                '"' => quoted = !quoted,
+                '(' if !quoted => depth += 1,
+                ')' if !quoted => depth = depth.saturating_sub(1),
+                ',' if !quoted && depth == 0 => {
+                    fields.push(line[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        fields.push(line[start..].trim());
+
+        let rule_type = fields[0].to_uppercase();
+        // FINAL has no value: "FINAL,Proxy,dns-failed"
+        let value_fields = usize::from(rule_type != "FINAL");
+        let policy = fields.get(value_fields + 1)?.trim_matches('"').to_string();
+        if policy.is_empty() {
+            return None;
+        }
+        Some(Self {
+            value: (value_fields == 1).then(|| fields[1].trim_matches('"').to_string()),
+            policy,
+            options: fields[value_fields + 2..]
+                .iter()
+                .map(|o| o.to_string())
+                .collect(),
+            rule_type,
+        })
+    }
+
+    /// The rule as `Request.rule` reports it ("DOMAIN-SUFFIX google.com", "FINAL")
+    pub fn request_key(&self) -> String {
+        match self.value {
+            Some(ref value) => format!("{} {}", self.rule_type, value),
+            None => self.rule_type.clone(),
+        }
+    }
+}
+
+/// Rule list response (`/v1/rules`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesResponse {
+    pub rules: Vec<String>,
+}
+
 /// Request detail (real API format)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Request {
@@ -203,6 +426,66 @@ pub struct DnsResponse {
     pub records: Vec<DnsRecord>,
 }
 
+/// DNS delay of one upstream server (`/v1/test/dns_delay`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DnsDelay {
+    /// Upstream server (empty when Surge reports a single overall delay)
+    #[serde(default, alias = "address")]
+    pub server: String,
+    /// Delay in seconds (None when the server failed)
+    #[serde(default, alias = "time_cost", alias = "timeCost")]
+    pub delay: Option<f64>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// DNS delay test response
+///
+/// Either per-server results or a single delay over all servers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DnsDelayResponse {
+    PerServer {
+        #[serde(alias = "servers")]
+        results: Vec<DnsDelay>,
+    },
+    Overall {
+        delay: f64,
+    },
+}
+
+impl DnsDelayResponse {
+    pub fn into_results(self) -> Vec<DnsDelay> {
+        match self {
+            Self::PerServer { results } => results,
+            Self::Overall { delay } => vec![DnsDelay {
+                server: String::new(),
+                delay: Some(delay),
+                error: None,
+            }],
+        }
+    }
+}
+
+/// MITM CA certificate (`/v1/mitm/ca`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaCertificate {
+    /// DER encoding, as served by Surge
+    pub der: Vec<u8>,
+    pub subject: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// SHA-256 fingerprint ("AB:CD:...")
+    pub fingerprint: String,
+}
+
+impl CaCertificate {
+    /// Whole days until the certificate expires (negative once expired)
+    pub fn days_until_expiry(&self, now: DateTime<Utc>) -> i64 {
+        (self.not_after - now).num_days()
+    }
+}
+
 /// Profile information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileInfo {
@@ -211,8 +494,265 @@ pub struct ProfileInfo {
     pub content: Option<String>,
 }
 
-/// Traffic statistics
+impl ProfileInfo {
+    /// Whether this is the named profile (the `.conf` extension is optional on both sides)
+    pub fn matches(&self, name: &str) -> bool {
+        profile_name(&self.name) == profile_name(name)
+    }
+}
+
+/// Profile name without the `.conf` extension
+pub fn profile_name(name: &str) -> &str {
+    name.strip_suffix(".conf").unwrap_or(name)
+}
+
+/// Profile list response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfilesResponse {
+    pub profiles: Vec<String>,
+}
+
+/// Surge module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub enabled: bool,
+    pub description: Option<String>,
+}
+
+/// Available module entry (a plain name, or an object with a description)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ModuleEntry {
+    Name(String),
+    Detail {
+        name: String,
+        #[serde(default)]
+        description: Option<String>,
+    },
+}
+
+/// Module list response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModulesResponse {
+    #[serde(default)]
+    pub enabled: Vec<String>,
+    #[serde(default)]
+    pub available: Vec<ModuleEntry>,
+}
+
+impl ModulesResponse {
+    /// Available modules with their enabled state (enabled modules missing
+    /// from `available` are included too)
+    pub fn into_modules(self) -> Vec<Module> {
+        let mut modules: Vec<Module> = self
+            .available
+            .into_iter()
+            .map(|entry| {
+                let (name, description) = match entry {
+                    ModuleEntry::Name(name) => (name, None),
+                    ModuleEntry::Detail { name, description } => (name, description),
+                };
+                Module {
+                    enabled: self.enabled.contains(&name),
+                    name,
+                    description,
+                }
+            })
+            .collect();
+
+        for name in self.enabled {
+            if !modules.iter().any(|m| m.name == name) {
+                modules.push(Module {
+                    name,
+                    enabled: true,
+                    description: None,
+                });
+            }
+        }
+        modules
+    }
+}
+
+/// Event severity (`type` in `/v1/events`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum EventLevel {
+    /// Notice (0)
+    #[default]
+    Info,
+    /// Warning (1)
+    Warning,
+    /// Error (2)
+    Error,
+}
+
+impl From<u8> for EventLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Info,
+            1 => Self::Warning,
+            _ => Self::Error,
+        }
+    }
+}
+
+impl From<EventLevel> for u8 {
+    fn from(level: EventLevel) -> Self {
+        match level {
+            EventLevel::Info => 0,
+            EventLevel::Warning => 1,
+            EventLevel::Error => 2,
+        }
+    }
+}
+
+/// Event center entry (config errors, policy failures, network changes, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    pub identifier: String,
+    /// ISO 8601 time (e.g. "2025-10-16T08:00:00Z")
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default, rename = "type")]
+    pub level: EventLevel,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default, rename = "allowDismiss")]
+    pub allow_dismiss: bool,
+}
+
+/// Event list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventsResponse {
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
+/// Script type whose environment is mocked by `/v1/scripting/evaluate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScriptMockType {
+    Generic,
+    HttpRequest,
+    HttpResponse,
+    Cron,
+    Event,
+    Dns,
+    Rule,
+}
+
+impl ScriptMockType {
+    /// Every mock type, in cycling order
+    pub const ALL: [Self; 7] = [
+        Self::Generic,
+        Self::HttpRequest,
+        Self::HttpResponse,
+        Self::Cron,
+        Self::Event,
+        Self::Dns,
+        Self::Rule,
+    ];
+
+    /// API value (e.g. "http-request")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "generic",
+            Self::HttpRequest => "http-request",
+            Self::HttpResponse => "http-response",
+            Self::Cron => "cron",
+            Self::Event => "event",
+            Self::Dns => "dns",
+            Self::Rule => "rule",
+        }
+    }
+
+    /// Next mock type (wraps around)
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Script evaluation response
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScriptEvaluation {
+    /// Value passed to `$done()`
+    #[serde(default)]
+    pub result: serde_json::Value,
+    /// Console output (a string, or an array of lines)
+    #[serde(default, alias = "logs")]
+    pub output: serde_json::Value,
+    /// Uncaught exception or evaluation error
+    #[serde(default, alias = "error")]
+    pub exception: Option<String>,
+}
+
+impl ScriptEvaluation {
+    /// Console output split into lines
+    pub fn log_lines(&self) -> Vec<String> {
+        match self.output {
+            serde_json::Value::String(ref text) => text.lines().map(str::to_string).collect(),
+            serde_json::Value::Array(ref lines) => lines
+                .iter()
+                .map(|line| match line.as_str() {
+                    Some(text) => text.to_string(),
+                    None => line.to_string(),
+                })
+                .collect(),
+            serde_json::Value::Null => Vec::new(),
+            ref other => vec![other.to_string()],
+        }
+    }
+}
+
+/// Traffic counters of one network interface or connector (`/v1/traffic`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterfaceTraffic {
+    #[serde(default, rename = "in")]
+    pub in_bytes: u64, // Download bytes
+    #[serde(default, rename = "out")]
+    pub out_bytes: u64, // Upload bytes
+    #[serde(default, rename = "inCurrentSpeed")]
+    pub in_current_speed: u64, // bytes/s
+    #[serde(default, rename = "outCurrentSpeed")]
+    pub out_current_speed: u64, // bytes/s
+    #[serde(default, rename = "inMaxSpeed")]
+    pub in_max_speed: u64, // bytes/s
+    #[serde(default, rename = "outMaxSpeed")]
+    pub out_max_speed: u64, // bytes/s
+}
+
+/// Traffic response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficResponse {
+    #[serde(default, rename = "startTime")]
+    pub start_time: Option<f64>,
+    /// Per network interface (e.g. "en0")
+    #[serde(default)]
+    pub interface: BTreeMap<String, InterfaceTraffic>,
+    /// Per policy connector
+    #[serde(default)]
+    pub connector: BTreeMap<String, InterfaceTraffic>,
+}
+
+impl TrafficResponse {
+    /// Totals and current speed summed over all interfaces
+    pub fn into_stats(self) -> TrafficStats {
+        let mut stats = TrafficStats::default();
+        for traffic in self.interface.values() {
+            stats.upload += traffic.out_bytes;
+            stats.download += traffic.in_bytes;
+            stats.upload_speed += traffic.out_current_speed;
+            stats.download_speed += traffic.in_current_speed;
+        }
+        stats.interfaces = self.interface;
+        stats
+    }
+}
+
+/// Traffic statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TrafficStats {
     #[serde(default)]
     pub upload: u64, // bytes
@@ -222,4 +762,7 @@ pub struct TrafficStats {
     pub upload_speed: u64, // bytes/s
     #[serde(default)]
     pub download_speed: u64, // bytes/s
+    /// Per-interface counters (empty when estimated from connections)
+    #[serde(default)]
+    pub interfaces: BTreeMap<String, InterfaceTraffic>,
 }
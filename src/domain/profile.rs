@@ -0,0 +1,161 @@
+/// Profile text helpers
+///
+/// Surge profiles are INI-like: `[Section]` headers followed by `key = value`
+/// lines or comma-separated entries (rules, proxies). These helpers only look
+/// at the text; they never validate it.
+use super::models::Rule;
+
+/// Replacement for masked values
+pub const MASK: &str = "********";
+
+/// Keys whose whole value is sensitive (`key = value` lines)
+const SENSITIVE_KEYS: &[&str] = &["ca-passphrase", "ca-p12", "private-key", "preshared-key"];
+
+/// Keys whose value is `secret@host:port` (only the secret is masked)
+const CREDENTIAL_KEYS: &[&str] = &[
+    "http-api",
+    "http-api-web-dashboard",
+    "external-controller-access",
+];
+
+/// Sensitive `name=value` parameters of proxy lines
+const SENSITIVE_PARAMS: &[&str] = &[
+    "password",
+    "passwd",
+    "psk",
+    "private-key",
+    "preshared-key",
+    "token",
+    "secret",
+    "uuid",
+    "ca-passphrase",
+];
+
+/// Proxy types taking `username, password` as positional parameters
+const POSITIONAL_AUTH_TYPES: &[&str] = &["http", "https", "socks5", "socks5-tls"];
+
+/// Profile section header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSection {
+    /// Section name without brackets (e.g. "Proxy Group")
+    pub name: String,
+    /// Zero-based line index of the header
+    pub line: usize,
+}
+
+/// Section name if the line is a `[Section]` header
+pub fn section_header(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .map(str::trim)
+}
+
+/// Whether the line is a comment (`#`, `;` or `//`)
+pub fn is_comment(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with('#') || trimmed.starts_with(';') || trimmed.starts_with("//")
+}
+
+/// Section headers in order of appearance
+pub fn sections(text: &str) -> Vec<ProfileSection> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(line, content)| {
+            section_header(content).map(|name| ProfileSection {
+                name: name.to_string(),
+                line,
+            })
+        })
+        .collect()
+}
+
+/// Rules of the `[Rule]` section in order (lines that do not parse are skipped)
+pub fn rules(text: &str) -> Vec<Rule> {
+    let mut in_rules = false;
+    text.lines()
+        .filter_map(|line| {
+            if let Some(name) = section_header(line) {
+                in_rules = name.eq_ignore_ascii_case("Rule");
+                return None;
+            }
+            if !in_rules || is_comment(line) {
+                return None;
+            }
+            Rule::parse(line)
+        })
+        .collect()
+}
+
+/// Replace passwords, keys and API credentials with `MASK`
+///
+/// Masking an already masked profile (e.g. one fetched with `sensitive=0`)
+/// leaves it unchanged.
+pub fn mask_sensitive(text: &str) -> String {
+    let mut masked: Vec<String> = text.lines().map(mask_line).collect();
+    if text.ends_with('\n') {
+        masked.push(String::new());
+    }
+    masked.join("\n")
+}
+
+/// Mask one profile line
+fn mask_line(line: &str) -> String {
+    if is_comment(line) || section_header(line).is_some() {
+        return line.to_string();
+    }
+    let Some((key_part, value)) = line.split_once('=') else {
+        return line.to_string();
+    };
+    let key = key_part.trim().to_lowercase();
+
+    let masked_value = if SENSITIVE_KEYS.contains(&key.as_str()) {
+        mask_whole(value)
+    } else if CREDENTIAL_KEYS.contains(&key.as_str()) {
+        match value.rsplit_once('@') {
+            Some((secret, address)) => format!("{}@{}", mask_whole(secret), address),
+            None => value.to_string(),
+        }
+    } else if value.contains(',') {
+        mask_params(value)
+    } else {
+        value.to_string()
+    };
+    format!("{}={}", key_part, masked_value)
+}
+
+/// Mask a value, keeping its surrounding whitespace
+fn mask_whole(value: &str) -> String {
+    if value.trim().is_empty() {
+        return value.to_string();
+    }
+    let leading = &value[..value.len() - value.trim_start().len()];
+    let trailing = &value[value.trim_end().len()..];
+    format!("{}{}{}", leading, MASK, trailing)
+}
+
+/// Mask sensitive parameters of a comma-separated proxy definition
+fn mask_params(value: &str) -> String {
+    let items: Vec<&str> = value.split(',').collect();
+    let positional_auth = items
+        .first()
+        .is_some_and(|t| POSITIONAL_AUTH_TYPES.contains(&t.trim().to_lowercase().as_str()));
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| match item.split_once('=') {
+            Some((name, param)) => {
+                if SENSITIVE_PARAMS.contains(&name.trim().to_lowercase().as_str()) {
+                    format!("{}={}", name, mask_whole(param))
+                } else {
+                    item.to_string()
+                }
+            }
+            // type, server, port, username, password
+            None if positional_auth && index == 4 => mask_whole(item),
+            None => item.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
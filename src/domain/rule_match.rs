@@ -0,0 +1,313 @@
+/// Offline rule matching
+///
+/// Walks a rule list the way Surge walks `[Rule]`: top to bottom, the first
+/// matching rule wins. Nothing is looked up, so IP rules only match an IP
+/// given directly and a hostname never matches them.
+use super::models::Rule;
+use std::net::IpAddr;
+
+/// Rule types the simulator evaluates; other types are skipped
+pub const SUPPORTED_RULE_TYPES: &[&str] = &[
+    "DOMAIN",
+    "DOMAIN-SUFFIX",
+    "DOMAIN-KEYWORD",
+    "IP-CIDR",
+    "IP-CIDR6",
+    "PROCESS-NAME",
+    "DEST-PORT",
+    "FINAL",
+];
+
+/// What a connection looks like to the rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchQuery {
+    /// Lower-case hostname
+    pub host: Option<String>,
+    pub ip: Option<IpAddr>,
+    pub port: Option<u16>,
+    /// Process name (file name of the executable)
+    pub process: Option<String>,
+}
+
+impl MatchQuery {
+    /// Parse whitespace-separated terms
+    ///
+    /// - a URL (`https://host/path`, default port from the scheme), `host`,
+    ///   `host:port`, an IP or `[v6]:port`
+    /// - a bare number: destination port
+    /// - `process=NAME` (a path is reduced to its file name)
+    pub fn parse(input: &str) -> Self {
+        let mut query = Self::default();
+        for term in input.split_whitespace() {
+            if let Some(process) = term
+                .strip_prefix("process=")
+                .or_else(|| term.strip_prefix("process:"))
+            {
+                let name = process.rsplit('/').next().unwrap_or(process);
+                query.process = Some(name.to_string());
+            } else if let Ok(port) = term.parse::<u16>() {
+                query.port = Some(port);
+            } else {
+                query.add_address(term);
+            }
+        }
+        query
+    }
+
+    /// Host or IP with an optional scheme, port and path
+    fn add_address(&mut self, term: &str) {
+        let (scheme, rest) = match term.split_once("://") {
+            Some((scheme, rest)) => (Some(scheme.to_lowercase()), rest),
+            None => (None, term),
+        };
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+        let authority = authority.rsplit('@').next().unwrap_or(authority);
+
+        let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+            // [2001:db8::1]:443
+            match bracketed.split_once(']') {
+                Some((host, rest)) => (host, rest.strip_prefix(':')),
+                None => (bracketed, None),
+            }
+        } else if authority.matches(':').count() == 1 {
+            let (host, port) = authority.split_once(':').unwrap_or((authority, ""));
+            (host, Some(port))
+        } else {
+            (authority, None)
+        };
+
+        let port = port
+            .and_then(|p| p.parse().ok())
+            .or(match scheme.as_deref() {
+                Some("http" | "ws") => Some(80),
+                Some("https" | "wss") => Some(443),
+                _ => None,
+            });
+        if port.is_some() {
+            self.port = port;
+        }
+
+        match host.parse::<IpAddr>() {
+            Ok(ip) => self.ip = Some(ip),
+            Err(_) if !host.is_empty() => {
+                self.host = Some(host.trim_end_matches('.').to_lowercase());
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Whether nothing was given
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Result of walking the rule list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleMatch {
+    /// Index of the first matching rule (None: not even FINAL matched)
+    pub index: Option<usize>,
+    /// Unsupported rule types skipped before the match (distinct, in order);
+    /// any of them could have matched first in Surge
+    pub skipped_types: Vec<String>,
+}
+
+/// Find the first rule matching the query
+pub fn evaluate(rules: &[Rule], query: &MatchQuery) -> RuleMatch {
+    let mut result = RuleMatch::default();
+    for (index, rule) in rules.iter().enumerate() {
+        match matches(rule, query) {
+            Some(true) => {
+                result.index = Some(index);
+                break;
+            }
+            Some(false) => {}
+            None => {
+                if !result.skipped_types.contains(&rule.rule_type) {
+                    result.skipped_types.push(rule.rule_type.clone());
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Whether the rule matches (None: rule type not supported)
+fn matches(rule: &Rule, query: &MatchQuery) -> Option<bool> {
+    let value = rule.value.as_deref().unwrap_or("");
+    let host = query.host.as_deref();
+
+    let matched = match rule.rule_type.as_str() {
+        "FINAL" => true,
+        "DOMAIN" => host.is_some_and(|h| h.eq_ignore_ascii_case(value)),
+        "DOMAIN-SUFFIX" => host.is_some_and(|h| {
+            let suffix = value.trim_start_matches('.').to_lowercase();
+            h == suffix || h.ends_with(&format!(".{}", suffix))
+        }),
+        "DOMAIN-KEYWORD" => host.is_some_and(|h| h.contains(&value.to_lowercase())),
+        "IP-CIDR" | "IP-CIDR6" => query.ip.is_some_and(|ip| cidr_contains(value, ip)),
+        "PROCESS-NAME" => query
+            .process
+            .as_deref()
+            .is_some_and(|p| p.eq_ignore_ascii_case(value)),
+        "DEST-PORT" => query.port.is_some_and(|port| port_matches(value, port)),
+        _ => return None,
+    };
+    Some(matched)
+}
+
+/// "10.0.0.0/8" contains the IP (a bare address matches only itself)
+fn cidr_contains(cidr: &str, ip: IpAddr) -> bool {
+    let (network, prefix) = match cidr.split_once('/') {
+        Some((network, prefix)) => (network, prefix.parse::<u32>().ok()),
+        None => (cidr, None),
+    };
+    match (network.trim().parse::<IpAddr>(), ip) {
+        (Ok(IpAddr::V4(network)), IpAddr::V4(ip)) => {
+            let prefix = prefix.unwrap_or(32).min(32);
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(network) & mask == u32::from(ip) & mask
+        }
+        (Ok(IpAddr::V6(network)), IpAddr::V6(ip)) => {
+            let prefix = prefix.unwrap_or(128).min(128);
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(network) & mask == u128::from(ip) & mask
+        }
+        _ => false,
+    }
+}
+
+/// "443" or a range "8000-9000"
+fn port_matches(value: &str, port: u16) -> bool {
+    match value.split_once('-') {
+        Some((low, high)) => match (low.trim().parse::<u16>(), high.trim().parse::<u16>()) {
+            (Ok(low), Ok(high)) => (low..=high).contains(&port),
+            _ => false,
+        },
+        None => value.trim().parse() == Ok(port),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(lines: &[&str]) -> Vec<Rule> {
+        lines
+            .iter()
+            .map(|line| Rule::parse(line).unwrap())
+            .collect()
+    }
+
+    /// Index of the rule the input hits
+    fn hit(lines: &[&str], input: &str) -> Option<usize> {
+        evaluate(&rules(lines), &MatchQuery::parse(input)).index
+    }
+
+    #[test]
+    fn parse_url_with_default_port() {
+        let query = MatchQuery::parse("https://WWW.Example.com./path?q=1");
+        assert_eq!(query.host.as_deref(), Some("www.example.com"));
+        assert_eq!(query.port, Some(443));
+        assert_eq!(query.ip, None);
+    }
+
+    #[test]
+    fn parse_host_port_and_ips() {
+        let query = MatchQuery::parse("example.com:8080");
+        assert_eq!(query.host.as_deref(), Some("example.com"));
+        assert_eq!(query.port, Some(8080));
+
+        let query = MatchQuery::parse("[2001:db8::1]:443");
+        assert_eq!(query.ip, Some("2001:db8::1".parse().unwrap()));
+        assert_eq!(query.port, Some(443));
+
+        let query = MatchQuery::parse("2001:db8::1");
+        assert_eq!(query.ip, Some("2001:db8::1".parse().unwrap()));
+        assert_eq!(query.port, None);
+    }
+
+    #[test]
+    fn parse_port_and_process_terms() {
+        let query = MatchQuery::parse("10.0.0.1 22 process=/usr/bin/ssh");
+        assert_eq!(query.ip, Some("10.0.0.1".parse().unwrap()));
+        assert_eq!(query.port, Some(22));
+        assert_eq!(query.process.as_deref(), Some("ssh"));
+        assert!(MatchQuery::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn domain_rules() {
+        let lines = [
+            "DOMAIN,exact.example.com,A",
+            "DOMAIN-SUFFIX,google.com,B",
+            "DOMAIN-KEYWORD,tube,C",
+        ];
+        assert_eq!(hit(&lines, "exact.example.com"), Some(0));
+        assert_eq!(hit(&lines, "sub.exact.example.com"), None);
+        assert_eq!(hit(&lines, "google.com"), Some(1));
+        assert_eq!(hit(&lines, "mail.google.com"), Some(1));
+        assert_eq!(hit(&lines, "notgoogle.com"), None);
+        assert_eq!(hit(&lines, "www.youtube.com"), Some(2));
+    }
+
+    #[test]
+    fn ipv4_cidr() {
+        let lines = ["IP-CIDR,192.168.0.0/16,DIRECT,no-resolve"];
+        assert_eq!(hit(&lines, "192.168.3.4"), Some(0));
+        assert_eq!(hit(&lines, "192.169.0.1"), None);
+        // Hostnames are not resolved
+        assert_eq!(hit(&lines, "router.local"), None);
+    }
+
+    #[test]
+    fn ipv6_cidr() {
+        let lines = ["IP-CIDR6,2001:db8::/32,DIRECT"];
+        assert_eq!(hit(&lines, "2001:db8:1::5"), Some(0));
+        assert_eq!(hit(&lines, "[2001:db8::1]:443"), Some(0));
+        assert_eq!(hit(&lines, "2001:db9::1"), None);
+        // Address families never cross
+        assert_eq!(hit(&lines, "10.0.0.1"), None);
+    }
+
+    #[test]
+    fn dest_port_single_and_range() {
+        let lines = ["DEST-PORT,22,A", "DEST-PORT,8000-9000,B"];
+        assert_eq!(hit(&lines, "22"), Some(0));
+        assert_eq!(hit(&lines, "example.com:8000"), Some(1));
+        assert_eq!(hit(&lines, "http://example.com:9000/"), Some(1));
+        assert_eq!(hit(&lines, "9001"), None);
+        // The port comes from the scheme
+        assert_eq!(hit(&lines, "http://example.com/"), None);
+    }
+
+    #[test]
+    fn process_name() {
+        let lines = ["PROCESS-NAME,Slack,Proxy"];
+        assert_eq!(hit(&lines, "process=slack"), Some(0));
+        assert_eq!(hit(&lines, "process=Safari"), None);
+    }
+
+    #[test]
+    fn final_matches_everything_left() {
+        let lines = ["DOMAIN,a.com,Proxy", "FINAL,DIRECT,dns-failed"];
+        assert_eq!(hit(&lines, "a.com"), Some(0));
+        assert_eq!(hit(&lines, "b.com"), Some(1));
+        assert_eq!(hit(&lines, "process=curl"), Some(1));
+    }
+
+    #[test]
+    fn unsupported_types_are_skipped_and_reported() {
+        let result = evaluate(
+            &rules(&[
+                "GEOIP,CN,DIRECT",
+                "RULE-SET,https://example.com/list,REJECT",
+                "GEOIP,US,Proxy",
+                "DOMAIN-SUFFIX,example.com,Proxy",
+            ]),
+            &MatchQuery::parse("www.example.com"),
+        );
+        assert_eq!(result.index, Some(3));
+        assert_eq!(result.skipped_types, ["GEOIP", "RULE-SET"]);
+    }
+}
@@ -21,6 +21,18 @@ impl Translate for EnUS {
         "(CLI Mode)"
     }
 
+    fn ui_status_remote(&self) -> &'static str {
+        "[Remote]"
+    }
+
+    fn ui_status_recording(&self) -> &'static str {
+        "[REC]"
+    }
+
+    fn ui_status_replay(&self) -> &'static str {
+        "[Replay]"
+    }
+
     // ========== Keyboard Shortcuts ==========
     fn key_quit(&self) -> &'static str {
         "[q]uit"
@@ -92,6 +104,10 @@ impl Translate for EnUS {
         format!("Test failed: {}", error)
     }
 
+    fn notification_test_url_ignored(&self) -> &'static str {
+        "Tested with surge-cli: the [testing] URL was ignored, Surge used the profile's proxy-test-url"
+    }
+
     // ========== Alerts ==========
     fn alert_surge_not_running(&self) -> &'static str {
         "Surge not running - Press S to start"
@@ -101,6 +117,10 @@ impl Translate for EnUS {
         "HTTP API unavailable - Press R to reload config"
     }
 
+    fn alert_surge_unreachable(&self) -> &'static str {
+        "Surge unreachable via HTTP API - check host, port and key"
+    }
+
     // ========== Policy Groups ==========
     fn policy_group_title(&self) -> &'static str {
         "Policy Groups"
@@ -177,6 +197,10 @@ impl Translate for EnUS {
         "Outbound Mode"
     }
 
+    fn overview_global_policy(&self) -> &'static str {
+        "Global Policy"
+    }
+
     fn overview_stats(&self) -> &'static str {
         "Statistics"
     }
@@ -369,13 +393,17 @@ impl Translate for EnUS {
     }
 
     fn help_shortcut_switch_view(&self) -> &'static str {
-        "  1-5        - switch view"
+        "  0-9, u     - switch view"
     }
 
     fn help_shortcut_toggle_outbound(&self) -> &'static str {
         "  m          - toggle outbound mode"
     }
 
+    fn help_shortcut_select_global_policy(&self) -> &'static str {
+        "  p          - select global policy (proxy all)"
+    }
+
     fn help_shortcut_notification_history(&self) -> &'static str {
         "  n          - notification history"
     }
@@ -384,6 +412,14 @@ impl Translate for EnUS {
         "  `          - devtools"
     }
 
+    fn help_shortcut_switch_instance(&self) -> &'static str {
+        "  w          - switch Surge instance"
+    }
+
+    fn help_shortcut_switch_profile(&self) -> &'static str {
+        "  o          - switch profile"
+    }
+
     fn help_shortcut_help(&self) -> &'static str {
         "  ?          - this help"
     }
@@ -425,6 +461,26 @@ impl Translate for EnUS {
         "  f          - flush DNS cache"
     }
 
+    fn help_shortcut_toggle_module(&self) -> &'static str {
+        "  Enter      - enable/disable module"
+    }
+
+    fn help_shortcut_profile_sections(&self) -> &'static str {
+        "  ↑/↓        - jump to section"
+    }
+
+    fn help_shortcut_profile_scroll(&self) -> &'static str {
+        "  PgUp/PgDn  - scroll profile"
+    }
+
+    fn help_shortcut_toggle_effective(&self) -> &'static str {
+        "  e          - original / effective profile"
+    }
+
+    fn help_shortcut_toggle_sensitive(&self) -> &'static str {
+        "  v          - reveal / mask sensitive values"
+    }
+
     // ---- Navigation lines ----
     fn help_nav_up_down(&self) -> &'static str {
         "  j/k or ↓/↑  - move up/down"
@@ -517,6 +573,26 @@ impl Translate for EnUS {
         "Traffic Capture"
     }
 
+    fn feature_rewrite(&self) -> &'static str {
+        "Rewrite"
+    }
+
+    fn feature_scripting(&self) -> &'static str {
+        "Scripting"
+    }
+
+    fn feature_system_proxy(&self) -> &'static str {
+        "System Proxy"
+    }
+
+    fn feature_enhanced_mode(&self) -> &'static str {
+        "Enhanced Mode"
+    }
+
+    fn overview_features(&self) -> &'static str {
+        "Features"
+    }
+
     fn status_enabled(&self) -> &'static str {
         "Enabled"
     }
@@ -525,26 +601,30 @@ impl Translate for EnUS {
         "Disabled"
     }
 
-    fn notification_mitm_enabled(&self) -> &'static str {
-        "MITM enabled"
+    fn status_unsupported(&self) -> &'static str {
+        "Unsupported"
     }
 
-    fn notification_mitm_disabled(&self) -> &'static str {
-        "MITM disabled"
+    fn help_shortcut_toggle_feature(&self) -> &'static str {
+        "  ↑/↓ Enter  - toggle selected feature"
     }
 
-    fn notification_capture_enabled(&self) -> &'static str {
-        "Traffic capture enabled"
+    fn notification_feature_enabled(&self, feature: &str) -> String {
+        format!("{} enabled", feature)
     }
 
-    fn notification_capture_disabled(&self) -> &'static str {
-        "Traffic capture disabled"
+    fn notification_feature_disabled(&self, feature: &str) -> String {
+        format!("{} disabled", feature)
     }
 
     fn notification_feature_toggle_failed(&self, error: &str) -> String {
         format!("Feature toggle failed: {}", error)
     }
 
+    fn notification_remote_unsupported(&self) -> &'static str {
+        "Not available in remote mode (requires local surge-cli)"
+    }
+
     // ========== DNS ==========
     fn view_dns(&self) -> &'static str {
         "DNS Cache"
@@ -585,4 +665,647 @@ impl Translate for EnUS {
     fn notification_dns_flush_failed(&self, error: &str) -> String {
         format!("Failed to flush DNS cache: {}", error)
     }
+
+    fn dns_label_server(&self) -> &'static str {
+        "Server"
+    }
+
+    fn dns_label_time_cost(&self) -> &'static str {
+        "Lookup Time"
+    }
+
+    fn dns_servers_title(&self) -> &'static str {
+        "Upstream Servers"
+    }
+
+    fn dns_server_all(&self) -> &'static str {
+        "All servers"
+    }
+
+    fn dns_server_records(&self, count: usize, avg_ms: Option<f64>) -> String {
+        match avg_ms {
+            Some(avg) => format!("{} cached, avg {:.0} ms", count, avg),
+            None => format!("{} cached", count),
+        }
+    }
+
+    fn dns_delay_not_tested(&self) -> &'static str {
+        "Press d to test DNS delay"
+    }
+
+    fn dns_delay_testing(&self) -> &'static str {
+        "Testing..."
+    }
+
+    fn help_shortcut_test_dns(&self) -> &'static str {
+        "  d          - test DNS delay"
+    }
+
+    fn notification_dns_delay_completed(&self) -> &'static str {
+        "DNS delay test completed"
+    }
+
+    fn notification_dns_delay_failed(&self, error: &str) -> String {
+        format!("DNS delay test failed: {}", error)
+    }
+
+    // ========== Instances ==========
+    fn instance_picker_title(&self) -> &'static str {
+        " Surge Instances [Enter switch / ESC close] "
+    }
+
+    fn instance_active(&self) -> &'static str {
+        "(active)"
+    }
+
+    fn notification_instance_switched(&self, name: &str) -> String {
+        format!("Switched to instance {}", name)
+    }
+
+    fn notification_instance_switch_failed(&self, error: &str) -> String {
+        format!("Failed to switch instance: {}", error)
+    }
+
+    // ========== Modules ==========
+    fn view_modules(&self) -> &'static str {
+        "Modules"
+    }
+
+    fn modules_list_title(&self) -> &'static str {
+        "Modules"
+    }
+
+    fn modules_detail_title(&self) -> &'static str {
+        "Module Details"
+    }
+
+    fn modules_no_modules(&self) -> &'static str {
+        "No modules"
+    }
+
+    fn module_label_name(&self) -> &'static str {
+        "Name"
+    }
+
+    fn module_label_status(&self) -> &'static str {
+        "Status"
+    }
+
+    fn module_label_description(&self) -> &'static str {
+        "Description"
+    }
+
+    fn module_no_description(&self) -> &'static str {
+        "No description"
+    }
+
+    fn overview_modules(&self) -> &'static str {
+        "Modules"
+    }
+
+    fn overview_modules_enabled(&self, enabled: usize, total: usize) -> String {
+        format!("{}/{} enabled", enabled, total)
+    }
+
+    fn notification_module_enabled(&self, name: &str) -> String {
+        format!("Module enabled: {}", name)
+    }
+
+    fn notification_module_disabled(&self, name: &str) -> String {
+        format!("Module disabled: {}", name)
+    }
+
+    fn notification_module_toggle_failed(&self, error: &str) -> String {
+        format!("Failed to toggle module: {}", error)
+    }
+
+    // ========== Global Policy ==========
+    fn global_policy_picker_title(&self) -> &'static str {
+        " Global Policy [Enter proxy all / ESC close] "
+    }
+
+    fn global_policy_current(&self) -> &'static str {
+        "(current)"
+    }
+
+    fn global_policy_group(&self) -> &'static str {
+        "group"
+    }
+
+    fn notification_global_policy_set(&self, policy: &str) -> String {
+        format!("Proxy all via {}", policy)
+    }
+
+    fn notification_global_policy_failed(&self, error: &str) -> String {
+        format!("Failed to set global policy: {}", error)
+    }
+
+    // ========== Profiles ==========
+    fn profile_picker_title(&self) -> &'static str {
+        " Profiles [Enter switch / ESC close] "
+    }
+
+    fn profile_active(&self) -> &'static str {
+        "(active)"
+    }
+
+    fn confirm_profile_switch_title(&self) -> &'static str {
+        " Confirm Profile Switch "
+    }
+
+    fn confirm_profile_switch_message(&self, name: &str) -> String {
+        format!("Switch to profile \"{}\"?", name)
+    }
+
+    fn confirm_profile_switch_current(&self, name: &str) -> String {
+        format!("Current profile: {}", name)
+    }
+
+    fn confirm_profile_switch_hint(&self) -> &'static str {
+        "[Enter] Switch  [ESC] Back"
+    }
+
+    fn notification_profile_switched(&self, name: &str) -> String {
+        format!("Switched to profile {}", name)
+    }
+
+    fn notification_profile_switch_failed(&self, error: &str) -> String {
+        format!("Failed to switch profile: {}", error)
+    }
+
+    fn notification_profiles_failed(&self, error: &str) -> String {
+        format!("Failed to list profiles: {}", error)
+    }
+
+    // ========== Profile View ==========
+    fn view_profile(&self) -> &'static str {
+        "Profile"
+    }
+
+    fn profile_sections_title(&self) -> &'static str {
+        "Sections"
+    }
+
+    fn profile_no_sections(&self) -> &'static str {
+        "No sections"
+    }
+
+    fn profile_original(&self) -> &'static str {
+        "Original"
+    }
+
+    fn profile_effective(&self) -> &'static str {
+        "Effective"
+    }
+
+    fn profile_not_loaded(&self) -> &'static str {
+        "Profile not loaded"
+    }
+
+    fn profile_no_matches(&self) -> &'static str {
+        "No matching lines"
+    }
+
+    fn action_reveal(&self) -> &'static str {
+        "Reveal"
+    }
+
+    fn action_mask(&self) -> &'static str {
+        "Mask"
+    }
+
+    fn notification_profile_load_failed(&self, error: &str) -> String {
+        format!("Failed to load profile: {}", error)
+    }
+
+    // ========== Events View ==========
+    fn view_events(&self) -> &'static str {
+        "Events"
+    }
+
+    fn events_list_title(&self) -> &'static str {
+        "Event Center"
+    }
+
+    fn events_detail_title(&self) -> &'static str {
+        "Event Detail"
+    }
+
+    fn events_no_events(&self) -> &'static str {
+        "No events"
+    }
+
+    fn event_level_info(&self) -> &'static str {
+        "Info"
+    }
+
+    fn event_level_warning(&self) -> &'static str {
+        "Warning"
+    }
+
+    fn event_level_error(&self) -> &'static str {
+        "Error"
+    }
+
+    fn event_label_level(&self) -> &'static str {
+        "Level"
+    }
+
+    fn event_label_time(&self) -> &'static str {
+        "Time"
+    }
+
+    fn event_label_identifier(&self) -> &'static str {
+        "Identifier"
+    }
+
+    fn event_label_content(&self) -> &'static str {
+        "Content"
+    }
+
+    // ========== Traffic ==========
+    fn overview_traffic(&self) -> &'static str {
+        "Traffic"
+    }
+
+    fn traffic_upload(&self) -> &'static str {
+        "Upload"
+    }
+
+    fn traffic_download(&self) -> &'static str {
+        "Download"
+    }
+
+    fn traffic_peak(&self) -> &'static str {
+        "peak"
+    }
+
+    fn traffic_totals(&self) -> &'static str {
+        "Totals"
+    }
+
+    fn traffic_active_connections(&self) -> &'static str {
+        "Active connections"
+    }
+
+    fn traffic_estimated(&self) -> &'static str {
+        "  Estimated from connections (no /v1/traffic)"
+    }
+
+    // ========== Scripting ==========
+    fn view_scripting(&self) -> &'static str {
+        "Scripting"
+    }
+
+    fn script_settings_title(&self) -> &'static str {
+        "Script Console"
+    }
+
+    fn script_label_file(&self) -> &'static str {
+        "File"
+    }
+
+    fn script_label_mock(&self) -> &'static str {
+        "Mock"
+    }
+
+    fn script_label_timeout(&self) -> &'static str {
+        "Timeout"
+    }
+
+    fn script_label_status(&self) -> &'static str {
+        "Status"
+    }
+
+    fn script_no_file(&self) -> &'static str {
+        "No file (press l)"
+    }
+
+    fn script_status_idle(&self) -> &'static str {
+        "Not run yet"
+    }
+
+    fn script_status_running(&self) -> &'static str {
+        "Running..."
+    }
+
+    fn script_status_finished(&self, elapsed_ms: u128, time: &str) -> String {
+        format!("Finished in {} ms at {}", elapsed_ms, time)
+    }
+
+    fn script_result_title(&self) -> &'static str {
+        "Result"
+    }
+
+    fn script_logs_title(&self) -> &'static str {
+        "Logs"
+    }
+
+    fn script_no_logs(&self) -> &'static str {
+        "No output"
+    }
+
+    fn script_exception(&self) -> &'static str {
+        "Exception"
+    }
+
+    fn script_error(&self) -> &'static str {
+        "Error"
+    }
+
+    fn action_run(&self) -> &'static str {
+        "Run"
+    }
+
+    fn help_shortcut_run_script(&self) -> &'static str {
+        "  Enter      - run script (re-reads the file)"
+    }
+
+    fn help_shortcut_script_file(&self) -> &'static str {
+        "  l          - set script file"
+    }
+
+    fn help_shortcut_script_mock(&self) -> &'static str {
+        "  x          - cycle mock context"
+    }
+
+    fn help_shortcut_script_timeout(&self) -> &'static str {
+        "  +/-        - change timeout"
+    }
+
+    fn notification_script_no_file(&self) -> &'static str {
+        "Set a script file first (press l)"
+    }
+
+    fn notification_script_finished(&self) -> &'static str {
+        "Script finished"
+    }
+
+    fn notification_script_failed(&self, error: &str) -> String {
+        format!("Script failed: {}", error)
+    }
+
+    // ========== MITM CA ==========
+    fn overview_mitm_ca(&self) -> &'static str {
+        "MITM CA Certificate"
+    }
+
+    fn ca_label_subject(&self) -> &'static str {
+        "Subject"
+    }
+
+    fn ca_label_validity(&self) -> &'static str {
+        "Valid"
+    }
+
+    fn ca_label_fingerprint(&self) -> &'static str {
+        "SHA-256"
+    }
+
+    fn ca_expires_in(&self, days: i64) -> String {
+        format!("expires in {} days", days)
+    }
+
+    fn ca_expired(&self) -> &'static str {
+        "expired"
+    }
+
+    // ========== Engine Control ==========
+    fn engine_menu_title(&self) -> &'static str {
+        " Engine Control [Enter apply / ESC close] "
+    }
+
+    fn engine_action_stop(&self) -> &'static str {
+        "Stop Surge"
+    }
+
+    fn engine_action_restart(&self) -> &'static str {
+        "Restart Surge"
+    }
+
+    fn engine_log_level(&self) -> &'static str {
+        "Log level"
+    }
+
+    fn action_engine_control(&self) -> &'static str {
+        "Engine control"
+    }
+
+    fn confirm_engine_title(&self) -> &'static str {
+        " Confirm "
+    }
+
+    fn confirm_engine_stop_message(&self) -> &'static str {
+        "Stop Surge? All proxied traffic will be interrupted."
+    }
+
+    fn confirm_engine_restart_message(&self) -> &'static str {
+        "Restart Surge? All proxied traffic will be interrupted."
+    }
+
+    fn confirm_engine_type_word(&self, word: &str) -> String {
+        format!("Type \"{}\" to confirm:", word)
+    }
+
+    fn confirm_engine_hint(&self) -> &'static str {
+        "[Enter] Confirm  [ESC] Back"
+    }
+
+    fn help_shortcut_engine_control(&self) -> &'static str {
+        "  e          - engine control (stop/restart, log level)"
+    }
+
+    fn notification_surge_stopped(&self) -> &'static str {
+        "Surge stopped"
+    }
+
+    fn notification_surge_restarting(&self) -> &'static str {
+        "Restarting Surge..."
+    }
+
+    fn notification_surge_restarted(&self) -> &'static str {
+        "Surge restarted"
+    }
+
+    fn notification_log_level_set(&self, level: &str) -> String {
+        format!("Log level set to {}", level)
+    }
+
+    fn notification_engine_failed(&self, error: &str) -> String {
+        format!("Engine control failed: {}", error)
+    }
+
+    // ========== Diagnostics ==========
+    fn view_diagnostics(&self) -> &'static str {
+        "Diagnostics"
+    }
+
+    fn diagnostics_title(&self) -> &'static str {
+        " Network Diagnostics [T run] "
+    }
+
+    fn diagnostics_unsupported(&self) -> &'static str {
+        "Diagnostics need surge-cli (not available in remote mode)"
+    }
+
+    fn diagnostics_running(&self) -> &'static str {
+        "Running diagnostics..."
+    }
+
+    fn diagnostics_not_run(&self) -> &'static str {
+        "Not run yet (press T)"
+    }
+
+    fn diagnostics_all_passed(&self) -> &'static str {
+        "All checks passed"
+    }
+
+    fn diagnostics_failed_sections(&self, sections: &str) -> String {
+        format!("Failed: {}", sections)
+    }
+
+    fn diagnostics_section_dns(&self) -> &'static str {
+        "DNS"
+    }
+
+    fn diagnostics_section_router(&self) -> &'static str {
+        "Router"
+    }
+
+    fn diagnostics_section_internet(&self) -> &'static str {
+        "Internet"
+    }
+
+    fn diagnostics_section_proxy(&self) -> &'static str {
+        "Proxy"
+    }
+
+    fn diagnostics_section_other(&self) -> &'static str {
+        "Other"
+    }
+
+    fn help_shortcut_run_diagnostics(&self) -> &'static str {
+        "  t          - run network diagnostics"
+    }
+
+    fn notification_diagnostics_completed(&self) -> &'static str {
+        "Diagnostics finished"
+    }
+
+    fn notification_diagnostics_failed(&self, error: &str) -> String {
+        format!("Diagnostics failed: {}", error)
+    }
+
+    // ========== Rules ==========
+    fn view_rules(&self) -> &'static str {
+        "Rules"
+    }
+
+    fn rules_list_title(&self) -> &'static str {
+        "Rules"
+    }
+
+    fn rules_detail_title(&self) -> &'static str {
+        " Rule Detail "
+    }
+
+    fn rules_no_rules(&self) -> &'static str {
+        "No rules"
+    }
+
+    fn rules_unsupported(&self) -> &'static str {
+        "Rule list not available from this backend"
+    }
+
+    fn rules_label_type(&self) -> &'static str {
+        "Type"
+    }
+
+    fn rules_label_value(&self) -> &'static str {
+        "Value"
+    }
+
+    fn rules_label_policy(&self) -> &'static str {
+        "Policy"
+    }
+
+    fn rules_label_options(&self) -> &'static str {
+        "Options"
+    }
+
+    fn rules_label_hits(&self) -> &'static str {
+        "Hits"
+    }
+
+    fn rules_label_last_host(&self) -> &'static str {
+        "Last host"
+    }
+
+    fn rules_hits_value(&self, count: usize, percent: f64) -> String {
+        format!("{} ({:.0}% of recent requests)", count, percent)
+    }
+
+    fn rules_never_matched(&self) -> &'static str {
+        "No match in recent requests"
+    }
+
+    fn rules_summary(&self, rules: usize, dead: usize, requests: usize) -> String {
+        format!(
+            "{} rules, {} without hits in {} recent requests",
+            rules, dead, requests
+        )
+    }
+
+    fn rules_final_share(&self, percent: f64) -> String {
+        format!("FINAL: {:.0}% of recent requests", percent)
+    }
+
+    // ========== Rule Match ==========
+    fn action_match(&self) -> &'static str {
+        "Match"
+    }
+
+    fn rules_match_title(&self) -> &'static str {
+        " Rule Match "
+    }
+
+    fn rules_match_input(&self) -> &'static str {
+        "Test"
+    }
+
+    fn rules_match_hint(&self) -> &'static str {
+        "host, IP, URL, port or process=NAME; Enter to match, Esc to cancel"
+    }
+
+    fn rules_match_prompt(&self) -> &'static str {
+        "Press Enter to find the rule a host, IP, URL, port or process would hit"
+    }
+
+    fn rules_match_rule(&self) -> &'static str {
+        "Rule"
+    }
+
+    fn rules_match_none(&self) -> &'static str {
+        "No rule matched"
+    }
+
+    fn rules_match_from_profile(&self) -> &'static str {
+        "Rules from the profile [Rule] section"
+    }
+
+    fn rules_match_supported(&self, types: &str) -> String {
+        format!("Supported: {}", types)
+    }
+
+    fn rules_match_skipped(&self, types: &str) -> String {
+        format!("Not evaluated: {}", types)
+    }
+
+    fn help_shortcut_rule_match(&self) -> &'static str {
+        "  Enter      - find the rule a host, IP, port or process hits"
+    }
+
+    fn notification_rule_match_failed(&self, error: &str) -> String {
+        format!("Rule match failed: {}", error)
+    }
 }
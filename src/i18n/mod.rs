@@ -17,6 +17,9 @@ pub trait Translate: Send + Sync {
     fn ui_status_stopped(&self) -> &'static str;
     fn ui_status_http_api(&self) -> &'static str;
     fn ui_status_cli_mode(&self) -> &'static str;
+    fn ui_status_remote(&self) -> &'static str;
+    fn ui_status_recording(&self) -> &'static str;
+    fn ui_status_replay(&self) -> &'static str;
 
     // ========== Keyboard Shortcuts ==========
     fn key_quit(&self) -> &'static str;
@@ -40,10 +43,12 @@ pub trait Translate: Send + Sync {
     fn notification_test_started(&self) -> &'static str;
     fn notification_test_completed(&self, alive: usize, total: usize) -> String;
     fn notification_test_failed(&self, error: &str) -> String;
+    fn notification_test_url_ignored(&self) -> &'static str;
 
     // ========== Alert Messages ==========
     fn alert_surge_not_running(&self) -> &'static str;
     fn alert_http_api_disabled(&self) -> &'static str;
+    fn alert_surge_unreachable(&self) -> &'static str;
 
     // ========== Policy Groups ==========
     fn policy_group_title(&self) -> &'static str;
@@ -70,6 +75,7 @@ pub trait Translate: Send + Sync {
     fn overview_surge_status(&self) -> &'static str;
     fn overview_api_status(&self) -> &'static str;
     fn overview_outbound_mode(&self) -> &'static str;
+    fn overview_global_policy(&self) -> &'static str;
     fn overview_stats(&self) -> &'static str;
 
     // ========== OutboundMode ==========
@@ -132,8 +138,11 @@ pub trait Translate: Send + Sync {
     fn help_shortcut_refresh(&self) -> &'static str;
     fn help_shortcut_switch_view(&self) -> &'static str;
     fn help_shortcut_toggle_outbound(&self) -> &'static str;
+    fn help_shortcut_select_global_policy(&self) -> &'static str;
     fn help_shortcut_notification_history(&self) -> &'static str;
     fn help_shortcut_devtools(&self) -> &'static str;
+    fn help_shortcut_switch_instance(&self) -> &'static str;
+    fn help_shortcut_switch_profile(&self) -> &'static str;
     fn help_shortcut_help(&self) -> &'static str;
     // ---- View-specific shortcut lines ----
     fn help_shortcut_toggle_mitm(&self) -> &'static str;
@@ -145,6 +154,11 @@ pub trait Translate: Send + Sync {
     fn help_shortcut_toggle_group(&self) -> &'static str;
     fn help_shortcut_switch_app(&self) -> &'static str;
     fn help_shortcut_flush_dns(&self) -> &'static str;
+    fn help_shortcut_toggle_module(&self) -> &'static str;
+    fn help_shortcut_profile_sections(&self) -> &'static str;
+    fn help_shortcut_profile_scroll(&self) -> &'static str;
+    fn help_shortcut_toggle_effective(&self) -> &'static str;
+    fn help_shortcut_toggle_sensitive(&self) -> &'static str;
     // ---- Navigation lines ----
     fn help_nav_up_down(&self) -> &'static str;
     fn help_nav_left_right(&self) -> &'static str;
@@ -174,13 +188,19 @@ pub trait Translate: Send + Sync {
     // ========== Feature Toggles ==========
     fn feature_mitm(&self) -> &'static str;
     fn feature_capture(&self) -> &'static str;
+    fn feature_rewrite(&self) -> &'static str;
+    fn feature_scripting(&self) -> &'static str;
+    fn feature_system_proxy(&self) -> &'static str;
+    fn feature_enhanced_mode(&self) -> &'static str;
+    fn overview_features(&self) -> &'static str;
     fn status_enabled(&self) -> &'static str;
     fn status_disabled(&self) -> &'static str;
-    fn notification_mitm_enabled(&self) -> &'static str;
-    fn notification_mitm_disabled(&self) -> &'static str;
-    fn notification_capture_enabled(&self) -> &'static str;
-    fn notification_capture_disabled(&self) -> &'static str;
+    fn status_unsupported(&self) -> &'static str;
+    fn help_shortcut_toggle_feature(&self) -> &'static str;
+    fn notification_feature_enabled(&self, feature: &str) -> String;
+    fn notification_feature_disabled(&self, feature: &str) -> String;
     fn notification_feature_toggle_failed(&self, error: &str) -> String;
+    fn notification_remote_unsupported(&self) -> &'static str;
 
     // ========== DNS ==========
     fn view_dns(&self) -> &'static str;
@@ -193,6 +213,188 @@ pub trait Translate: Send + Sync {
     fn action_flush(&self) -> &'static str;
     fn notification_dns_flushed(&self) -> &'static str;
     fn notification_dns_flush_failed(&self, error: &str) -> String;
+    fn dns_label_server(&self) -> &'static str;
+    fn dns_label_time_cost(&self) -> &'static str;
+    fn dns_servers_title(&self) -> &'static str;
+    fn dns_server_all(&self) -> &'static str;
+    fn dns_server_records(&self, count: usize, avg_ms: Option<f64>) -> String;
+    fn dns_delay_not_tested(&self) -> &'static str;
+    fn dns_delay_testing(&self) -> &'static str;
+    fn help_shortcut_test_dns(&self) -> &'static str;
+    fn notification_dns_delay_completed(&self) -> &'static str;
+    fn notification_dns_delay_failed(&self, error: &str) -> String;
+
+    // ========== Instances ==========
+    fn instance_picker_title(&self) -> &'static str;
+    fn instance_active(&self) -> &'static str;
+    fn notification_instance_switched(&self, name: &str) -> String;
+    fn notification_instance_switch_failed(&self, error: &str) -> String;
+
+    // ========== Modules ==========
+    fn view_modules(&self) -> &'static str;
+    fn modules_list_title(&self) -> &'static str;
+    fn modules_detail_title(&self) -> &'static str;
+    fn modules_no_modules(&self) -> &'static str;
+    fn module_label_name(&self) -> &'static str;
+    fn module_label_status(&self) -> &'static str;
+    fn module_label_description(&self) -> &'static str;
+    fn module_no_description(&self) -> &'static str;
+    fn overview_modules(&self) -> &'static str;
+    fn overview_modules_enabled(&self, enabled: usize, total: usize) -> String;
+    fn notification_module_enabled(&self, name: &str) -> String;
+    fn notification_module_disabled(&self, name: &str) -> String;
+    fn notification_module_toggle_failed(&self, error: &str) -> String;
+
+    // ========== Global Policy ==========
+    fn global_policy_picker_title(&self) -> &'static str;
+    fn global_policy_current(&self) -> &'static str;
+    fn global_policy_group(&self) -> &'static str;
+    fn notification_global_policy_set(&self, policy: &str) -> String;
+    fn notification_global_policy_failed(&self, error: &str) -> String;
+
+    // ========== Profiles ==========
+    fn profile_picker_title(&self) -> &'static str;
+    fn profile_active(&self) -> &'static str;
+    fn confirm_profile_switch_title(&self) -> &'static str;
+    fn confirm_profile_switch_message(&self, name: &str) -> String;
+    fn confirm_profile_switch_current(&self, name: &str) -> String;
+    fn confirm_profile_switch_hint(&self) -> &'static str;
+    fn notification_profile_switched(&self, name: &str) -> String;
+    fn notification_profile_switch_failed(&self, error: &str) -> String;
+    fn notification_profiles_failed(&self, error: &str) -> String;
+
+    // ========== Profile View ==========
+    fn view_profile(&self) -> &'static str;
+    fn profile_sections_title(&self) -> &'static str;
+    fn profile_no_sections(&self) -> &'static str;
+    fn profile_original(&self) -> &'static str;
+    fn profile_effective(&self) -> &'static str;
+    fn profile_not_loaded(&self) -> &'static str;
+    fn profile_no_matches(&self) -> &'static str;
+    fn action_reveal(&self) -> &'static str;
+    fn action_mask(&self) -> &'static str;
+    fn notification_profile_load_failed(&self, error: &str) -> String;
+
+    // ========== Events View ==========
+    fn view_events(&self) -> &'static str;
+    fn events_list_title(&self) -> &'static str;
+    fn events_detail_title(&self) -> &'static str;
+    fn events_no_events(&self) -> &'static str;
+    fn event_level_info(&self) -> &'static str;
+    fn event_level_warning(&self) -> &'static str;
+    fn event_level_error(&self) -> &'static str;
+    fn event_label_level(&self) -> &'static str;
+    fn event_label_time(&self) -> &'static str;
+    fn event_label_identifier(&self) -> &'static str;
+    fn event_label_content(&self) -> &'static str;
+
+    // ========== Traffic ==========
+    fn overview_traffic(&self) -> &'static str;
+    fn traffic_upload(&self) -> &'static str;
+    fn traffic_download(&self) -> &'static str;
+    fn traffic_peak(&self) -> &'static str;
+    fn traffic_totals(&self) -> &'static str;
+    fn traffic_active_connections(&self) -> &'static str;
+    fn traffic_estimated(&self) -> &'static str;
+
+    // ========== Scripting ==========
+    fn view_scripting(&self) -> &'static str;
+    fn script_settings_title(&self) -> &'static str;
+    fn script_label_file(&self) -> &'static str;
+    fn script_label_mock(&self) -> &'static str;
+    fn script_label_timeout(&self) -> &'static str;
+    fn script_label_status(&self) -> &'static str;
+    fn script_no_file(&self) -> &'static str;
+    fn script_status_idle(&self) -> &'static str;
+    fn script_status_running(&self) -> &'static str;
+    fn script_status_finished(&self, elapsed_ms: u128, time: &str) -> String;
+    fn script_result_title(&self) -> &'static str;
+    fn script_logs_title(&self) -> &'static str;
+    fn script_no_logs(&self) -> &'static str;
+    fn script_exception(&self) -> &'static str;
+    fn script_error(&self) -> &'static str;
+    fn action_run(&self) -> &'static str;
+    fn help_shortcut_run_script(&self) -> &'static str;
+    fn help_shortcut_script_file(&self) -> &'static str;
+    fn help_shortcut_script_mock(&self) -> &'static str;
+    fn help_shortcut_script_timeout(&self) -> &'static str;
+    fn notification_script_no_file(&self) -> &'static str;
+    fn notification_script_finished(&self) -> &'static str;
+    fn notification_script_failed(&self, error: &str) -> String;
+
+    // ========== MITM CA ==========
+    fn overview_mitm_ca(&self) -> &'static str;
+    fn ca_label_subject(&self) -> &'static str;
+    fn ca_label_validity(&self) -> &'static str;
+    fn ca_label_fingerprint(&self) -> &'static str;
+    fn ca_expires_in(&self, days: i64) -> String;
+    fn ca_expired(&self) -> &'static str;
+
+    // ========== Engine Control ==========
+    fn engine_menu_title(&self) -> &'static str;
+    fn engine_action_stop(&self) -> &'static str;
+    fn engine_action_restart(&self) -> &'static str;
+    fn engine_log_level(&self) -> &'static str;
+    fn action_engine_control(&self) -> &'static str;
+    fn confirm_engine_title(&self) -> &'static str;
+    fn confirm_engine_stop_message(&self) -> &'static str;
+    fn confirm_engine_restart_message(&self) -> &'static str;
+    fn confirm_engine_type_word(&self, word: &str) -> String;
+    fn confirm_engine_hint(&self) -> &'static str;
+    fn help_shortcut_engine_control(&self) -> &'static str;
+    fn notification_surge_stopped(&self) -> &'static str;
+    fn notification_surge_restarting(&self) -> &'static str;
+    fn notification_surge_restarted(&self) -> &'static str;
+    fn notification_log_level_set(&self, level: &str) -> String;
+    fn notification_engine_failed(&self, error: &str) -> String;
+
+    // ========== Diagnostics ==========
+    fn view_diagnostics(&self) -> &'static str;
+    fn diagnostics_title(&self) -> &'static str;
+    fn diagnostics_unsupported(&self) -> &'static str;
+    fn diagnostics_running(&self) -> &'static str;
+    fn diagnostics_not_run(&self) -> &'static str;
+    fn diagnostics_all_passed(&self) -> &'static str;
+    fn diagnostics_failed_sections(&self, sections: &str) -> String;
+    fn diagnostics_section_dns(&self) -> &'static str;
+    fn diagnostics_section_router(&self) -> &'static str;
+    fn diagnostics_section_internet(&self) -> &'static str;
+    fn diagnostics_section_proxy(&self) -> &'static str;
+    fn diagnostics_section_other(&self) -> &'static str;
+    fn help_shortcut_run_diagnostics(&self) -> &'static str;
+    fn notification_diagnostics_completed(&self) -> &'static str;
+    fn notification_diagnostics_failed(&self, error: &str) -> String;
+
+    // ========== Rules ==========
+    fn view_rules(&self) -> &'static str;
+    fn rules_list_title(&self) -> &'static str;
+    fn rules_detail_title(&self) -> &'static str;
+    fn rules_no_rules(&self) -> &'static str;
+    fn rules_unsupported(&self) -> &'static str;
+    fn rules_label_type(&self) -> &'static str;
+    fn rules_label_value(&self) -> &'static str;
+    fn rules_label_policy(&self) -> &'static str;
+    fn rules_label_options(&self) -> &'static str;
+    fn rules_label_hits(&self) -> &'static str;
+    fn rules_label_last_host(&self) -> &'static str;
+    fn rules_hits_value(&self, count: usize, percent: f64) -> String;
+    fn rules_never_matched(&self) -> &'static str;
+    fn rules_summary(&self, rules: usize, dead: usize, requests: usize) -> String;
+    fn rules_final_share(&self, percent: f64) -> String;
+
+    // ========== Rule Match ==========
+    fn action_match(&self) -> &'static str;
+    fn rules_match_title(&self) -> &'static str;
+    fn rules_match_input(&self) -> &'static str;
+    fn rules_match_hint(&self) -> &'static str;
+    fn rules_match_prompt(&self) -> &'static str;
+    fn rules_match_rule(&self) -> &'static str;
+    fn rules_match_none(&self) -> &'static str;
+    fn rules_match_from_profile(&self) -> &'static str;
+    fn rules_match_supported(&self, types: &str) -> String;
+    fn rules_match_skipped(&self, types: &str) -> String;
+    fn help_shortcut_rule_match(&self) -> &'static str;
+    fn notification_rule_match_failed(&self, error: &str) -> String;
 }
 
 // Compile-time language selection (zero runtime overhead):
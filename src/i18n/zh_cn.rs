@@ -21,6 +21,18 @@ impl Translate for ZhCN {
         "(CLI 模式)"
     }
 
+    fn ui_status_remote(&self) -> &'static str {
+        "[远程]"
+    }
+
+    fn ui_status_recording(&self) -> &'static str {
+        "[录制中]"
+    }
+
+    fn ui_status_replay(&self) -> &'static str {
+        "[回放]"
+    }
+
     // ========== 快捷键说明 ==========
     fn key_quit(&self) -> &'static str {
         "[q]uit"
@@ -92,6 +104,10 @@ impl Translate for ZhCN {
         format!("测试失败: {}", error)
     }
 
+    fn notification_test_url_ignored(&self) -> &'static str {
+        "使用 surge-cli 测试：已忽略 [testing] 中的 URL，Surge 使用了配置文件中的 proxy-test-url"
+    }
+
     // ========== Alert 消息 ==========
     fn alert_surge_not_running(&self) -> &'static str {
         "Surge 未运行 - 按 S 启动"
@@ -101,6 +117,10 @@ impl Translate for ZhCN {
         "HTTP API 不可用 - 按 R 重载配置"
     }
 
+    fn alert_surge_unreachable(&self) -> &'static str {
+        "无法通过 HTTP API 连接 Surge - 请检查主机、端口和密钥"
+    }
+
     // ========== 策略组 ==========
     fn policy_group_title(&self) -> &'static str {
         "策略组"
@@ -177,6 +197,10 @@ impl Translate for ZhCN {
         "出站模式"
     }
 
+    fn overview_global_policy(&self) -> &'static str {
+        "全局策略"
+    }
+
     fn overview_stats(&self) -> &'static str {
         "统计信息"
     }
@@ -369,13 +393,17 @@ impl Translate for ZhCN {
     }
 
     fn help_shortcut_switch_view(&self) -> &'static str {
-        "  1-5        - 切换视图"
+        "  0-9, u     - 切换视图"
     }
 
     fn help_shortcut_toggle_outbound(&self) -> &'static str {
         "  m          - 切换出站模式"
     }
 
+    fn help_shortcut_select_global_policy(&self) -> &'static str {
+        "  p          - 选择全局策略（全部代理）"
+    }
+
     fn help_shortcut_notification_history(&self) -> &'static str {
         "  n          - 通知历史"
     }
@@ -384,6 +412,14 @@ impl Translate for ZhCN {
         "  `          - 开发工具"
     }
 
+    fn help_shortcut_switch_instance(&self) -> &'static str {
+        "  w          - 切换 Surge 实例"
+    }
+
+    fn help_shortcut_switch_profile(&self) -> &'static str {
+        "  o          - 切换配置"
+    }
+
     fn help_shortcut_help(&self) -> &'static str {
         "  ?          - 此帮助"
     }
@@ -425,6 +461,26 @@ impl Translate for ZhCN {
         "  f          - 清空 DNS 缓存"
     }
 
+    fn help_shortcut_toggle_module(&self) -> &'static str {
+        "  Enter      - 启用/停用模块"
+    }
+
+    fn help_shortcut_profile_sections(&self) -> &'static str {
+        "  ↑/↓        - 跳转到段落"
+    }
+
+    fn help_shortcut_profile_scroll(&self) -> &'static str {
+        "  PgUp/PgDn  - 滚动配置"
+    }
+
+    fn help_shortcut_toggle_effective(&self) -> &'static str {
+        "  e          - 原始 / 生效配置"
+    }
+
+    fn help_shortcut_toggle_sensitive(&self) -> &'static str {
+        "  v          - 显示 / 隐藏敏感信息"
+    }
+
     // ---- 导航行 ----
     fn help_nav_up_down(&self) -> &'static str {
         "  j/k 或 ↓/↑  - 上下移动"
@@ -517,6 +573,26 @@ impl Translate for ZhCN {
         "流量捕获"
     }
 
+    fn feature_rewrite(&self) -> &'static str {
+        "重写"
+    }
+
+    fn feature_scripting(&self) -> &'static str {
+        "脚本"
+    }
+
+    fn feature_system_proxy(&self) -> &'static str {
+        "系统代理"
+    }
+
+    fn feature_enhanced_mode(&self) -> &'static str {
+        "增强模式"
+    }
+
+    fn overview_features(&self) -> &'static str {
+        "功能开关"
+    }
+
     fn status_enabled(&self) -> &'static str {
         "已启用"
     }
@@ -525,26 +601,30 @@ impl Translate for ZhCN {
         "已禁用"
     }
 
-    fn notification_mitm_enabled(&self) -> &'static str {
-        "MITM 已启用"
+    fn status_unsupported(&self) -> &'static str {
+        "不支持"
     }
 
-    fn notification_mitm_disabled(&self) -> &'static str {
-        "MITM 已禁用"
+    fn help_shortcut_toggle_feature(&self) -> &'static str {
+        "  ↑/↓ Enter  - 切换选中的功能"
     }
 
-    fn notification_capture_enabled(&self) -> &'static str {
-        "流量捕获已启用"
+    fn notification_feature_enabled(&self, feature: &str) -> String {
+        format!("{} 已启用", feature)
     }
 
-    fn notification_capture_disabled(&self) -> &'static str {
-        "流量捕获已禁用"
+    fn notification_feature_disabled(&self, feature: &str) -> String {
+        format!("{} 已禁用", feature)
     }
 
     fn notification_feature_toggle_failed(&self, error: &str) -> String {
         format!("功能切换失败: {}", error)
     }
 
+    fn notification_remote_unsupported(&self) -> &'static str {
+        "远程模式下不可用（需要本机 surge-cli）"
+    }
+
     // ========== DNS ==========
     fn view_dns(&self) -> &'static str {
         "DNS 缓存"
@@ -585,4 +665,647 @@ impl Translate for ZhCN {
     fn notification_dns_flush_failed(&self, error: &str) -> String {
         format!("清空 DNS 缓存失败: {}", error)
     }
+
+    fn dns_label_server(&self) -> &'static str {
+        "服务器"
+    }
+
+    fn dns_label_time_cost(&self) -> &'static str {
+        "解析耗时"
+    }
+
+    fn dns_servers_title(&self) -> &'static str {
+        "上游服务器"
+    }
+
+    fn dns_server_all(&self) -> &'static str {
+        "全部服务器"
+    }
+
+    fn dns_server_records(&self, count: usize, avg_ms: Option<f64>) -> String {
+        match avg_ms {
+            Some(avg) => format!("缓存 {} 条，平均 {:.0} ms", count, avg),
+            None => format!("缓存 {} 条", count),
+        }
+    }
+
+    fn dns_delay_not_tested(&self) -> &'static str {
+        "按 d 测试 DNS 延迟"
+    }
+
+    fn dns_delay_testing(&self) -> &'static str {
+        "测试中..."
+    }
+
+    fn help_shortcut_test_dns(&self) -> &'static str {
+        "  d          - 测试 DNS 延迟"
+    }
+
+    fn notification_dns_delay_completed(&self) -> &'static str {
+        "DNS 延迟测试完成"
+    }
+
+    fn notification_dns_delay_failed(&self, error: &str) -> String {
+        format!("DNS 延迟测试失败: {}", error)
+    }
+
+    // ========== 实例 ==========
+    fn instance_picker_title(&self) -> &'static str {
+        " Surge 实例 [Enter 切换 / ESC 关闭] "
+    }
+
+    fn instance_active(&self) -> &'static str {
+        "(当前)"
+    }
+
+    fn notification_instance_switched(&self, name: &str) -> String {
+        format!("已切换到实例 {}", name)
+    }
+
+    fn notification_instance_switch_failed(&self, error: &str) -> String {
+        format!("切换实例失败: {}", error)
+    }
+
+    // ========== 模块 ==========
+    fn view_modules(&self) -> &'static str {
+        "模块"
+    }
+
+    fn modules_list_title(&self) -> &'static str {
+        "模块列表"
+    }
+
+    fn modules_detail_title(&self) -> &'static str {
+        "模块详情"
+    }
+
+    fn modules_no_modules(&self) -> &'static str {
+        "无模块"
+    }
+
+    fn module_label_name(&self) -> &'static str {
+        "名称"
+    }
+
+    fn module_label_status(&self) -> &'static str {
+        "状态"
+    }
+
+    fn module_label_description(&self) -> &'static str {
+        "描述"
+    }
+
+    fn module_no_description(&self) -> &'static str {
+        "无描述"
+    }
+
+    fn overview_modules(&self) -> &'static str {
+        "模块"
+    }
+
+    fn overview_modules_enabled(&self, enabled: usize, total: usize) -> String {
+        format!("已启用 {}/{}", enabled, total)
+    }
+
+    fn notification_module_enabled(&self, name: &str) -> String {
+        format!("模块已启用: {}", name)
+    }
+
+    fn notification_module_disabled(&self, name: &str) -> String {
+        format!("模块已停用: {}", name)
+    }
+
+    fn notification_module_toggle_failed(&self, error: &str) -> String {
+        format!("切换模块失败: {}", error)
+    }
+
+    // ========== 全局策略 ==========
+    fn global_policy_picker_title(&self) -> &'static str {
+        " 全局策略 [Enter 全部代理 / ESC 关闭] "
+    }
+
+    fn global_policy_current(&self) -> &'static str {
+        "(当前)"
+    }
+
+    fn global_policy_group(&self) -> &'static str {
+        "策略组"
+    }
+
+    fn notification_global_policy_set(&self, policy: &str) -> String {
+        format!("全部流量经由 {} 代理", policy)
+    }
+
+    fn notification_global_policy_failed(&self, error: &str) -> String {
+        format!("设置全局策略失败: {}", error)
+    }
+
+    // ========== 配置 ==========
+    fn profile_picker_title(&self) -> &'static str {
+        " 配置 [Enter 切换 / ESC 关闭] "
+    }
+
+    fn profile_active(&self) -> &'static str {
+        "(当前)"
+    }
+
+    fn confirm_profile_switch_title(&self) -> &'static str {
+        " 确认切换配置 "
+    }
+
+    fn confirm_profile_switch_message(&self, name: &str) -> String {
+        format!("确定要切换到配置 \"{}\" 吗？", name)
+    }
+
+    fn confirm_profile_switch_current(&self, name: &str) -> String {
+        format!("当前配置: {}", name)
+    }
+
+    fn confirm_profile_switch_hint(&self) -> &'static str {
+        "[Enter] 切换  [ESC] 返回"
+    }
+
+    fn notification_profile_switched(&self, name: &str) -> String {
+        format!("已切换到配置 {}", name)
+    }
+
+    fn notification_profile_switch_failed(&self, error: &str) -> String {
+        format!("切换配置失败: {}", error)
+    }
+
+    fn notification_profiles_failed(&self, error: &str) -> String {
+        format!("获取配置列表失败: {}", error)
+    }
+
+    // ========== 配置文件视图 ==========
+    fn view_profile(&self) -> &'static str {
+        "配置文件"
+    }
+
+    fn profile_sections_title(&self) -> &'static str {
+        "段落"
+    }
+
+    fn profile_no_sections(&self) -> &'static str {
+        "无段落"
+    }
+
+    fn profile_original(&self) -> &'static str {
+        "原始配置"
+    }
+
+    fn profile_effective(&self) -> &'static str {
+        "生效配置"
+    }
+
+    fn profile_not_loaded(&self) -> &'static str {
+        "配置未加载"
+    }
+
+    fn profile_no_matches(&self) -> &'static str {
+        "无匹配行"
+    }
+
+    fn action_reveal(&self) -> &'static str {
+        "显示"
+    }
+
+    fn action_mask(&self) -> &'static str {
+        "隐藏"
+    }
+
+    fn notification_profile_load_failed(&self, error: &str) -> String {
+        format!("加载配置失败: {}", error)
+    }
+
+    // ========== 事件视图 ==========
+    fn view_events(&self) -> &'static str {
+        "事件"
+    }
+
+    fn events_list_title(&self) -> &'static str {
+        "事件中心"
+    }
+
+    fn events_detail_title(&self) -> &'static str {
+        "事件详情"
+    }
+
+    fn events_no_events(&self) -> &'static str {
+        "暂无事件"
+    }
+
+    fn event_level_info(&self) -> &'static str {
+        "信息"
+    }
+
+    fn event_level_warning(&self) -> &'static str {
+        "警告"
+    }
+
+    fn event_level_error(&self) -> &'static str {
+        "错误"
+    }
+
+    fn event_label_level(&self) -> &'static str {
+        "级别"
+    }
+
+    fn event_label_time(&self) -> &'static str {
+        "时间"
+    }
+
+    fn event_label_identifier(&self) -> &'static str {
+        "标识"
+    }
+
+    fn event_label_content(&self) -> &'static str {
+        "内容"
+    }
+
+    // ========== 流量 ==========
+    fn overview_traffic(&self) -> &'static str {
+        "流量"
+    }
+
+    fn traffic_upload(&self) -> &'static str {
+        "上传"
+    }
+
+    fn traffic_download(&self) -> &'static str {
+        "下载"
+    }
+
+    fn traffic_peak(&self) -> &'static str {
+        "峰值"
+    }
+
+    fn traffic_totals(&self) -> &'static str {
+        "总量"
+    }
+
+    fn traffic_active_connections(&self) -> &'static str {
+        "活动连接"
+    }
+
+    fn traffic_estimated(&self) -> &'static str {
+        "  根据连接估算（无 /v1/traffic）"
+    }
+
+    // ========== 脚本 ==========
+    fn view_scripting(&self) -> &'static str {
+        "脚本"
+    }
+
+    fn script_settings_title(&self) -> &'static str {
+        "脚本控制台"
+    }
+
+    fn script_label_file(&self) -> &'static str {
+        "文件"
+    }
+
+    fn script_label_mock(&self) -> &'static str {
+        "模拟环境"
+    }
+
+    fn script_label_timeout(&self) -> &'static str {
+        "超时"
+    }
+
+    fn script_label_status(&self) -> &'static str {
+        "状态"
+    }
+
+    fn script_no_file(&self) -> &'static str {
+        "未选择文件（按 l）"
+    }
+
+    fn script_status_idle(&self) -> &'static str {
+        "尚未运行"
+    }
+
+    fn script_status_running(&self) -> &'static str {
+        "运行中..."
+    }
+
+    fn script_status_finished(&self, elapsed_ms: u128, time: &str) -> String {
+        format!("{} 完成，耗时 {} ms", time, elapsed_ms)
+    }
+
+    fn script_result_title(&self) -> &'static str {
+        "结果"
+    }
+
+    fn script_logs_title(&self) -> &'static str {
+        "日志"
+    }
+
+    fn script_no_logs(&self) -> &'static str {
+        "无输出"
+    }
+
+    fn script_exception(&self) -> &'static str {
+        "异常"
+    }
+
+    fn script_error(&self) -> &'static str {
+        "错误"
+    }
+
+    fn action_run(&self) -> &'static str {
+        "运行"
+    }
+
+    fn help_shortcut_run_script(&self) -> &'static str {
+        "  Enter      - 运行脚本（重新读取文件）"
+    }
+
+    fn help_shortcut_script_file(&self) -> &'static str {
+        "  l          - 设置脚本文件"
+    }
+
+    fn help_shortcut_script_mock(&self) -> &'static str {
+        "  x          - 切换模拟环境"
+    }
+
+    fn help_shortcut_script_timeout(&self) -> &'static str {
+        "  +/-        - 调整超时"
+    }
+
+    fn notification_script_no_file(&self) -> &'static str {
+        "请先设置脚本文件（按 l）"
+    }
+
+    fn notification_script_finished(&self) -> &'static str {
+        "脚本运行完成"
+    }
+
+    fn notification_script_failed(&self, error: &str) -> String {
+        format!("脚本运行失败: {}", error)
+    }
+
+    // ========== MITM CA ==========
+    fn overview_mitm_ca(&self) -> &'static str {
+        "MITM CA 证书"
+    }
+
+    fn ca_label_subject(&self) -> &'static str {
+        "主题"
+    }
+
+    fn ca_label_validity(&self) -> &'static str {
+        "有效期"
+    }
+
+    fn ca_label_fingerprint(&self) -> &'static str {
+        "SHA-256"
+    }
+
+    fn ca_expires_in(&self, days: i64) -> String {
+        format!("{} 天后过期", days)
+    }
+
+    fn ca_expired(&self) -> &'static str {
+        "已过期"
+    }
+
+    // ========== Engine Control ==========
+    fn engine_menu_title(&self) -> &'static str {
+        " 引擎控制 [Enter 执行 / ESC 关闭] "
+    }
+
+    fn engine_action_stop(&self) -> &'static str {
+        "停止 Surge"
+    }
+
+    fn engine_action_restart(&self) -> &'static str {
+        "重启 Surge"
+    }
+
+    fn engine_log_level(&self) -> &'static str {
+        "日志级别"
+    }
+
+    fn action_engine_control(&self) -> &'static str {
+        "引擎控制"
+    }
+
+    fn confirm_engine_title(&self) -> &'static str {
+        " 确认 "
+    }
+
+    fn confirm_engine_stop_message(&self) -> &'static str {
+        "停止 Surge？所有代理流量都会中断。"
+    }
+
+    fn confirm_engine_restart_message(&self) -> &'static str {
+        "重启 Surge？所有代理流量都会中断。"
+    }
+
+    fn confirm_engine_type_word(&self, word: &str) -> String {
+        format!("输入 \"{}\" 确认：", word)
+    }
+
+    fn confirm_engine_hint(&self) -> &'static str {
+        "[Enter] 确认  [ESC] 返回"
+    }
+
+    fn help_shortcut_engine_control(&self) -> &'static str {
+        "  e          - 引擎控制（停止/重启、日志级别）"
+    }
+
+    fn notification_surge_stopped(&self) -> &'static str {
+        "Surge 已停止"
+    }
+
+    fn notification_surge_restarting(&self) -> &'static str {
+        "正在重启 Surge..."
+    }
+
+    fn notification_surge_restarted(&self) -> &'static str {
+        "Surge 已重启"
+    }
+
+    fn notification_log_level_set(&self, level: &str) -> String {
+        format!("日志级别已设为 {}", level)
+    }
+
+    fn notification_engine_failed(&self, error: &str) -> String {
+        format!("引擎控制失败: {}", error)
+    }
+
+    // ========== Diagnostics ==========
+    fn view_diagnostics(&self) -> &'static str {
+        "诊断"
+    }
+
+    fn diagnostics_title(&self) -> &'static str {
+        " 网络诊断 [T 运行] "
+    }
+
+    fn diagnostics_unsupported(&self) -> &'static str {
+        "诊断需要 surge-cli（远程模式不可用）"
+    }
+
+    fn diagnostics_running(&self) -> &'static str {
+        "正在诊断..."
+    }
+
+    fn diagnostics_not_run(&self) -> &'static str {
+        "尚未运行（按 T）"
+    }
+
+    fn diagnostics_all_passed(&self) -> &'static str {
+        "全部检查通过"
+    }
+
+    fn diagnostics_failed_sections(&self, sections: &str) -> String {
+        format!("失败: {}", sections)
+    }
+
+    fn diagnostics_section_dns(&self) -> &'static str {
+        "DNS"
+    }
+
+    fn diagnostics_section_router(&self) -> &'static str {
+        "路由器"
+    }
+
+    fn diagnostics_section_internet(&self) -> &'static str {
+        "互联网"
+    }
+
+    fn diagnostics_section_proxy(&self) -> &'static str {
+        "代理"
+    }
+
+    fn diagnostics_section_other(&self) -> &'static str {
+        "其他"
+    }
+
+    fn help_shortcut_run_diagnostics(&self) -> &'static str {
+        "  t          - 运行网络诊断"
+    }
+
+    fn notification_diagnostics_completed(&self) -> &'static str {
+        "诊断完成"
+    }
+
+    fn notification_diagnostics_failed(&self, error: &str) -> String {
+        format!("诊断失败: {}", error)
+    }
+
+    // ========== Rules ==========
+    fn view_rules(&self) -> &'static str {
+        "规则"
+    }
+
+    fn rules_list_title(&self) -> &'static str {
+        "规则"
+    }
+
+    fn rules_detail_title(&self) -> &'static str {
+        " 规则详情 "
+    }
+
+    fn rules_no_rules(&self) -> &'static str {
+        "没有规则"
+    }
+
+    fn rules_unsupported(&self) -> &'static str {
+        "当前后端无法获取规则列表"
+    }
+
+    fn rules_label_type(&self) -> &'static str {
+        "类型"
+    }
+
+    fn rules_label_value(&self) -> &'static str {
+        "值"
+    }
+
+    fn rules_label_policy(&self) -> &'static str {
+        "策略"
+    }
+
+    fn rules_label_options(&self) -> &'static str {
+        "选项"
+    }
+
+    fn rules_label_hits(&self) -> &'static str {
+        "命中"
+    }
+
+    fn rules_label_last_host(&self) -> &'static str {
+        "最近主机"
+    }
+
+    fn rules_hits_value(&self, count: usize, percent: f64) -> String {
+        format!("{}（占最近请求的 {:.0}%）", count, percent)
+    }
+
+    fn rules_never_matched(&self) -> &'static str {
+        "最近请求中没有命中"
+    }
+
+    fn rules_summary(&self, rules: usize, dead: usize, requests: usize) -> String {
+        format!(
+            "共 {} 条规则，{} 条在最近 {} 个请求中没有命中",
+            rules, dead, requests
+        )
+    }
+
+    fn rules_final_share(&self, percent: f64) -> String {
+        format!("FINAL：占最近请求的 {:.0}%", percent)
+    }
+
+    // ========== Rule Match ==========
+    fn action_match(&self) -> &'static str {
+        "匹配"
+    }
+
+    fn rules_match_title(&self) -> &'static str {
+        " 规则匹配 "
+    }
+
+    fn rules_match_input(&self) -> &'static str {
+        "测试"
+    }
+
+    fn rules_match_hint(&self) -> &'static str {
+        "主机、IP、URL、端口或 process=名称；Enter 匹配，Esc 取消"
+    }
+
+    fn rules_match_prompt(&self) -> &'static str {
+        "按 Enter 查找主机、IP、URL、端口或进程会命中的规则"
+    }
+
+    fn rules_match_rule(&self) -> &'static str {
+        "规则"
+    }
+
+    fn rules_match_none(&self) -> &'static str {
+        "没有匹配的规则"
+    }
+
+    fn rules_match_from_profile(&self) -> &'static str {
+        "规则来自配置文件 [Rule] 段"
+    }
+
+    fn rules_match_supported(&self, types: &str) -> String {
+        format!("支持：{}", types)
+    }
+
+    fn rules_match_skipped(&self, types: &str) -> String {
+        format!("未评估：{}", types)
+    }
+
+    fn help_shortcut_rule_match(&self) -> &'static str {
+        "  Enter      - 查找主机、IP、端口或进程命中的规则"
+    }
+
+    fn notification_rule_match_failed(&self, error: &str) -> String {
+        format!("规则匹配失败：{}", error)
+    }
 }
@@ -0,0 +1,62 @@
+/// MITM CA certificate handling
+///
+/// Surge serves its MITM CA as DER (`/v1/mitm/ca`); this module extracts the
+/// details shown in the UI and converts it to PEM for export.
+use crate::domain::errors::{Result, SurgeError};
+use crate::domain::models::CaCertificate;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use x509_parser::prelude::*;
+
+/// Base64 characters per PEM line
+const PEM_LINE_WIDTH: usize = 64;
+
+/// Parse a DER certificate
+pub fn parse_certificate(der: Vec<u8>) -> Result<CaCertificate> {
+    let (_, certificate) =
+        X509Certificate::from_der(&der).map_err(|e| parse_error(e.to_string()))?;
+    let validity = certificate.validity();
+    let not_before = to_datetime(validity.not_before)?;
+    let not_after = to_datetime(validity.not_after)?;
+    let subject = certificate.subject().to_string();
+
+    let fingerprint = Sha256::digest(&der)
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(":");
+
+    Ok(CaCertificate {
+        der,
+        subject,
+        not_before,
+        not_after,
+        fingerprint,
+    })
+}
+
+/// PEM encoding of a DER certificate
+pub fn to_pem(der: &[u8]) -> String {
+    let encoded = BASE64.encode(der);
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in encoded.as_bytes().chunks(PEM_LINE_WIDTH) {
+        // Base64 output is ASCII
+        pem.push_str(std::str::from_utf8(line).unwrap_or_default());
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+fn to_datetime(time: ASN1Time) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(time.timestamp(), 0)
+        .ok_or_else(|| parse_error(format!("invalid validity date {}", time)))
+}
+
+fn parse_error(error: String) -> SurgeError {
+    SurgeError::ParseError {
+        source: "MITM CA certificate".to_string(),
+        error,
+    }
+}
@@ -1,13 +1,44 @@
 /// surge-cli client
 ///
 /// Interacts with Surge via the surge-cli command-line tool
-use crate::domain::errors::{Result, SurgeError};
+use crate::domain::{
+    backend::{Operation, SurgeBackend},
+    errors::{Result, SurgeError},
+    models::{
+        DiagnosticCheck, DiagnosticSection, DiagnosticStatus, DnsRecord, LatencyTest, LogLevel,
+        OutboundMode, PolicyDetail, PolicyGroup, PolicyGroupsResponse, PolicyType, Request, Rule,
+    },
+};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::process::Command;
+use tokio::sync::Mutex;
+
+/// How long one `dump policy` result is reused
+///
+/// A refresh asks for the outbound mode, the groups and every group's
+/// selection at once; all of them come from the same dump.
+const POLICY_DUMP_TTL: Duration = Duration::from_secs(1);
 
 /// surge-cli client
 #[derive(Clone)]
 pub struct SurgeCliClient {
     cli_path: String,
+    policy_dump: Arc<Mutex<Option<(Instant, PolicyDump)>>>,
+}
+
+/// Parsed `dump policy` output
+#[derive(Debug, Clone, Default)]
+struct PolicyDump {
+    proxies: Vec<String>,
+    groups: Vec<PolicyGroup>,
+    mode: Option<OutboundMode>,
 }
 
 impl SurgeCliClient {
@@ -16,7 +47,10 @@ impl SurgeCliClient {
         let cli_path = cli_path.unwrap_or_else(|| {
             "/Applications/Surge.app/Contents/Applications/surge-cli".to_string()
         });
-        Self { cli_path }
+        Self {
+            cli_path,
+            policy_dump: Arc::new(Mutex::new(None)),
+        }
     }
 
     /// Execute a surge-cli command
@@ -92,6 +126,81 @@ impl SurgeCliClient {
         self.execute_json(&["dump", "dns"]).await
     }
 
+    /// Get recent requests as domain models
+    pub async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        let dump = self.dump_requests().await?;
+        parse_list(&dump, "requests", "dump request")
+    }
+
+    /// Get active connections as domain models
+    pub async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        let dump = self.dump_active().await?;
+        parse_list(&dump, "requests", "dump active")
+    }
+
+    /// Get the ordered rule list (lines that do not parse are skipped)
+    pub async fn get_rules(&self) -> Result<Vec<Rule>> {
+        let dump = self.dump_rules().await?;
+        let lines: Vec<String> = parse_list(&dump, "rules", "dump rule")?;
+        Ok(lines.iter().filter_map(|r| Rule::parse(r)).collect())
+    }
+
+    /// Get DNS cache as domain models
+    pub async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
+        let dump = self.dump_dns().await?;
+        parse_list(&dump, "dnsCache", "dump dns")
+    }
+
+    /// Get parsed `dump policy` output, reusing a recent one
+    async fn policy_dump(&self) -> Result<PolicyDump> {
+        // Held across the command so concurrent callers wait for one dump
+        let mut cached = self.policy_dump.lock().await;
+        if let Some((fetched_at, ref dump)) = *cached {
+            if fetched_at.elapsed() < POLICY_DUMP_TTL {
+                return Ok(dump.clone());
+            }
+        }
+
+        let dump = parse_policy_dump(&self.dump_policies().await?)?;
+        *cached = Some((Instant::now(), dump.clone()));
+        Ok(dump)
+    }
+
+    /// Get outbound mode
+    pub async fn get_outbound_mode(&self) -> Result<OutboundMode> {
+        self.policy_dump()
+            .await?
+            .mode
+            .ok_or_else(|| SurgeError::ParseError {
+                source: "surge-cli dump policy".to_string(),
+                error: "no outbound mode".to_string(),
+            })
+    }
+
+    /// Get all policy names (proxies, then policy groups)
+    pub async fn get_policies(&self) -> Result<Vec<String>> {
+        let dump = self.policy_dump().await?;
+        let mut all_policies = dump.proxies;
+        all_policies.extend(dump.groups.into_iter().map(|g| g.name));
+        Ok(all_policies)
+    }
+
+    /// Get policy groups, sorted by name
+    pub async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        Ok(self.policy_dump().await?.groups)
+    }
+
+    /// Get the selected policy of a policy group
+    pub async fn get_policy_group_selected(&self, group_name: &str) -> Result<Option<String>> {
+        Ok(self
+            .policy_dump()
+            .await?
+            .groups
+            .into_iter()
+            .find(|g| g.name == group_name)
+            .and_then(|g| g.selected))
+    }
+
     /// Get profile content
     pub async fn dump_profile(&self, effective: bool) -> Result<String> {
         let profile_type = if effective { "effective" } else { "original" };
@@ -132,8 +241,8 @@ impl SurgeCliClient {
     }
 
     /// Set log level
-    pub async fn set_log_level(&self, level: &str) -> Result<()> {
-        self.execute(&["set-log-level", level]).await?;
+    pub async fn set_log_level(&self, level: LogLevel) -> Result<()> {
+        self.execute(&["set-log-level", level.as_str()]).await?;
         Ok(())
     }
 
@@ -193,4 +302,554 @@ impl SurgeCliClient {
 
         None
     }
+
+    /// Test policies one by one with `test-policy`
+    ///
+    /// At most `parallelism` commands run at once. A policy that fails, does
+    /// not answer within the timeout or is slower than it counts as
+    /// unavailable. surge-cli has no URL option: Surge tests with the
+    /// profile's `proxy-test-url`.
+    pub async fn test_policies(
+        &self,
+        names: &[String],
+        test: &LatencyTest,
+    ) -> Vec<(String, Option<u32>, bool)> {
+        let timeout_ms = test.timeout.as_millis();
+        let tests: Vec<_> = names
+            .iter()
+            .map(|name| async move {
+                let output = tokio::time::timeout(test.timeout, self.test_policy(name)).await;
+                let latency = match output {
+                    // Lines may or may not start with "ProxyName:"
+                    Ok(Ok(output)) => output
+                        .lines()
+                        .filter_map(|line| {
+                            Self::parse_test_line(line)
+                                .or_else(|| Self::parse_test_line(&format!("{}: {}", name, line)))
+                        })
+                        .find_map(|(_, latency, _)| latency),
+                    Ok(Err(e)) => {
+                        tracing::warn!("surge-cli test-policy {} failed: {}", name, e);
+                        None
+                    }
+                    Err(_) => None,
+                };
+                let latency = latency.filter(|ms| u128::from(*ms) <= timeout_ms);
+                (name.clone(), latency, latency.is_some())
+            })
+            .collect();
+
+        stream::iter(tests)
+            .buffered(test.parallelism.max(1))
+            .collect()
+            .await
+    }
+}
+
+#[async_trait]
+impl SurgeBackend for SurgeCliClient {
+    fn name(&self) -> &'static str {
+        "surge-cli"
+    }
+
+    fn capabilities(&self) -> &'static [Operation] {
+        &[
+            Operation::OutboundMode,
+            Operation::PolicyGroups,
+            Operation::TestPolicy,
+            Operation::TestPolicyGroup,
+            Operation::TestAllPolicies,
+            Operation::TestPolicyLatency,
+            Operation::Rules,
+            Operation::RecentRequests,
+            Operation::ActiveConnections,
+            Operation::KillConnection,
+            Operation::ReloadConfig,
+            Operation::SwitchProfile,
+            Operation::ProfileDump,
+            Operation::DnsCache,
+            Operation::FlushDns,
+            Operation::StopSurge,
+            Operation::LogLevel,
+            Operation::Diagnostics,
+        ]
+    }
+
+    /// `test-policy` has no URL option: Surge uses the profile's `proxy-test-url`
+    fn uses_test_url(&self) -> bool {
+        false
+    }
+
+    async fn is_available(&self) -> bool {
+        tokio::fs::metadata(&self.cli_path).await.is_ok()
+    }
+
+    async fn get_outbound_mode(&self) -> Result<OutboundMode> {
+        SurgeCliClient::get_outbound_mode(self).await
+    }
+
+    async fn get_policies(&self) -> Result<Vec<String>> {
+        SurgeCliClient::get_policies(self).await
+    }
+
+    async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        SurgeCliClient::get_policy_groups(self).await
+    }
+
+    async fn get_policy_group_selected(&self, group_name: &str) -> Result<Option<String>> {
+        SurgeCliClient::get_policy_group_selected(self, group_name).await
+    }
+
+    async fn test_policy(&self, name: &str, test: &LatencyTest) -> Result<()> {
+        tokio::time::timeout(test.timeout, SurgeCliClient::test_policy(self, name))
+            .await
+            .map_err(|_| SurgeError::CliExecutionFailed {
+                command: format!("{} test-policy {}", self.cli_path, name),
+                error: "timed out".to_string(),
+            })??;
+        Ok(())
+    }
+
+    async fn test_policy_group(&self, group_name: &str) -> Result<Vec<String>> {
+        self.test_group(group_name).await?;
+        // surge-cli does not report the available list
+        Ok(Vec::new())
+    }
+
+    async fn test_all_policies(&self) -> Result<Vec<PolicyDetail>> {
+        let results = SurgeCliClient::test_all_policies(self).await?;
+        Ok(results
+            .into_iter()
+            .map(|(name, latency, alive)| PolicyDetail {
+                name,
+                policy_type: PolicyType::Direct, // Temporary value
+                alive,
+                latency,
+                last_test_at: None,
+            })
+            .collect())
+    }
+
+    async fn test_policy_latency(
+        &self,
+        names: &[String],
+        test: &LatencyTest,
+    ) -> Result<Vec<PolicyDetail>> {
+        let results = self.test_policies(names, test).await;
+        Ok(results
+            .into_iter()
+            .map(|(name, latency, alive)| PolicyDetail {
+                name,
+                policy_type: PolicyType::Unknown,
+                alive,
+                latency,
+                last_test_at: None,
+            })
+            .collect())
+    }
+
+    async fn get_rules(&self) -> Result<Vec<Rule>> {
+        SurgeCliClient::get_rules(self).await
+    }
+
+    async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        SurgeCliClient::get_recent_requests(self).await
+    }
+
+    async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        SurgeCliClient::get_active_connections(self).await
+    }
+
+    async fn kill_connection(&self, id: u64) -> Result<()> {
+        SurgeCliClient::kill_connection(self, id).await
+    }
+
+    async fn reload_config(&self) -> Result<()> {
+        SurgeCliClient::reload_config(self).await
+    }
+
+    async fn switch_profile(&self, name: &str) -> Result<()> {
+        SurgeCliClient::switch_profile(self, name).await
+    }
+
+    async fn dump_profile(&self, effective: bool) -> Result<String> {
+        SurgeCliClient::dump_profile(self, effective).await
+    }
+
+    async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
+        SurgeCliClient::get_dns_cache(self).await
+    }
+
+    async fn flush_dns(&self) -> Result<()> {
+        SurgeCliClient::flush_dns(self).await
+    }
+
+    async fn stop_surge(&self) -> Result<()> {
+        SurgeCliClient::stop_surge(self).await
+    }
+
+    async fn set_log_level(&self, level: LogLevel) -> Result<()> {
+        SurgeCliClient::set_log_level(self, level).await
+    }
+
+    /// Runs `diagnostics` and `test-network` concurrently; a command that
+    /// fails is reported as a failed check as long as the other one succeeds
+    async fn run_diagnostics(&self) -> Result<Vec<DiagnosticCheck>> {
+        let (diagnostics, network) =
+            tokio::join!(SurgeCliClient::run_diagnostics(self), self.test_network());
+        if let (Err(e), Err(_)) = (&diagnostics, &network) {
+            return Err(e.clone());
+        }
+
+        let mut checks = Vec::new();
+        for (command, output) in [("diagnostics", diagnostics), ("test-network", network)] {
+            match output {
+                Ok(output) => checks.extend(parse_diagnostics(&output)),
+                Err(e) => checks.push(DiagnosticCheck {
+                    section: DiagnosticSection::Other,
+                    name: format!("surge-cli {}", command),
+                    detail: e.to_string(),
+                    status: DiagnosticStatus::Fail,
+                }),
+            }
+        }
+        Ok(checks)
+    }
+}
+
+/// Parse a list dump into domain models
+///
+/// Dumps print the HTTP API response: an object holding the list under `key`
+/// (`{"requests": [...]}`). Entries that do not parse are skipped so one odd
+/// entry does not hide the rest.
+fn parse_list<T: DeserializeOwned>(dump: &Value, key: &str, command: &str) -> Result<Vec<T>> {
+    let entries =
+        dump.get(key)
+            .and_then(Value::as_array)
+            .ok_or_else(|| SurgeError::ParseError {
+                source: format!("surge-cli {}", command),
+                error: format!("expected an object with a \"{}\" list", key),
+            })?;
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| {
+            serde_json::from_value(entry.clone())
+                .inspect_err(|e| tracing::debug!("Skipping {} entry: {}", command, e))
+                .ok()
+        })
+        .collect())
+}
+
+/// `dump policy` output
+#[derive(Debug, Deserialize)]
+struct PolicyDumpOutput {
+    /// Proxy names
+    proxies: Vec<String>,
+    /// Group name → members (`/v1/policy_groups` format)
+    #[serde(rename = "policy-groups")]
+    policy_groups: PolicyGroupsResponse,
+    /// Group name → selected policy
+    #[serde(default)]
+    selected: HashMap<String, String>,
+    /// Outbound mode
+    #[serde(default)]
+    mode: Option<OutboundMode>,
+}
+
+/// Parse `dump policy` output
+fn parse_policy_dump(dump: &Value) -> Result<PolicyDump> {
+    let output: PolicyDumpOutput =
+        serde_json::from_value(dump.clone()).map_err(|e| SurgeError::ParseError {
+            source: "surge-cli dump policy".to_string(),
+            error: e.to_string(),
+        })?;
+
+    let mut selected = output.selected;
+    let mut groups: Vec<PolicyGroup> = output
+        .policy_groups
+        .into_iter()
+        .map(|(name, policies)| PolicyGroup {
+            selected: selected.remove(&name),
+            name,
+            policies,
+            available_policies: None,
+        })
+        .collect();
+    // Sort by group name to maintain stable order (same as the HTTP API path)
+    groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(PolicyDump {
+        proxies: output.proxies,
+        groups,
+        mode: output.mode,
+    })
+}
+
+/// Parse `diagnostics` / `test-network` output into checks
+///
+/// Both commands print "Name: result" lines. `diagnostics` groups them under
+/// section headers ("DNS:", "Router:", "Internet:", "Proxy:" on a line of their
+/// own); `test-network` prints one line per section ("DNS: 21 ms"). Lines
+/// without a colon are titles or progress messages and are skipped.
+fn parse_diagnostics(output: &str) -> Vec<DiagnosticCheck> {
+    let mut header = None;
+    let mut checks = Vec::new();
+
+    for line in output.lines() {
+        let Some((name, detail)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let (name, detail) = (name.trim(), detail.trim());
+        if name.is_empty() {
+            continue;
+        }
+        if detail.is_empty() {
+            header = Some(diagnostic_section(name).unwrap_or(DiagnosticSection::Other));
+            continue;
+        }
+
+        checks.push(DiagnosticCheck {
+            section: header
+                .or_else(|| diagnostic_section(name))
+                .unwrap_or(DiagnosticSection::Other),
+            name: name.to_string(),
+            detail: detail.to_string(),
+            status: diagnostic_status(detail),
+        });
+    }
+    checks
+}
+
+/// Section named by a header or a `test-network` check ("DNS", "Router", ...)
+fn diagnostic_section(name: &str) -> Option<DiagnosticSection> {
+    match name.to_lowercase().as_str() {
+        "dns" => Some(DiagnosticSection::Dns),
+        "router" | "gateway" => Some(DiagnosticSection::Router),
+        "internet" => Some(DiagnosticSection::Internet),
+        "proxy" | "proxies" | "policy" | "policies" => Some(DiagnosticSection::Proxy),
+        _ => None,
+    }
+}
+
+/// Verdict of a result text, decided by how it starts: a latency ("21 ms") or
+/// "OK" passes, "Failed", "Timeout" or "Error" fails, anything else (addresses,
+/// "Not Configured") is informational
+fn diagnostic_status(detail: &str) -> DiagnosticStatus {
+    const PASS_WORDS: &[&str] = &["ok", "passed", "success", "succeeded", "reachable"];
+    const FAIL_WORDS: &[&str] = &[
+        "failed",
+        "failure",
+        "error",
+        "timeout",
+        "timed",
+        "unreachable",
+        "refused",
+    ];
+
+    let detail = detail.to_lowercase();
+    let first_word = detail
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default();
+
+    if is_latency(&detail) || PASS_WORDS.contains(&first_word) {
+        DiagnosticStatus::Pass
+    } else if FAIL_WORDS.contains(&first_word) {
+        DiagnosticStatus::Fail
+    } else {
+        DiagnosticStatus::Info
+    }
+}
+
+/// Whether a result is a latency: "21 ms", "21ms" or "12.5 ms"
+fn is_latency(detail: &str) -> bool {
+    detail.strip_suffix("ms").is_some_and(|number| {
+        let number = number.trim_end();
+        number.starts_with(|c: char| c.is_ascii_digit())
+            && number.chars().all(|c| c.is_ascii_digit() || c == '.')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (section, name, status) of each check
+    fn summary(checks: &[DiagnosticCheck]) -> Vec<(DiagnosticSection, &str, DiagnosticStatus)> {
+        checks
+            .iter()
+            .map(|c| (c.section, c.name.as_str(), c.status))
+            .collect()
+    }
+
+    #[test]
+    fn parse_diagnostics_passing() {
+        use DiagnosticSection::*;
+        use DiagnosticStatus::*;
+
+        let checks = parse_diagnostics(include_str!("../../fixtures/surge-cli/diagnostics.txt"));
+        assert_eq!(
+            summary(&checks),
+            [
+                (Dns, "System DNS Servers", Info),
+                (Dns, "Resolve apple.com", Pass),
+                (Dns, "Encrypted DNS", Info),
+                (Router, "Gateway 192.168.1.1", Pass),
+                (Internet, "HTTP Connectivity", Pass),
+                (Internet, "IPv6 Connectivity", Info),
+                (Proxy, "Proxy Server", Pass),
+                (Proxy, "Rule-based Policy", Info),
+            ]
+        );
+        assert_eq!(checks[1].detail, "18 ms");
+    }
+
+    #[test]
+    fn parse_diagnostics_failing() {
+        use DiagnosticSection::*;
+        use DiagnosticStatus::*;
+
+        let checks = parse_diagnostics(include_str!(
+            "../../fixtures/surge-cli/diagnostics-failing.txt"
+        ));
+        assert_eq!(
+            summary(&checks),
+            [
+                (Dns, "System DNS Servers", Info),
+                (Dns, "Resolve apple.com", Fail),
+                (Router, "Gateway 192.168.1.1", Fail),
+                (Internet, "HTTP Connectivity", Fail),
+                // "no" later in the text does not turn a pass into a failure
+                (Proxy, "Proxy Server", Pass),
+            ]
+        );
+        assert_eq!(
+            checks[3].detail,
+            "Error: The Internet connection appears to be offline."
+        );
+    }
+
+    #[test]
+    fn parse_test_network() {
+        use DiagnosticSection::*;
+        use DiagnosticStatus::*;
+
+        let checks = parse_diagnostics(include_str!("../../fixtures/surge-cli/test-network.txt"));
+        assert_eq!(
+            summary(&checks),
+            [
+                (Router, "Router", Pass),
+                (Dns, "DNS", Pass),
+                (Internet, "Internet", Pass),
+            ]
+        );
+
+        let checks = parse_diagnostics(include_str!(
+            "../../fixtures/surge-cli/test-network-failing.txt"
+        ));
+        assert_eq!(
+            summary(&checks),
+            [
+                (Router, "Router", Pass),
+                (Dns, "DNS", Fail),
+                (Internet, "Internet", Fail),
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostic_section_matches_whole_names() {
+        assert_eq!(diagnostic_section("DNS"), Some(DiagnosticSection::Dns));
+        assert_eq!(diagnostic_section("Proxy"), Some(DiagnosticSection::Proxy));
+        assert_eq!(diagnostic_section("Encrypted DNS"), None);
+        assert_eq!(diagnostic_section("Network Diagnostics"), None);
+    }
+
+    #[test]
+    fn diagnostic_status_reads_leading_verdict() {
+        assert_eq!(diagnostic_status("12.5 ms"), DiagnosticStatus::Pass);
+        assert_eq!(diagnostic_status("8ms"), DiagnosticStatus::Pass);
+        assert_eq!(diagnostic_status("OK"), DiagnosticStatus::Pass);
+        assert_eq!(diagnostic_status("Timed out"), DiagnosticStatus::Fail);
+        assert_eq!(diagnostic_status("Not Configured"), DiagnosticStatus::Info);
+        assert_eq!(diagnostic_status("No Errors"), DiagnosticStatus::Info);
+        assert_eq!(diagnostic_status("192.168.1.1"), DiagnosticStatus::Info);
+    }
+
+    fn fixture(json: &str) -> Value {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn parse_request_dumps() {
+        let dump = fixture(include_str!("../../fixtures/surge-cli/dump-request.json"));
+        let requests: Vec<Request> = parse_list(&dump, "requests", "dump request").unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].id, 101);
+        assert!(requests[1].failed);
+
+        let dump = fixture(include_str!("../../fixtures/surge-cli/dump-active.json"));
+        let active: Vec<Request> = parse_list(&dump, "requests", "dump active").unwrap();
+        assert_eq!(active.iter().map(|r| r.id).collect::<Vec<_>>(), [201, 202]);
+    }
+
+    #[test]
+    fn parse_dns_and_rule_dumps() {
+        let dump = fixture(include_str!("../../fixtures/surge-cli/dump-dns.json"));
+        let records: Vec<DnsRecord> = parse_list(&dump, "dnsCache", "dump dns").unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].domain, "www.google.com");
+        assert_eq!(records[1].ip.len(), 2);
+
+        let dump = fixture(include_str!("../../fixtures/surge-cli/dump-rule.json"));
+        let rules: Vec<String> = parse_list(&dump, "rules", "dump rule").unwrap();
+        assert_eq!(rules.len(), 7);
+        assert_eq!(rules[6], "FINAL,DIRECT,dns-failed");
+    }
+
+    #[test]
+    fn parse_list_skips_odd_entries() {
+        let dump = serde_json::json!({
+            "dnsCache": [
+                { "domain": "example.com", "data": ["93.184.215.14"] },
+                { "domain": 42 }
+            ]
+        });
+        let records: Vec<DnsRecord> = parse_list(&dump, "dnsCache", "dump dns").unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn parse_list_rejects_other_shapes() {
+        let bare = serde_json::json!([{ "domain": "example.com", "data": [] }]);
+        assert!(parse_list::<DnsRecord>(&bare, "dnsCache", "dump dns").is_err());
+        let other_key = serde_json::json!({ "requests": [] });
+        assert!(parse_list::<DnsRecord>(&other_key, "dnsCache", "dump dns").is_err());
+    }
+
+    #[test]
+    fn parse_policy_dump_fixture() {
+        let dump = fixture(include_str!("../../fixtures/surge-cli/dump-policy.json"));
+        let dump = parse_policy_dump(&dump).unwrap();
+
+        assert_eq!(dump.mode, Some(OutboundMode::Rule));
+        assert_eq!(dump.proxies.len(), 6);
+        let names: Vec<&str> = dump.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["Auto", "Proxy", "Streaming"]);
+
+        let proxy = &dump.groups[1];
+        assert_eq!(proxy.selected.as_deref(), Some("Auto"));
+        assert_eq!(proxy.policies.len(), 5);
+        assert!(proxy.policies[0].is_group);
+        assert_eq!(proxy.policies[1].type_description, "Shadowsocks");
+        // No selection reported for the group
+        assert_eq!(dump.groups[0].selected, None);
+    }
+
+    #[test]
+    fn parse_policy_dump_requires_groups() {
+        let dump = serde_json::json!({ "proxies": ["DIRECT"] });
+        assert!(parse_policy_dump(&dump).is_err());
+    }
 }
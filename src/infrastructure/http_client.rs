@@ -1,12 +1,22 @@
 /// HTTP API client
 ///
 /// Wraps all Surge HTTP API calls
+use super::recording::{RawResponse, Recorder, Replayer};
+use super::tls::{self, TlsOptions};
 use crate::domain::{
+    backend::{Operation, SurgeBackend},
     errors::{Result, SurgeError},
     models::*,
 };
-use reqwest::Client;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use reqwest::{Client, Method};
 use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Time Surge gets to answer a policy test beyond the per-policy timeout
+const POLICY_TEST_GRACE: Duration = Duration::from_secs(5);
 
 /// HTTP API client
 #[derive(Clone)]
@@ -14,17 +24,43 @@ pub struct SurgeHttpClient {
     base_url: String,
     api_key: String,
     client: Client,
+    /// Saves every raw response (`--record`)
+    recorder: Option<Arc<Recorder>>,
+    /// Answers from a recorded session instead of the network (`--replay`)
+    replayer: Option<Arc<Replayer>>,
 }
 
 impl SurgeHttpClient {
     /// Create new HTTP client
-    pub fn new(host: String, port: u16, api_key: String) -> Self {
-        let base_url = format!("http://{}:{}", host, port);
-        Self {
+    ///
+    /// `tls` switches to HTTPS (Surge `http-api-tls = true`)
+    pub fn new(host: String, port: u16, api_key: String, tls: Option<TlsOptions>) -> Result<Self> {
+        let (base_url, client) = match tls {
+            Some(options) => (
+                format!("https://{}:{}", host, port),
+                tls::build_client(&options)?,
+            ),
+            None => (format!("http://{}:{}", host, port), Client::new()),
+        };
+        Ok(Self {
             base_url,
             api_key,
-            client: Client::new(),
-        }
+            client,
+            recorder: None,
+            replayer: None,
+        })
+    }
+
+    /// Record every raw response
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Answer requests from a recorded session (no network access)
+    pub fn with_replayer(mut self, replayer: Replayer) -> Self {
+        self.replayer = Some(Arc::new(replayer));
+        self
     }
 
     /// Test if HTTP API is available
@@ -37,44 +73,68 @@ impl SurgeHttpClient {
         format!("{}{}", self.base_url, path)
     }
 
-    /// Send GET request
-    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let response = self
-            .client
-            .get(&self.build_url(path))
-            .header("X-Key", &self.api_key)
-            .send()
-            .await
-            .map_err(|e| SurgeError::NetworkError {
-                message: format!("HTTP GET failed: {}", e),
-            })?;
+    /// Send a request and return the raw response body
+    ///
+    /// Every request goes through here so sessions can be recorded and replayed
+    async fn request(&self, method: Method, path: &str, body: Option<Value>) -> Result<String> {
+        let response = match self.replayer {
+            Some(ref replayer) => replayer.respond(method.as_str(), path),
+            None => {
+                let (status, bytes) = self.send(method.clone(), path, body).await?;
+                let response = RawResponse {
+                    status,
+                    body: String::from_utf8_lossy(&bytes).into_owned(),
+                };
+
+                if let Some(ref recorder) = self.recorder {
+                    recorder.record(method.as_str(), path, &response);
+                }
+                response
+            }
+        };
 
-        if !response.status().is_success() {
-            return Err(SurgeError::HttpApiUnavailable {
-                reason: format!("HTTP {} returned status {}", path, response.status()),
-            });
+        check_status(path, response.status)?;
+        Ok(response.body)
+    }
+
+    /// Send a GET request for a binary body
+    ///
+    /// Binary bodies are recorded base64-encoded
+    async fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        if let Some(ref replayer) = self.replayer {
+            let response = replayer.respond(Method::GET.as_str(), path);
+            check_status(path, response.status)?;
+            return BASE64
+                .decode(response.body.trim())
+                .map_err(|e| SurgeError::ParseError {
+                    source: format!("Recorded response {}", path),
+                    error: e.to_string(),
+                });
         }
 
-        // Parse JSON directly, only read text on failure
-        response.json().await.map_err(|e| {
-            tracing::error!("Failed to parse {} response: {}", path, e);
-            SurgeError::ParseError {
-                source: format!("HTTP Response {}", path),
-                error: e.to_string(),
-            }
-        })
+        let (status, bytes) = self.send(Method::GET, path, None).await?;
+        if let Some(ref recorder) = self.recorder {
+            let response = RawResponse {
+                status,
+                body: BASE64.encode(&bytes),
+            };
+            recorder.record(Method::GET.as_str(), path, &response);
+        }
+
+        check_status(path, status)?;
+        Ok(bytes)
     }
 
-    /// Send POST request
-    #[allow(dead_code)]
-    async fn post<T: serde::de::DeserializeOwned>(
+    /// Send a request over the network and return the status and body
+    async fn send(
         &self,
+        method: Method,
         path: &str,
         body: Option<Value>,
-    ) -> Result<T> {
+    ) -> Result<(u16, Vec<u8>)> {
         let mut request = self
             .client
-            .post(&self.build_url(path))
+            .request(method.clone(), self.build_url(path))
             .header("X-Key", &self.api_key);
 
         if let Some(body) = body {
@@ -82,42 +142,49 @@ impl SurgeHttpClient {
         }
 
         let response = request.send().await.map_err(|e| SurgeError::NetworkError {
-            message: format!("HTTP POST failed: {}", e),
+            message: format!("HTTP {} failed: {}", method, e),
         })?;
 
-        if !response.status().is_success() {
-            return Err(SurgeError::HttpApiUnavailable {
-                reason: format!("HTTP {} returned status {}", path, response.status()),
-            });
-        }
+        let status = response.status().as_u16();
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| SurgeError::NetworkError {
+                message: format!("Failed to read response body: {}", e),
+            })?;
+        Ok((status, bytes.to_vec()))
+    }
 
-        response.json().await.map_err(|e| SurgeError::ParseError {
-            source: "HTTP Response".to_string(),
-            error: e.to_string(),
+    /// Parse a JSON response body
+    fn parse<T: serde::de::DeserializeOwned>(path: &str, body: &str) -> Result<T> {
+        serde_json::from_str(body).map_err(|e| {
+            tracing::error!("Failed to parse {} response: {}. Raw: {}", path, e, body);
+            SurgeError::ParseError {
+                source: format!("HTTP Response {}", path),
+                error: e.to_string(),
+            }
         })
     }
 
-    /// Send POST request (no response body)
-    async fn post_empty(&self, path: &str, body: Option<Value>) -> Result<()> {
-        let mut request = self
-            .client
-            .post(&self.build_url(path))
-            .header("X-Key", &self.api_key);
-
-        if let Some(body) = body {
-            request = request.json(&body);
-        }
-
-        let response = request.send().await.map_err(|e| SurgeError::NetworkError {
-            message: format!("HTTP POST failed: {}", e),
-        })?;
+    /// Send GET request
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let body = self.request(Method::GET, path, None).await?;
+        Self::parse(path, &body)
+    }
 
-        if !response.status().is_success() {
-            return Err(SurgeError::HttpApiUnavailable {
-                reason: format!("HTTP {} returned status {}", path, response.status()),
-            });
-        }
+    /// Send POST request
+    async fn post<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<T> {
+        let body = self.request(Method::POST, path, body).await?;
+        Self::parse(path, &body)
+    }
 
+    /// Send POST request (response body ignored)
+    async fn post_empty(&self, path: &str, body: Option<Value>) -> Result<()> {
+        self.request(Method::POST, path, body).await?;
         Ok(())
     }
 
@@ -135,6 +202,18 @@ impl SurgeHttpClient {
         self.post_empty("/v1/outbound", Some(body)).await
     }
 
+    /// Get global policy (used in proxy mode)
+    pub async fn get_global_policy(&self) -> Result<String> {
+        let response: GlobalPolicyResponse = self.get("/v1/outbound/global").await?;
+        Ok(response.policy)
+    }
+
+    /// Set global policy (used in proxy mode)
+    pub async fn set_global_policy(&self, policy: &str) -> Result<()> {
+        let body = serde_json::json!({ "policy": policy });
+        self.post_empty("/v1/outbound/global", Some(body)).await
+    }
+
     // ===== Policy-related =====
 
     /// Get all policies
@@ -155,48 +234,86 @@ impl SurgeHttpClient {
     }
 
     /// Test policy latency
-    pub async fn test_policy(&self, name: &str) -> Result<()> {
+    pub async fn test_policy(&self, name: &str, test: &LatencyTest) -> Result<()> {
         let body = serde_json::json!({
             "policy_names": [name],
-            "url": "http://www.gstatic.com/generate_204"
+            "url": test.url
         });
         self.post_empty("/v1/policies/test", Some(body)).await
     }
 
+    /// Test policies and read their latency
+    ///
+    /// All policies go in one `/v1/policies/test` request (Surge tests them
+    /// concurrently). Policies the response does not report are filled from the
+    /// last group test results, the rest count as unavailable. Fails only when
+    /// the request fails and the group test results have none of the policies
+    pub async fn test_policy_latency(
+        &self,
+        names: &[String],
+        test: &LatencyTest,
+    ) -> Result<Vec<PolicyDetail>> {
+        let body = serde_json::json!({
+            "policy_names": names,
+            "url": test.url
+        });
+        let request = self.post::<PolicyTestResponse>("/v1/policies/test", Some(body));
+        let response = match tokio::time::timeout(test.timeout + POLICY_TEST_GRACE, request).await {
+            Ok(result) => result,
+            Err(_) => Err(SurgeError::NetworkError {
+                message: "policy test timed out".to_string(),
+            }),
+        };
+        let tested = match response {
+            Ok(ref response) => {
+                tracing::debug!("Policy test response: {:?}", response);
+                latency_results(response, names, test.timeout)
+            }
+            Err(ref e) => {
+                tracing::warn!("Policy test failed: {}", e);
+                Vec::new()
+            }
+        };
+
+        let fallback = if tested.len() < names.len() {
+            match self.get_policy_group_test_results().await {
+                Ok(test_results) => group_latency_results(&test_results, names, test.timeout),
+                Err(e) => {
+                    tracing::debug!("No group test results to fall back on: {}", e);
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        if let Err(e) = response {
+            if fallback.is_empty() {
+                return Err(e);
+            }
+        }
+        Ok(merge_latency_results(names, tested, fallback))
+    }
+
     // ===== Policy group-related =====
 
     /// Get all policy groups
+    ///
+    /// Single request: `selected` is left empty, fill it with
+    /// `get_policy_group_selected` (the application layer caches those)
     pub async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
         let response: PolicyGroupsResponse = self.get("/v1/policy_groups").await?;
 
-        // Collect and sort group names to maintain stable order
-        let mut group_names: Vec<String> = response.keys().cloned().collect();
-        group_names.sort();
-
-        // Fetch the currently selected policy for each group
-        let mut selected_map = std::collections::HashMap::new();
-        for group_name in &group_names {
-            if let Ok(Some(policy)) = self.get_policy_group_selected(group_name).await {
-                selected_map.insert(group_name.clone(), policy);
-            }
-        }
-
-        // Build Vec<PolicyGroup> in sorted order
-        let groups = group_names
+        // Sort by group name to maintain stable order
+        let mut groups: Vec<PolicyGroup> = response
             .into_iter()
-            .filter_map(|name| {
-                response.get(&name).map(|policies| {
-                    let selected = selected_map.get(&name).cloned();
-
-                    PolicyGroup {
-                        name,
-                        policies: policies.clone(),
-                        selected,
-                        available_policies: None, // Initially None; populated after testing
-                    }
-                })
+            .map(|(name, policies)| PolicyGroup {
+                name,
+                policies,
+                selected: None,
+                available_policies: None, // Initially None; populated after testing
             })
             .collect();
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
 
         Ok(groups)
     }
@@ -212,10 +329,9 @@ impl SurgeHttpClient {
 
         match self.get::<PolicyGroupSelectResponse>(&url).await {
             Ok(response) => Ok(Some(response.policy)),
-            Err(_) => {
-                // Return None if fetch fails (e.g. non-select type group)
-                Ok(None)
-            }
+            // Not found: the group has no manual selection (e.g. url-test)
+            Err(SurgeError::HttpStatus { status: 404, .. }) => Ok(None),
+            Err(e) => Err(e),
         }
     }
 
@@ -236,7 +352,7 @@ impl SurgeHttpClient {
         tracing::debug!("Policy group {} test response: {:?}", group_name, response);
 
         // Parse {"available": ["proxy1", "proxy2"]} format
-        let available = response
+        let available: Vec<String> = response
             .get("available")
             .and_then(|v| v.as_array())
             .map(|arr| {
@@ -244,7 +360,7 @@ impl SurgeHttpClient {
                     .filter_map(|v| v.as_str().map(|s| s.to_string()))
                     .collect()
             })
-            .unwrap_or_else(Vec::new);
+            .unwrap_or_default();
 
         tracing::info!(
             "Policy group {} test completed, {} available policies",
@@ -255,10 +371,22 @@ impl SurgeHttpClient {
     }
 
     /// Get policy group test results
-    pub async fn get_policy_group_test_results(&self) -> Result<serde_json::Value> {
+    pub async fn get_policy_group_test_results(&self) -> Result<PolicyGroupTestResults> {
         self.get("/v1/policy_groups/test_results").await
     }
 
+    // ===== Rules =====
+
+    /// Get the ordered rule list (lines that do not parse are skipped)
+    pub async fn get_rules(&self) -> Result<Vec<Rule>> {
+        let response: RulesResponse = self.get("/v1/rules").await?;
+        Ok(response
+            .rules
+            .iter()
+            .filter_map(|r| Rule::parse(r))
+            .collect())
+    }
+
     // ===== Request-related =====
 
     /// Get recent requests
@@ -293,6 +421,18 @@ impl SurgeHttpClient {
         self.get(&path).await
     }
 
+    /// Get available profile names
+    pub async fn get_profiles(&self) -> Result<Vec<String>> {
+        let response: ProfilesResponse = self.get("/v1/profiles").await?;
+        Ok(response.profiles)
+    }
+
+    /// Switch profile (macOS only)
+    pub async fn switch_profile(&self, name: &str) -> Result<()> {
+        let body = serde_json::json!({ "name": name });
+        self.post_empty("/v1/profiles/switch", Some(body)).await
+    }
+
     // ===== DNS-related =====
 
     /// Flush DNS cache
@@ -300,83 +440,455 @@ impl SurgeHttpClient {
         self.post_empty("/v1/dns/flush", None).await
     }
 
+    /// Test upstream DNS server delay
+    pub async fn test_dns_delay(&self) -> Result<Vec<DnsDelay>> {
+        let response: DnsDelayResponse = self.post("/v1/test/dns_delay", None).await?;
+        Ok(response.into_results())
+    }
+
     /// Get DNS cache
     pub async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
-        // Fetch raw response text for debugging
-        let response = self
-            .client
-            .get(&self.build_url("/v1/dns"))
-            .header("X-Key", &self.api_key)
-            .send()
-            .await
-            .map_err(|e| SurgeError::NetworkError {
-                message: format!("HTTP GET failed: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            return Err(SurgeError::HttpApiUnavailable {
-                reason: format!("HTTP /v1/dns returned status {}", response.status()),
-            });
-        }
-
-        let text = response
-            .text()
-            .await
-            .map_err(|e| SurgeError::NetworkError {
-                message: format!("Failed to read response body: {}", e),
-            })?;
-
+        let text = self.request(Method::GET, "/v1/dns", None).await?;
         tracing::debug!("DNS API raw response: {}", text);
 
-        // Attempt to parse as DnsResponse
-        serde_json::from_str::<DnsResponse>(&text)
-            .map(|r| r.records)
-            .map_err(|e| {
-                tracing::error!(
-                    "Failed to parse DNS response as DnsResponse: {}. Raw: {}",
-                    e,
-                    text
-                );
-                SurgeError::ParseError {
-                    source: "DNS Response".to_string(),
-                    error: e.to_string(),
-                }
-            })
+        let response: DnsResponse = Self::parse("/v1/dns", &text)?;
+        Ok(response.records)
     }
 
     // ===== Feature toggles =====
 
     /// Get feature status
-    async fn get_feature_status(&self, feature: &str) -> Result<bool> {
-        let path = format!("/v1/features/{}", feature);
+    pub async fn get_feature_status(&self, feature: Feature) -> Result<bool> {
+        let path = format!("/v1/features/{}", feature.as_str());
         let response: FeatureStatus = self.get(&path).await?;
         Ok(response.enabled)
     }
 
     /// Set feature status
-    async fn set_feature_status(&self, feature: &str, enabled: bool) -> Result<()> {
-        let path = format!("/v1/features/{}", feature);
+    pub async fn set_feature_status(&self, feature: Feature, enabled: bool) -> Result<()> {
+        let path = format!("/v1/features/{}", feature.as_str());
         let body = serde_json::json!({ "enabled": enabled });
         self.post_empty(&path, Some(body)).await
     }
 
-    /// Get MITM status
-    pub async fn get_mitm_status(&self) -> Result<bool> {
-        self.get_feature_status("mitm").await
+    /// Get the MITM CA certificate (DER)
+    pub async fn get_mitm_ca(&self) -> Result<Vec<u8>> {
+        self.get_bytes("/v1/mitm/ca").await
+    }
+
+    // ===== Modules =====
+
+    /// Get available modules and their enabled state
+    pub async fn get_modules(&self) -> Result<Vec<Module>> {
+        let response: ModulesResponse = self.get("/v1/modules").await?;
+        Ok(response.into_modules())
+    }
+
+    /// Enable or disable a module
+    pub async fn set_module_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        let body = serde_json::json!({ "module_name": name, "enabled": enabled });
+        self.post_empty("/v1/modules", Some(body)).await
     }
 
-    /// Set MITM status
-    pub async fn set_mitm_status(&self, enabled: bool) -> Result<()> {
-        self.set_feature_status("mitm", enabled).await
+    // ===== Events =====
+
+    /// Get event center entries
+    pub async fn get_events(&self) -> Result<Vec<Event>> {
+        let response: EventsResponse = self.get("/v1/events").await?;
+        Ok(response.events)
     }
 
-    /// Get traffic capture status
-    pub async fn get_capture_status(&self) -> Result<bool> {
-        self.get_feature_status("capture").await
+    // ===== Traffic =====
+
+    /// Get traffic statistics (not available in older Surge versions)
+    pub async fn get_traffic(&self) -> Result<TrafficStats> {
+        let response: TrafficResponse = self.get("/v1/traffic").await?;
+        Ok(response.into_stats())
     }
 
-    /// Set traffic capture status
-    pub async fn set_capture_status(&self, enabled: bool) -> Result<()> {
-        self.set_feature_status("capture", enabled).await
+    // ===== Scripting =====
+
+    /// Evaluate a script with a mocked context
+    pub async fn evaluate_script(
+        &self,
+        script: &str,
+        mock_type: ScriptMockType,
+        timeout_secs: u64,
+    ) -> Result<ScriptEvaluation> {
+        let body = serde_json::json!({
+            "script_text": script,
+            "mock_type": mock_type.as_str(),
+            "timeout": timeout_secs,
+        });
+        self.post("/v1/scripting/evaluate", Some(body)).await
+    }
+
+    // ===== Engine control =====
+
+    /// Stop the Surge engine
+    pub async fn stop_surge(&self) -> Result<()> {
+        self.post_empty("/v1/stop", None).await
+    }
+}
+
+#[async_trait]
+impl SurgeBackend for SurgeHttpClient {
+    fn name(&self) -> &'static str {
+        "HTTP API"
+    }
+
+    fn capabilities(&self) -> &'static [Operation] {
+        // Latency of all policies at once is only reported by surge-cli
+        &[
+            Operation::OutboundMode,
+            Operation::PolicyGroups,
+            Operation::SelectPolicy,
+            Operation::GlobalPolicy,
+            Operation::TestPolicy,
+            Operation::TestPolicyGroup,
+            Operation::TestPolicyLatency,
+            Operation::Rules,
+            Operation::RecentRequests,
+            Operation::ActiveConnections,
+            Operation::KillConnection,
+            Operation::ReloadConfig,
+            Operation::Profiles,
+            Operation::SwitchProfile,
+            Operation::DnsCache,
+            Operation::FlushDns,
+            Operation::DnsDelay,
+            Operation::Features,
+            Operation::MitmCa,
+            Operation::Modules,
+            Operation::Events,
+            Operation::Traffic,
+            Operation::Scripting,
+            Operation::StopSurge,
+        ]
+    }
+
+    async fn is_available(&self) -> bool {
+        SurgeHttpClient::is_available(self).await
+    }
+
+    async fn get_outbound_mode(&self) -> Result<OutboundMode> {
+        SurgeHttpClient::get_outbound_mode(self).await
+    }
+
+    async fn set_outbound_mode(&self, mode: OutboundMode) -> Result<()> {
+        SurgeHttpClient::set_outbound_mode(self, mode).await
+    }
+
+    async fn get_global_policy(&self) -> Result<String> {
+        SurgeHttpClient::get_global_policy(self).await
+    }
+
+    async fn set_global_policy(&self, policy: &str) -> Result<()> {
+        SurgeHttpClient::set_global_policy(self, policy).await
+    }
+
+    async fn get_policies(&self) -> Result<Vec<String>> {
+        SurgeHttpClient::get_policies(self).await
+    }
+
+    async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        SurgeHttpClient::get_policy_groups(self).await
+    }
+
+    async fn get_policy_group_selected(&self, group_name: &str) -> Result<Option<String>> {
+        SurgeHttpClient::get_policy_group_selected(self, group_name).await
+    }
+
+    async fn select_policy_group(&self, group_name: &str, policy: &str) -> Result<()> {
+        SurgeHttpClient::select_policy_group(self, group_name, policy).await
+    }
+
+    async fn test_policy(&self, name: &str, test: &LatencyTest) -> Result<()> {
+        SurgeHttpClient::test_policy(self, name, test).await
+    }
+
+    async fn test_policy_group(&self, group_name: &str) -> Result<Vec<String>> {
+        SurgeHttpClient::test_policy_group(self, group_name).await
+    }
+
+    async fn test_policy_latency(
+        &self,
+        names: &[String],
+        test: &LatencyTest,
+    ) -> Result<Vec<PolicyDetail>> {
+        SurgeHttpClient::test_policy_latency(self, names, test).await
+    }
+
+    async fn get_rules(&self) -> Result<Vec<Rule>> {
+        SurgeHttpClient::get_rules(self).await
+    }
+
+    async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        SurgeHttpClient::get_recent_requests(self).await
+    }
+
+    async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        SurgeHttpClient::get_active_connections(self).await
+    }
+
+    async fn kill_connection(&self, id: u64) -> Result<()> {
+        SurgeHttpClient::kill_connection(self, id).await
+    }
+
+    async fn reload_config(&self) -> Result<()> {
+        SurgeHttpClient::reload_config(self).await
+    }
+
+    async fn get_profiles(&self) -> Result<Vec<String>> {
+        SurgeHttpClient::get_profiles(self).await
+    }
+
+    async fn get_current_profile(&self, show_sensitive: bool) -> Result<ProfileInfo> {
+        SurgeHttpClient::get_current_profile(self, show_sensitive).await
+    }
+
+    async fn switch_profile(&self, name: &str) -> Result<()> {
+        SurgeHttpClient::switch_profile(self, name).await
+    }
+
+    async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
+        SurgeHttpClient::get_dns_cache(self).await
+    }
+
+    async fn flush_dns(&self) -> Result<()> {
+        SurgeHttpClient::flush_dns(self).await
+    }
+
+    async fn test_dns_delay(&self) -> Result<Vec<DnsDelay>> {
+        SurgeHttpClient::test_dns_delay(self).await
+    }
+
+    async fn get_feature(&self, feature: Feature) -> Result<bool> {
+        SurgeHttpClient::get_feature_status(self, feature).await
+    }
+
+    async fn set_feature(&self, feature: Feature, enabled: bool) -> Result<()> {
+        SurgeHttpClient::set_feature_status(self, feature, enabled).await
+    }
+
+    async fn get_mitm_ca(&self) -> Result<Vec<u8>> {
+        SurgeHttpClient::get_mitm_ca(self).await
+    }
+
+    async fn get_modules(&self) -> Result<Vec<Module>> {
+        SurgeHttpClient::get_modules(self).await
+    }
+
+    async fn set_module_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        SurgeHttpClient::set_module_enabled(self, name, enabled).await
+    }
+
+    async fn get_events(&self) -> Result<Vec<Event>> {
+        SurgeHttpClient::get_events(self).await
+    }
+
+    async fn get_traffic(&self) -> Result<TrafficStats> {
+        SurgeHttpClient::get_traffic(self).await
+    }
+
+    async fn evaluate_script(
+        &self,
+        script: &str,
+        mock_type: ScriptMockType,
+        timeout_secs: u64,
+    ) -> Result<ScriptEvaluation> {
+        SurgeHttpClient::evaluate_script(self, script, mock_type, timeout_secs).await
+    }
+
+    async fn stop_surge(&self) -> Result<()> {
+        SurgeHttpClient::stop_surge(self).await
+    }
+}
+
+/// Error for a non-2xx response
+fn check_status(path: &str, status: u16) -> Result<()> {
+    if (200..300).contains(&status) {
+        Ok(())
+    } else {
+        Err(SurgeError::HttpStatus {
+            path: path.to_string(),
+            status,
+        })
+    }
+}
+
+/// Latency results for the tested `names` present in a `/v1/policies/test` response
+///
+/// A policy slower than `timeout` counts as unavailable
+fn latency_results(
+    response: &PolicyTestResponse,
+    names: &[String],
+    timeout: Duration,
+) -> Vec<PolicyDetail> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let result = response.get(name)?;
+            let latency = result
+                .rtt
+                .filter(|ms| result.available && u128::from(*ms) <= timeout.as_millis());
+            Some(PolicyDetail {
+                name: name.clone(),
+                policy_type: PolicyType::Unknown,
+                alive: latency.is_some(),
+                latency,
+                last_test_at: None,
+            })
+        })
+        .collect()
+}
+
+/// Latency results for `names` from the group test results (a policy tested
+/// in several groups is reported once)
+fn group_latency_results(
+    test_results: &PolicyGroupTestResults,
+    names: &[String],
+    timeout: Duration,
+) -> Vec<PolicyDetail> {
+    let mut results: Vec<PolicyDetail> = Vec::new();
+    for group in test_results.values() {
+        for result in latency_results(group, names, timeout) {
+            if !results.iter().any(|r| r.name == result.name) {
+                results.push(result);
+            }
+        }
+    }
+    results
+}
+
+/// One result per name: tested, else from the fallback, else unavailable
+fn merge_latency_results(
+    names: &[String],
+    tested: Vec<PolicyDetail>,
+    fallback: Vec<PolicyDetail>,
+) -> Vec<PolicyDetail> {
+    names
+        .iter()
+        .map(|name| {
+            let found =
+                |results: &[PolicyDetail]| results.iter().find(|r| &r.name == name).cloned();
+            found(&tested)
+                .or_else(|| found(&fallback))
+                .unwrap_or_else(|| unavailable(name))
+        })
+        .collect()
+}
+
+/// Result of a policy that failed or timed out
+fn unavailable(name: &str) -> PolicyDetail {
+    PolicyDetail {
+        name: name.to_string(),
+        policy_type: PolicyType::Unknown,
+        alive: false,
+        latency: None,
+        last_test_at: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    /// (name, alive, latency) of each result, by name
+    fn summary(mut results: Vec<PolicyDetail>) -> Vec<(String, bool, Option<u32>)> {
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        results
+            .into_iter()
+            .map(|r| (r.name, r.alive, r.latency))
+            .collect()
+    }
+
+    #[test]
+    fn latency_results_from_policy_test_response() {
+        let response: PolicyTestResponse =
+            serde_json::from_str(include_str!("../../fixtures/http-api/policies-test.json"))
+                .unwrap();
+        let results = latency_results(&response, &names(&["HK-01", "HK-02", "US-01"]), TIMEOUT);
+        assert_eq!(
+            summary(results),
+            [
+                ("HK-01".to_string(), true, Some(48)),
+                ("HK-02".to_string(), false, None),
+                // Slower than the timeout
+                ("US-01".to_string(), false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn latency_results_skip_policies_missing_from_response() {
+        let response: PolicyTestResponse =
+            serde_json::from_str(include_str!("../../fixtures/http-api/policies-test.json"))
+                .unwrap();
+        let results = latency_results(&response, &names(&["HK-01", "JP-01"]), TIMEOUT);
+        assert_eq!(summary(results), [("HK-01".to_string(), true, Some(48))]);
+    }
+
+    #[test]
+    fn policies_missing_from_response_are_unavailable() {
+        let response: PolicyTestResponse =
+            serde_json::from_str(include_str!("../../fixtures/http-api/policies-test.json"))
+                .unwrap();
+        let tested = names(&["HK-01", "JP-01"]);
+        let results = latency_results(&response, &tested, TIMEOUT);
+        assert_eq!(
+            summary(merge_latency_results(&tested, results, Vec::new())),
+            [
+                ("HK-01".to_string(), true, Some(48)),
+                ("JP-01".to_string(), false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn group_test_results_fill_policies_missing_from_response() {
+        let response: PolicyTestResponse =
+            serde_json::from_str(include_str!("../../fixtures/http-api/policies-test.json"))
+                .unwrap();
+        let test_results: PolicyGroupTestResults = serde_json::from_str(include_str!(
+            "../../fixtures/http-api/policy-groups-test-results.json"
+        ))
+        .unwrap();
+        // SG-01 is in neither
+        let tested = names(&["HK-01", "JP-01", "SG-01"]);
+        let results = latency_results(&response, &tested, TIMEOUT);
+        let fallback = group_latency_results(&test_results, &tested, TIMEOUT);
+        assert_eq!(
+            summary(merge_latency_results(&tested, results, fallback)),
+            [
+                ("HK-01".to_string(), true, Some(48)),
+                ("JP-01".to_string(), true, Some(97)),
+                ("SG-01".to_string(), false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn latency_results_from_group_test_results() {
+        let test_results: PolicyGroupTestResults = serde_json::from_str(include_str!(
+            "../../fixtures/http-api/policy-groups-test-results.json"
+        ))
+        .unwrap();
+        let results =
+            group_latency_results(&test_results, &names(&["HK-01", "HK-02", "US-01"]), TIMEOUT);
+        assert_eq!(
+            summary(results),
+            [
+                ("HK-01".to_string(), true, Some(51)),
+                ("HK-02".to_string(), false, None),
+                ("US-01".to_string(), true, Some(180)),
+            ]
+        );
     }
 }
@@ -0,0 +1,517 @@
+/// In-memory backend
+///
+/// Test double for `SurgeBackend`: keeps a Surge state in memory and applies
+/// writes to it, so `SurgeClient` and the UI can run without a real Surge.
+use crate::domain::{
+    backend::{unsupported, Operation, SurgeBackend},
+    errors::{Result, SurgeError},
+    models::*,
+};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// Every operation (default capabilities of `MemoryBackend`)
+const ALL_OPERATIONS: &[Operation] = &[
+    Operation::OutboundMode,
+    Operation::PolicyGroups,
+    Operation::SelectPolicy,
+    Operation::GlobalPolicy,
+    Operation::TestPolicy,
+    Operation::TestPolicyGroup,
+    Operation::TestAllPolicies,
+    Operation::TestPolicyLatency,
+    Operation::Rules,
+    Operation::RecentRequests,
+    Operation::ActiveConnections,
+    Operation::KillConnection,
+    Operation::ReloadConfig,
+    Operation::Profiles,
+    Operation::SwitchProfile,
+    Operation::ProfileDump,
+    Operation::DnsCache,
+    Operation::FlushDns,
+    Operation::DnsDelay,
+    Operation::Features,
+    Operation::MitmCa,
+    Operation::Modules,
+    Operation::Events,
+    Operation::Traffic,
+    Operation::Scripting,
+    Operation::StopSurge,
+    Operation::LogLevel,
+    Operation::Diagnostics,
+];
+
+/// Surge state held by `MemoryBackend`
+#[derive(Debug, Clone)]
+pub struct MemoryState {
+    /// Reported by `is_available`
+    pub available: bool,
+    pub outbound_mode: OutboundMode,
+    /// Global policy (proxy mode)
+    pub global_policy: String,
+    /// Proxy names (`get_policies` lists these, then the group names)
+    pub proxies: Vec<String>,
+    /// Policy groups; `selected` is the current selection
+    pub policy_groups: Vec<PolicyGroup>,
+    /// Returned by `test_all_policies` and `test_policy_latency`
+    pub policy_latencies: Vec<PolicyDetail>,
+    pub rules: Vec<Rule>,
+    pub recent_requests: Vec<Request>,
+    pub active_connections: Vec<Request>,
+    pub dns_cache: Vec<DnsRecord>,
+    /// Returned by `test_dns_delay`
+    pub dns_delays: Vec<DnsDelay>,
+    /// Feature switches; features missing here are unknown to this "Surge"
+    pub features: BTreeMap<Feature, bool>,
+    /// MITM CA certificate (DER)
+    pub mitm_ca: Vec<u8>,
+    pub modules: Vec<Module>,
+    pub events: Vec<Event>,
+    pub traffic: TrafficStats,
+    /// Returned by `evaluate_script`
+    pub script_evaluation: ScriptEvaluation,
+    /// Profiles (name and content)
+    pub profiles: Vec<ProfileInfo>,
+    /// Name of the current profile
+    pub current_profile: String,
+    pub log_level: LogLevel,
+    /// Returned by `run_diagnostics`
+    pub diagnostics: Vec<DiagnosticCheck>,
+    /// Write operations performed, in order (e.g. "kill_connection 42")
+    pub calls: Vec<String>,
+}
+
+impl Default for MemoryState {
+    fn default() -> Self {
+        Self {
+            available: true,
+            outbound_mode: OutboundMode::Rule,
+            global_policy: "DIRECT".to_string(),
+            proxies: Vec::new(),
+            policy_groups: Vec::new(),
+            policy_latencies: Vec::new(),
+            rules: Vec::new(),
+            recent_requests: Vec::new(),
+            active_connections: Vec::new(),
+            dns_cache: Vec::new(),
+            dns_delays: Vec::new(),
+            features: Feature::ALL.iter().map(|f| (*f, false)).collect(),
+            mitm_ca: Vec::new(),
+            modules: Vec::new(),
+            events: Vec::new(),
+            traffic: TrafficStats::default(),
+            script_evaluation: ScriptEvaluation::default(),
+            profiles: Vec::new(),
+            current_profile: String::new(),
+            log_level: LogLevel::Notify,
+            diagnostics: Vec::new(),
+            calls: Vec::new(),
+        }
+    }
+}
+
+/// In-memory backend
+pub struct MemoryBackend {
+    state: Mutex<MemoryState>,
+    capabilities: &'static [Operation],
+    uses_test_url: bool,
+}
+
+impl MemoryBackend {
+    /// Create a backend supporting every operation
+    pub fn new(state: MemoryState) -> Self {
+        Self {
+            state: Mutex::new(state),
+            capabilities: ALL_OPERATIONS,
+            uses_test_url: true,
+        }
+    }
+
+    /// Restrict the supported operations (e.g. to mimic surge-cli)
+    pub fn with_capabilities(mut self, capabilities: &'static [Operation]) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Ignore the latency test URL (like surge-cli)
+    pub fn without_test_url(mut self) -> Self {
+        self.uses_test_url = false;
+        self
+    }
+
+    /// Copy of the current state
+    pub fn state(&self) -> MemoryState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Modify the state (e.g. simulate changes made outside the TUI)
+    pub fn update(&self, f: impl FnOnce(&mut MemoryState)) {
+        f(&mut self.state.lock().unwrap());
+    }
+
+    /// Lock the state if the operation is supported
+    fn check(&self, operation: Operation) -> Result<MutexGuard<'_, MemoryState>> {
+        if self.supports(operation) {
+            Ok(self.state.lock().unwrap())
+        } else {
+            Err(unsupported(self.name(), operation))
+        }
+    }
+
+    /// Lock the state for a write operation and record the call
+    fn record(&self, operation: Operation, call: String) -> Result<MutexGuard<'_, MemoryState>> {
+        let mut state = self.check(operation)?;
+        state.calls.push(call);
+        Ok(state)
+    }
+}
+
+#[async_trait]
+impl SurgeBackend for MemoryBackend {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn capabilities(&self) -> &'static [Operation] {
+        self.capabilities
+    }
+
+    fn uses_test_url(&self) -> bool {
+        self.uses_test_url
+    }
+
+    async fn is_available(&self) -> bool {
+        self.state.lock().unwrap().available
+    }
+
+    async fn get_outbound_mode(&self) -> Result<OutboundMode> {
+        Ok(self.check(Operation::OutboundMode)?.outbound_mode.clone())
+    }
+
+    async fn set_outbound_mode(&self, mode: OutboundMode) -> Result<()> {
+        let mut state = self.record(
+            Operation::OutboundMode,
+            format!("set_outbound_mode {:?}", mode),
+        )?;
+        state.outbound_mode = mode;
+        Ok(())
+    }
+
+    async fn get_global_policy(&self) -> Result<String> {
+        Ok(self.check(Operation::GlobalPolicy)?.global_policy.clone())
+    }
+
+    async fn set_global_policy(&self, policy: &str) -> Result<()> {
+        let mut state = self.record(
+            Operation::GlobalPolicy,
+            format!("set_global_policy {}", policy),
+        )?;
+        let known = state.proxies.iter().any(|p| p == policy)
+            || state.policy_groups.iter().any(|g| g.name == policy);
+        if !known {
+            return Err(SurgeError::PolicyNotFound {
+                name: policy.to_string(),
+            });
+        }
+        state.global_policy = policy.to_string();
+        Ok(())
+    }
+
+    async fn get_policies(&self) -> Result<Vec<String>> {
+        let state = self.check(Operation::PolicyGroups)?;
+        Ok(state
+            .proxies
+            .iter()
+            .cloned()
+            .chain(state.policy_groups.iter().map(|g| g.name.clone()))
+            .collect())
+    }
+
+    async fn get_policy_groups(&self) -> Result<Vec<PolicyGroup>> {
+        let state = self.check(Operation::PolicyGroups)?;
+        // Same shape as the HTTP API: selections are fetched separately
+        Ok(state
+            .policy_groups
+            .iter()
+            .map(|g| PolicyGroup {
+                selected: None,
+                ..g.clone()
+            })
+            .collect())
+    }
+
+    async fn get_policy_group_selected(&self, group_name: &str) -> Result<Option<String>> {
+        let state = self.check(Operation::PolicyGroups)?;
+        state
+            .policy_groups
+            .iter()
+            .find(|g| g.name == group_name)
+            .map(|g| g.selected.clone())
+            .ok_or_else(|| SurgeError::PolicyGroupNotFound {
+                name: group_name.to_string(),
+            })
+    }
+
+    async fn select_policy_group(&self, group_name: &str, policy: &str) -> Result<()> {
+        let mut state = self.record(
+            Operation::SelectPolicy,
+            format!("select_policy_group {} {}", group_name, policy),
+        )?;
+        let group = state
+            .policy_groups
+            .iter_mut()
+            .find(|g| g.name == group_name)
+            .ok_or_else(|| SurgeError::PolicyGroupNotFound {
+                name: group_name.to_string(),
+            })?;
+        if !group.policies.iter().any(|p| p.name == policy) {
+            return Err(SurgeError::PolicyNotFound {
+                name: policy.to_string(),
+            });
+        }
+        group.selected = Some(policy.to_string());
+        Ok(())
+    }
+
+    async fn test_policy(&self, name: &str, test: &LatencyTest) -> Result<()> {
+        self.record(
+            Operation::TestPolicy,
+            format!("test_policy {} {}", test.url, name),
+        )
+        .map(drop)
+    }
+
+    async fn test_policy_group(&self, group_name: &str) -> Result<Vec<String>> {
+        let state = self.record(
+            Operation::TestPolicyGroup,
+            format!("test_policy_group {}", group_name),
+        )?;
+        let group = state
+            .policy_groups
+            .iter()
+            .find(|g| g.name == group_name)
+            .ok_or_else(|| SurgeError::PolicyGroupNotFound {
+                name: group_name.to_string(),
+            })?;
+        // Available: members with a successful latency result
+        Ok(group
+            .policies
+            .iter()
+            .filter(|p| {
+                state
+                    .policy_latencies
+                    .iter()
+                    .any(|l| l.name == p.name && l.alive)
+            })
+            .map(|p| p.name.clone())
+            .collect())
+    }
+
+    async fn test_all_policies(&self) -> Result<Vec<PolicyDetail>> {
+        let state = self.record(Operation::TestAllPolicies, "test_all_policies".to_string())?;
+        Ok(state.policy_latencies.clone())
+    }
+
+    async fn test_policy_latency(
+        &self,
+        names: &[String],
+        test: &LatencyTest,
+    ) -> Result<Vec<PolicyDetail>> {
+        let state = self.record(
+            Operation::TestPolicyLatency,
+            format!("test_policy_latency {} {}", test.url, names.join(",")),
+        )?;
+        Ok(state
+            .policy_latencies
+            .iter()
+            .filter(|l| names.contains(&l.name))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_rules(&self) -> Result<Vec<Rule>> {
+        Ok(self.check(Operation::Rules)?.rules.clone())
+    }
+
+    async fn get_recent_requests(&self) -> Result<Vec<Request>> {
+        Ok(self
+            .check(Operation::RecentRequests)?
+            .recent_requests
+            .clone())
+    }
+
+    async fn get_active_connections(&self) -> Result<Vec<Request>> {
+        Ok(self
+            .check(Operation::ActiveConnections)?
+            .active_connections
+            .clone())
+    }
+
+    async fn kill_connection(&self, id: u64) -> Result<()> {
+        let mut state =
+            self.record(Operation::KillConnection, format!("kill_connection {}", id))?;
+        let before = state.active_connections.len();
+        state.active_connections.retain(|c| c.id != id);
+        if state.active_connections.len() == before {
+            return Err(SurgeError::ConnectionNotFound { id });
+        }
+        Ok(())
+    }
+
+    async fn reload_config(&self) -> Result<()> {
+        self.record(Operation::ReloadConfig, "reload_config".to_string())
+            .map(drop)
+    }
+
+    async fn get_profiles(&self) -> Result<Vec<String>> {
+        let state = self.check(Operation::Profiles)?;
+        Ok(state.profiles.iter().map(|p| p.name.clone()).collect())
+    }
+
+    async fn get_current_profile(&self, _show_sensitive: bool) -> Result<ProfileInfo> {
+        let state = self.check(Operation::Profiles)?;
+        state
+            .profiles
+            .iter()
+            .find(|p| p.matches(&state.current_profile))
+            .cloned()
+            .ok_or_else(|| SurgeError::ProfileNotFound {
+                name: state.current_profile.clone(),
+            })
+    }
+
+    async fn switch_profile(&self, name: &str) -> Result<()> {
+        let mut state =
+            self.record(Operation::SwitchProfile, format!("switch_profile {}", name))?;
+        if !state.profiles.iter().any(|p| p.matches(name)) {
+            return Err(SurgeError::ProfileNotFound {
+                name: name.to_string(),
+            });
+        }
+        state.current_profile = name.to_string();
+        Ok(())
+    }
+
+    async fn dump_profile(&self, _effective: bool) -> Result<String> {
+        let state = self.check(Operation::ProfileDump)?;
+        // No modules or overrides: original and effective are the same
+        state
+            .profiles
+            .iter()
+            .find(|p| p.matches(&state.current_profile))
+            .and_then(|p| p.content.clone())
+            .ok_or_else(|| SurgeError::ProfileNotFound {
+                name: state.current_profile.clone(),
+            })
+    }
+
+    async fn get_dns_cache(&self) -> Result<Vec<DnsRecord>> {
+        Ok(self.check(Operation::DnsCache)?.dns_cache.clone())
+    }
+
+    async fn flush_dns(&self) -> Result<()> {
+        let mut state = self.record(Operation::FlushDns, "flush_dns".to_string())?;
+        state.dns_cache.clear();
+        Ok(())
+    }
+
+    async fn test_dns_delay(&self) -> Result<Vec<DnsDelay>> {
+        Ok(self.check(Operation::DnsDelay)?.dns_delays.clone())
+    }
+
+    async fn get_feature(&self, feature: Feature) -> Result<bool> {
+        self.check(Operation::Features)?
+            .features
+            .get(&feature)
+            .copied()
+            .ok_or_else(|| unknown_feature(feature))
+    }
+
+    async fn set_feature(&self, feature: Feature, enabled: bool) -> Result<()> {
+        let mut state = self.record(
+            Operation::Features,
+            format!("set_feature {} {}", feature.as_str(), enabled),
+        )?;
+        let status = state
+            .features
+            .get_mut(&feature)
+            .ok_or_else(|| unknown_feature(feature))?;
+        *status = enabled;
+        Ok(())
+    }
+
+    async fn get_mitm_ca(&self) -> Result<Vec<u8>> {
+        Ok(self.check(Operation::MitmCa)?.mitm_ca.clone())
+    }
+
+    async fn get_modules(&self) -> Result<Vec<Module>> {
+        Ok(self.check(Operation::Modules)?.modules.clone())
+    }
+
+    async fn set_module_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        let mut state = self.record(
+            Operation::Modules,
+            format!("set_module_enabled {} {}", name, enabled),
+        )?;
+        let module = state
+            .modules
+            .iter_mut()
+            .find(|m| m.name == name)
+            .ok_or_else(|| SurgeError::ModuleNotFound {
+                name: name.to_string(),
+            })?;
+        module.enabled = enabled;
+        Ok(())
+    }
+
+    async fn get_events(&self) -> Result<Vec<Event>> {
+        Ok(self.check(Operation::Events)?.events.clone())
+    }
+
+    async fn get_traffic(&self) -> Result<TrafficStats> {
+        Ok(self.check(Operation::Traffic)?.traffic.clone())
+    }
+
+    async fn evaluate_script(
+        &self,
+        _script: &str,
+        mock_type: ScriptMockType,
+        timeout_secs: u64,
+    ) -> Result<ScriptEvaluation> {
+        let state = self.record(
+            Operation::Scripting,
+            format!("evaluate_script {} {}", mock_type.as_str(), timeout_secs),
+        )?;
+        Ok(state.script_evaluation.clone())
+    }
+
+    /// Surge stops answering afterwards (`is_available` turns false)
+    async fn stop_surge(&self) -> Result<()> {
+        let mut state = self.record(Operation::StopSurge, "stop_surge".to_string())?;
+        state.available = false;
+        Ok(())
+    }
+
+    async fn set_log_level(&self, level: LogLevel) -> Result<()> {
+        let mut state = self.record(
+            Operation::LogLevel,
+            format!("set_log_level {}", level.as_str()),
+        )?;
+        state.log_level = level;
+        Ok(())
+    }
+
+    async fn run_diagnostics(&self) -> Result<Vec<DiagnosticCheck>> {
+        Ok(self.check(Operation::Diagnostics)?.diagnostics.clone())
+    }
+}
+
+/// Error for a feature missing from `MemoryState::features`
+fn unknown_feature(feature: Feature) -> SurgeError {
+    SurgeError::Unsupported {
+        backend: "memory".to_string(),
+        operation: format!("feature {}", feature.as_str()),
+    }
+}
@@ -1,11 +1,18 @@
 /// Infrastructure layer - external service implementations
 ///
 /// Depends on external services: HTTP API, CLI, System
+pub mod certificate;
 pub mod cli_client;
 pub mod http_client;
+pub mod memory_backend;
+pub mod recording;
 pub mod system_client;
+pub mod tls;
 
 // Re-export clients
 pub use cli_client::SurgeCliClient;
 pub use http_client::SurgeHttpClient;
+pub use memory_backend::{MemoryBackend, MemoryState};
+pub use recording::{Recorder, Replayer};
 pub use system_client::SurgeSystemClient;
+pub use tls::TlsOptions;
@@ -0,0 +1,238 @@
+/// Session recording and replay for the HTTP API
+///
+/// `Recorder` appends every raw response `SurgeHttpClient` receives to
+/// `<dir>/responses.jsonl`, one timestamped entry per line. `Replayer` loads
+/// such a file and answers requests from it, following the original timeline,
+/// so the TUI (including response parsing) runs offline.
+///
+/// Unmasked profiles (`/v1/profiles/current?sensitive=1`) are never recorded;
+/// everything else, including request URLs and the masked profile, is.
+use crate::domain::errors::{Result, SurgeError};
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// File holding the recorded responses inside the session directory
+const RESPONSES_FILE: &str = "responses.jsonl";
+
+/// Query of requests whose response holds unmasked secrets (not recorded)
+const SENSITIVE_QUERY: &str = "sensitive=1";
+
+/// Raw HTTP response
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// One recorded response (a line of `responses.jsonl`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    /// When the response was received (RFC 3339, local time)
+    time: String,
+    method: String,
+    /// Path including the query string
+    path: String,
+    status: u16,
+    /// Raw response body
+    body: String,
+}
+
+/// Appends responses to a session directory
+#[derive(Debug)]
+pub struct Recorder {
+    file: Mutex<File>,
+}
+
+impl Recorder {
+    /// Create the directory if needed and append to its responses file
+    pub fn create(dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(dir).map_err(|e| recording_error(dir, e))?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(RESPONSES_FILE))
+            .map_err(|e| recording_error(dir, e))?;
+        tracing::warn!(
+            "Recording HTTP API responses to {}: it contains your requests, \
+             connections and masked profile; review it before sharing",
+            dir.join(RESPONSES_FILE).display()
+        );
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Record a response (failures are logged, never returned)
+    ///
+    /// Responses with unmasked secrets are skipped
+    pub fn record(&self, method: &str, path: &str, response: &RawResponse) {
+        if is_sensitive(path) {
+            tracing::debug!("Not recording sensitive response for {}", path);
+            return;
+        }
+        let entry = RecordedResponse {
+            time: Local::now().to_rfc3339(),
+            method: method.to_string(),
+            path: path.to_string(),
+            status: response.status,
+            body: response.body.clone(),
+        };
+        let line = match serde_json::to_string(&entry) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!("Failed to serialize recorded response: {}", e);
+                return;
+            }
+        };
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = writeln!(file, "{}", line) {
+            tracing::warn!("Failed to record response for {}: {}", path, e);
+        }
+    }
+}
+
+/// Answers requests from a recorded session
+#[derive(Debug)]
+pub struct Replayer {
+    /// Responses per (method, path), ordered by offset from the session start
+    responses: HashMap<(String, String), Vec<(Duration, RawResponse)>>,
+    started_at: Instant,
+}
+
+impl Replayer {
+    /// Load `<dir>/responses.jsonl`
+    pub fn load(dir: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(dir.join(RESPONSES_FILE))
+            .map_err(|e| recording_error(dir, e))?;
+
+        let mut entries: Vec<(DateTime<FixedOffset>, RecordedResponse)> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: RecordedResponse =
+                serde_json::from_str(line).map_err(|e| SurgeError::ParseError {
+                    source: format!("{} line {}", RESPONSES_FILE, index + 1),
+                    error: e.to_string(),
+                })?;
+            let time =
+                DateTime::parse_from_rfc3339(&entry.time).map_err(|e| SurgeError::ParseError {
+                    source: format!("{} line {}", RESPONSES_FILE, index + 1),
+                    error: e.to_string(),
+                })?;
+            entries.push((time, entry));
+        }
+        entries.sort_by_key(|(time, _)| *time);
+
+        let Some(start) = entries.first().map(|(time, _)| *time) else {
+            return Err(SurgeError::ConfigError {
+                message: format!("Replay: no responses in {}", dir.display()),
+            });
+        };
+
+        let mut responses: HashMap<(String, String), Vec<(Duration, RawResponse)>> = HashMap::new();
+        for (time, entry) in entries {
+            let offset = (time - start).to_std().unwrap_or_default();
+            responses
+                .entry((entry.method, entry.path))
+                .or_default()
+                .push((
+                    offset,
+                    RawResponse {
+                        status: entry.status,
+                        body: entry.body,
+                    },
+                ));
+        }
+
+        tracing::info!(
+            "Loaded recorded session from {} ({} endpoints)",
+            dir.display(),
+            responses.len()
+        );
+        Ok(Self {
+            responses,
+            started_at: Instant::now(),
+        })
+    }
+
+    /// Response to a request at the current point of the session timeline
+    ///
+    /// The latest response recorded up to now is used (the first one before
+    /// it was recorded, the last one once the session has ended). Requests
+    /// that were never recorded get a 404.
+    pub fn respond(&self, method: &str, path: &str) -> RawResponse {
+        let Some(recorded) = self.responses.get(&(method.to_string(), path.to_string())) else {
+            return RawResponse {
+                status: 404,
+                body: String::new(),
+            };
+        };
+
+        let elapsed = self.started_at.elapsed();
+        recorded
+            .iter()
+            .take_while(|(offset, _)| *offset <= elapsed)
+            .last()
+            .unwrap_or(&recorded[0])
+            .1
+            .clone()
+    }
+}
+
+/// Whether the request asks for unmasked secrets
+fn is_sensitive(path: &str) -> bool {
+    path.split_once('?')
+        .is_some_and(|(_, query)| query.split('&').any(|pair| pair == SENSITIVE_QUERY))
+}
+
+fn recording_error(dir: &Path, error: std::io::Error) -> SurgeError {
+    SurgeError::ConfigError {
+        message: format!("Session directory {}: {}", dir.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(body: &str) -> RawResponse {
+        RawResponse {
+            status: 200,
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn unmasked_profile_is_not_recorded() {
+        let dir = std::env::temp_dir().join(format!("surge-tui-recording-{}", std::process::id()));
+        let recorder = Recorder::create(&dir).unwrap();
+        recorder.record(
+            "GET",
+            "/v1/profiles/current?sensitive=0",
+            &response("password = ***"),
+        );
+        recorder.record(
+            "GET",
+            "/v1/profiles/current?sensitive=1",
+            &response("password = secret"),
+        );
+        drop(recorder);
+
+        let replayer = Replayer::load(&dir).unwrap();
+        let content = std::fs::read_to_string(dir.join(RESPONSES_FILE)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!content.contains("secret"));
+        let masked = replayer.respond("GET", "/v1/profiles/current?sensitive=0");
+        assert_eq!(masked.body, "password = ***");
+        let unmasked = replayer.respond("GET", "/v1/profiles/current?sensitive=1");
+        assert_eq!(unmasked.status, 404);
+    }
+}
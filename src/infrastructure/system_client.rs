@@ -2,8 +2,13 @@
 ///
 /// System-level operations: checking Surge process status, starting Surge, etc.
 use crate::domain::errors::{Result, SurgeError};
+use crate::domain::models::profile_name;
+use std::path::PathBuf;
 use tokio::process::Command;
 
+/// Default profile directory, relative to the home directory
+const PROFILE_DIR: &str = "Library/Application Support/Surge/Profiles";
+
 /// System command client
 #[derive(Clone, Copy)]
 pub struct SurgeSystemClient;
@@ -87,6 +92,31 @@ impl SurgeSystemClient {
         stdout.trim().parse().ok()
     }
 
+    /// List profiles in the default profile directory (names without `.conf`)
+    pub async fn list_profiles(&self) -> Result<Vec<String>> {
+        let dir = std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(PROFILE_DIR))
+            .ok_or_else(|| SurgeError::ConfigError {
+                message: "HOME is not set".to_string(),
+            })?;
+
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .map_err(|e| SurgeError::ConfigError {
+                message: format!("Cannot read {}: {}", dir.display(), e),
+            })?;
+
+        let mut profiles = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.ends_with(".conf") {
+                profiles.push(profile_name(&file_name).to_string());
+            }
+        }
+        profiles.sort();
+        Ok(profiles)
+    }
+
     /// Check if surge-cli exists at the given path
     pub async fn cli_exists(&self, cli_path: &str) -> bool {
         tokio::fs::metadata(cli_path).await.is_ok()
@@ -6,11 +6,14 @@
 /// - application: Business coordination layer
 /// - ui: User interface layer
 /// - config: Configuration management
+/// - mock: Mock Surge HTTP API (development and tests, `mock` feature)
 pub mod application;
 pub mod config;
 pub mod domain;
 pub mod i18n;
 pub mod infrastructure;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod ui;
 
 // Re-export commonly used types
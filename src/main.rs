@@ -5,8 +5,88 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::path::{Path, PathBuf};
+use surge_tui::config::Recording;
+use surge_tui::infrastructure::certificate;
 use surge_tui::{App, Config, SurgeClient};
 
+const USAGE: &str = "Usage: surge-tui [--record DIR | --replay DIR] [--export-ca FILE]
+
+  --record DIR      Save every raw HTTP API response to DIR (with timestamps;
+                    unmasked profiles are left out)
+  --replay DIR      Run from a recorded session in DIR, without a live Surge
+  --export-ca FILE  Save the MITM CA certificate to FILE and exit
+                    (PEM when FILE ends in .pem or .crt, DER otherwise)";
+
+/// Command line arguments
+#[derive(Default)]
+struct Args {
+    recording: Option<Recording>,
+    export_ca: Option<PathBuf>,
+}
+
+/// Parse command line arguments
+fn parse_args() -> anyhow::Result<Args> {
+    let mut parsed = Args::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |what: &str| {
+            args.next()
+                .map(PathBuf::from)
+                .ok_or_else(|| anyhow::anyhow!("{} needs a {}\n\n{}", arg, what, USAGE))
+        };
+        let mode = match arg.as_str() {
+            "--record" => Recording::Record(value("directory")?),
+            "--replay" => Recording::Replay(value("directory")?),
+            "--export-ca" => {
+                parsed.export_ca = Some(value("file")?);
+                continue;
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => anyhow::bail!("unknown argument: {}\n\n{}", arg, USAGE),
+        };
+        if parsed.recording.is_some() {
+            anyhow::bail!("--record and --replay cannot be combined\n\n{}", USAGE);
+        }
+        parsed.recording = Some(mode);
+    }
+    Ok(parsed)
+}
+
+/// Save the MITM CA certificate and print its details
+async fn export_ca(client: &SurgeClient, path: &Path) -> anyhow::Result<()> {
+    let ca = client.get_mitm_ca().await?;
+    let pem = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("pem") || e.eq_ignore_ascii_case("crt"));
+    if pem {
+        std::fs::write(path, certificate::to_pem(&ca.der))?;
+    } else {
+        std::fs::write(path, &ca.der)?;
+    }
+
+    println!(
+        "Saved MITM CA certificate to {} ({})",
+        path.display(),
+        if pem { "PEM" } else { "DER" }
+    );
+    println!("  Subject:     {}", ca.subject);
+    println!(
+        "  Not before:  {}",
+        ca.not_before.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!(
+        "  Not after:   {}",
+        ca.not_after.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!("  SHA-256:     {}", ca.fingerprint);
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize logging (only warnings and errors unless RUST_LOG is set)
@@ -14,11 +94,20 @@ async fn main() -> anyhow::Result<()> {
         .with_env_filter(std::env::var("RUST_LOG").unwrap_or_else(|_| "surge_tui=warn".to_string()))
         .init();
 
+    let args = parse_args()?;
+
     // Load config
-    let config = Config::load(None)?;
+    let mut config = Config::load(None)?;
+    config.recording = args.recording;
+    let replay = matches!(config.recording, Some(Recording::Replay(_)));
 
-    // Validate API Key
-    if config.surge.http_api_key.is_empty() {
+    // Select startup instance (SURGE_INSTANCE picks one by name, otherwise the first)
+    let instances = config.instance_list();
+    let active_instance = config.startup_instance();
+    let active_config = config.with_instance(&instances[active_instance]);
+
+    // Validate API Key (a replayed session needs none)
+    if active_config.surge.http_api_key.is_empty() && !replay {
         eprintln!("Error: HTTP API Key not configured");
         eprintln!("\nPlease set SURGE_HTTP_API_KEY environment variable or create config file");
         eprintln!("\nExample config file:\n");
@@ -27,10 +116,20 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Create Surge client
-    let client = SurgeClient::new(config.clone());
+    let client = match SurgeClient::new(active_config) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(ref path) = args.export_ca {
+        return export_ca(&client, path).await;
+    }
 
     // Create app
-    let mut app = App::new(client, config.ui.refresh_interval);
+    let mut app = App::new(client, config, active_instance);
 
     // Setup terminal
     enable_raw_mode()?;
@@ -0,0 +1,9 @@
+/// Mock Surge HTTP API
+///
+/// Local stand-in for Surge's HTTP API, used by the `surge-mock` binary and
+/// by integration tests: `SurgeClient` and the TUI run against it without a Mac.
+pub mod server;
+pub mod state;
+
+pub use server::MockServer;
+pub use state::MockState;
@@ -0,0 +1,518 @@
+/// Mock Surge HTTP API server
+///
+/// Serves the `/v1/*` endpoints used by `SurgeHttpClient` from a `MockState`.
+/// Every request must carry the configured `X-Key`; successful POSTs mutate
+/// the state and are recorded in `MockState::calls`.
+use super::state::MockState;
+use axum::{
+    extract::{Path, Query, Request, State},
+    http::{header, Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// State shared by the handlers
+#[derive(Clone)]
+struct Shared {
+    api_key: Arc<str>,
+    state: Arc<Mutex<MockState>>,
+}
+
+/// Error response (`{"error": message}` with a status code)
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+type HandlerResult = std::result::Result<Json<Value>, ApiError>;
+
+/// Running mock server (stopped when dropped)
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Start serving on `addr` (port 0 picks a free port)
+    pub async fn start(
+        addr: SocketAddr,
+        api_key: impl Into<String>,
+        state: MockState,
+    ) -> std::io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(state));
+        let app = router(api_key.into(), state.clone());
+
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("Mock server stopped: {}", e);
+            }
+        });
+
+        tracing::info!("Mock Surge HTTP API listening on {}", addr);
+        Ok(Self { addr, state, task })
+    }
+
+    /// Bound address
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Copy of the current state
+    pub fn state(&self) -> MockState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Modify the state (e.g. simulate changes made in the Surge UI)
+    pub fn update(&self, f: impl FnOnce(&mut MockState)) {
+        f(&mut self.state.lock().unwrap());
+    }
+
+    /// Wait until the server stops
+    pub async fn wait(mut self) {
+        let _ = (&mut self.task).await;
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Build the `/v1/*` router
+fn router(api_key: String, state: Arc<Mutex<MockState>>) -> Router {
+    let shared = Shared {
+        api_key: api_key.into(),
+        state,
+    };
+
+    Router::new()
+        .route("/v1/outbound", get(get_outbound).post(set_outbound))
+        .route(
+            "/v1/outbound/global",
+            get(get_global_policy).post(set_global_policy),
+        )
+        .route("/v1/policies", get(get_policies))
+        .route("/v1/policies/test", post(test_policies))
+        .route("/v1/policy_groups", get(get_policy_groups))
+        .route(
+            "/v1/policy_groups/select",
+            get(get_selection).post(set_selection),
+        )
+        .route("/v1/policy_groups/test", post(test_policy_group))
+        .route(
+            "/v1/policy_groups/test_results",
+            get(get_group_test_results),
+        )
+        .route("/v1/rules", get(get_rules))
+        .route("/v1/requests/recent", get(get_recent_requests))
+        .route("/v1/requests/active", get(get_active_connections))
+        .route("/v1/requests/kill", post(kill_connection))
+        .route("/v1/dns", get(get_dns))
+        .route("/v1/dns/flush", post(flush_dns))
+        .route("/v1/test/dns_delay", post(test_dns_delay))
+        .route("/v1/features/{name}", get(get_feature).post(set_feature))
+        .route("/v1/mitm/ca", get(get_mitm_ca))
+        .route("/v1/modules", get(get_modules).post(set_module))
+        .route("/v1/events", get(get_events))
+        .route("/v1/traffic", get(get_traffic))
+        .route("/v1/scripting/evaluate", post(evaluate_script))
+        .route("/v1/profiles", get(get_profiles))
+        .route("/v1/profiles/current", get(get_current_profile))
+        .route("/v1/profiles/reload", post(reload_profile))
+        .route("/v1/profiles/switch", post(switch_profile))
+        .route("/v1/stop", post(stop_surge))
+        .layer(middleware::from_fn_with_state(shared.clone(), check_key))
+        .with_state(shared)
+}
+
+/// Reject requests without the right `X-Key`; record successful POSTs
+async fn check_key(State(shared): State<Shared>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get("X-Key")
+        .is_some_and(|key| key.as_bytes() == shared.api_key.as_bytes());
+    if !authorized {
+        return error(StatusCode::UNAUTHORIZED, "invalid X-Key").into_response();
+    }
+
+    let call = (request.method() == Method::POST).then(|| format!("POST {}", request.uri().path()));
+    let response = next.run(request).await;
+    if let Some(call) = call {
+        if response.status().is_success() {
+            shared.state.lock().unwrap().calls.push(call);
+        }
+    }
+    response
+}
+
+fn error(status: StatusCode, message: &str) -> ApiError {
+    ApiError {
+        status,
+        message: message.to_string(),
+    }
+}
+
+fn ok() -> HandlerResult {
+    Ok(Json(json!({})))
+}
+
+/// Required string field of a JSON body
+fn str_field<'a>(body: &'a Value, field: &str) -> std::result::Result<&'a str, ApiError> {
+    body.get(field).and_then(Value::as_str).ok_or_else(|| {
+        error(
+            StatusCode::BAD_REQUEST,
+            &format!("missing field: {}", field),
+        )
+    })
+}
+
+// ===== Outbound mode =====
+
+async fn get_outbound(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "mode": state.outbound })))
+}
+
+async fn set_outbound(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let mode = str_field(&body, "mode")?;
+    if !matches!(mode, "direct" | "proxy" | "rule") {
+        return Err(error(StatusCode::BAD_REQUEST, "invalid mode"));
+    }
+    shared.state.lock().unwrap().outbound = mode.to_string();
+    ok()
+}
+
+async fn get_global_policy(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "policy": state.global_policy })))
+}
+
+async fn set_global_policy(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let policy = str_field(&body, "policy")?;
+    let mut state = shared.state.lock().unwrap();
+    if !state.has_policy(policy) {
+        return Err(error(StatusCode::NOT_FOUND, "unknown policy"));
+    }
+    state.global_policy = policy.to_string();
+    ok()
+}
+
+// ===== Policies =====
+
+async fn get_policies(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    let groups: Vec<&String> = state.policy_groups.keys().collect();
+    Ok(Json(json!({
+        "proxies": state.proxies,
+        "policy-groups": groups,
+    })))
+}
+
+async fn test_policies(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let names = body
+        .get("policy_names")
+        .and_then(Value::as_array)
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, "missing field: policy_names"))?;
+    let state = shared.state.lock().unwrap();
+    let names: Vec<&str> = names.iter().filter_map(Value::as_str).collect();
+    if names
+        .iter()
+        .any(|name| state.failing_policy_tests.iter().any(|f| f == name))
+    {
+        return Err(error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "policy test failed",
+        ));
+    }
+    let results: serde_json::Map<String, Value> = names
+        .into_iter()
+        .map(|name| (name.to_string(), state.latency_result(name)))
+        .collect();
+    Ok(Json(Value::Object(results)))
+}
+
+async fn get_policy_groups(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!(state.policy_groups)))
+}
+
+async fn get_selection(
+    State(shared): State<Shared>,
+    Query(query): Query<HashMap<String, String>>,
+) -> HandlerResult {
+    let group_name = query
+        .get("group_name")
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, "missing group_name"))?;
+    let state = shared.state.lock().unwrap();
+    // Groups without a manual selection (url-test, ...) have no answer
+    match state.selections.get(group_name) {
+        Some(policy) => Ok(Json(json!({ "policy": policy }))),
+        None => Err(error(StatusCode::NOT_FOUND, "no selection")),
+    }
+}
+
+async fn set_selection(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let group_name = str_field(&body, "group_name")?;
+    let policy = str_field(&body, "policy")?;
+    let mut state = shared.state.lock().unwrap();
+    let members = state
+        .group_members(group_name)
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, "unknown policy group"))?;
+    if !members.iter().any(|m| m == policy) {
+        return Err(error(StatusCode::BAD_REQUEST, "policy not in group"));
+    }
+    state
+        .selections
+        .insert(group_name.to_string(), policy.to_string());
+    ok()
+}
+
+async fn get_group_test_results(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    // Group name → member name → result (nested groups are skipped)
+    let results: serde_json::Map<String, Value> = state
+        .policy_groups
+        .keys()
+        .map(|group| {
+            let members: serde_json::Map<String, Value> = state
+                .group_members(group)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|name| !state.policy_groups.contains_key(name))
+                .map(|name| {
+                    let result = state.latency_result(&name);
+                    (name, result)
+                })
+                .collect();
+            (group.clone(), Value::Object(members))
+        })
+        .collect();
+    Ok(Json(Value::Object(results)))
+}
+
+async fn test_policy_group(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let group_name = str_field(&body, "group_name")?;
+    let state = shared.state.lock().unwrap();
+    // Every member passes
+    let available = state
+        .group_members(group_name)
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, "unknown policy group"))?;
+    Ok(Json(json!({ "available": available })))
+}
+
+// ===== Rules =====
+
+async fn get_rules(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "rules": state.rules })))
+}
+
+// ===== Requests =====
+
+async fn get_recent_requests(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "requests": state.recent_requests })))
+}
+
+async fn get_active_connections(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "requests": state.active_connections })))
+}
+
+async fn kill_connection(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let id = body
+        .get("id")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, "missing field: id"))?;
+    let mut state = shared.state.lock().unwrap();
+    let before = state.active_connections.len();
+    state
+        .active_connections
+        .retain(|c| c.get("id").and_then(Value::as_u64) != Some(id));
+    if state.active_connections.len() == before {
+        return Err(error(StatusCode::NOT_FOUND, "unknown connection"));
+    }
+    ok()
+}
+
+// ===== DNS =====
+
+async fn get_dns(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "dnsCache": state.dns_cache })))
+}
+
+async fn flush_dns(State(shared): State<Shared>) -> HandlerResult {
+    shared.state.lock().unwrap().dns_cache.clear();
+    ok()
+}
+
+async fn test_dns_delay(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(state.dns_delay.clone()))
+}
+
+// ===== Features =====
+
+async fn get_feature(State(shared): State<Shared>, Path(name): Path<String>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    match state.features.get(&name) {
+        Some(enabled) => Ok(Json(json!({ "enabled": enabled }))),
+        None => Err(error(StatusCode::NOT_FOUND, "unknown feature")),
+    }
+}
+
+async fn set_feature(
+    State(shared): State<Shared>,
+    Path(name): Path<String>,
+    Json(body): Json<Value>,
+) -> HandlerResult {
+    let enabled = body
+        .get("enabled")
+        .and_then(Value::as_bool)
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, "missing field: enabled"))?;
+    let mut state = shared.state.lock().unwrap();
+    match state.features.get_mut(&name) {
+        Some(value) => *value = enabled,
+        None => return Err(error(StatusCode::NOT_FOUND, "unknown feature")),
+    }
+    ok()
+}
+
+// ===== MITM =====
+
+async fn get_mitm_ca(State(shared): State<Shared>) -> std::result::Result<Response, ApiError> {
+    let state = shared.state.lock().unwrap();
+    let der = BASE64
+        .decode(&state.mitm_ca)
+        .map_err(|_| error(StatusCode::NOT_FOUND, "no CA certificate"))?;
+    Ok(([(header::CONTENT_TYPE, "application/x-x509-ca-cert")], der).into_response())
+}
+
+// ===== Modules =====
+
+async fn get_modules(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({
+        "enabled": state.enabled_modules,
+        "available": state.modules,
+    })))
+}
+
+async fn set_module(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let name = str_field(&body, "module_name")?;
+    let enabled = body
+        .get("enabled")
+        .and_then(Value::as_bool)
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, "missing field: enabled"))?;
+    let mut state = shared.state.lock().unwrap();
+    if !state.has_module(name) {
+        return Err(error(StatusCode::NOT_FOUND, "unknown module"));
+    }
+    state.enabled_modules.retain(|m| m != name);
+    if enabled {
+        state.enabled_modules.push(name.to_string());
+    }
+    ok()
+}
+
+// ===== Events =====
+
+async fn get_events(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "events": state.events })))
+}
+
+// ===== Traffic =====
+
+async fn get_traffic(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    // Fixtures without traffic mimic Surge versions lacking the endpoint
+    if state.traffic.is_null() {
+        return Err(error(StatusCode::NOT_FOUND, "not found"));
+    }
+    Ok(Json(state.traffic.clone()))
+}
+
+// ===== Scripting =====
+
+/// Echo `console.log("...")` string arguments; scripts containing `throw` fail
+async fn evaluate_script(Json(body): Json<Value>) -> HandlerResult {
+    let script = str_field(&body, "script_text")?;
+    let mock_type = str_field(&body, "mock_type")?;
+
+    if script.contains("throw") {
+        return Ok(Json(json!({
+            "result": null,
+            "output": "",
+            "exception": "Uncaught Error (mock)",
+        })));
+    }
+
+    let output: Vec<&str> = script
+        .split("console.log(")
+        .skip(1)
+        .filter_map(|call| {
+            let quote = call.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+            call[1..].split(quote).next()
+        })
+        .collect();
+    Ok(Json(json!({
+        "result": { "mock_type": mock_type },
+        "output": output.join("\n"),
+    })))
+}
+
+// ===== Profiles =====
+
+async fn get_profiles(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({ "profiles": state.profiles })))
+}
+
+async fn get_current_profile(State(shared): State<Shared>) -> HandlerResult {
+    let state = shared.state.lock().unwrap();
+    Ok(Json(json!({
+        "name": state.current_profile,
+        "content": state.profile_contents.get(&state.current_profile),
+    })))
+}
+
+async fn reload_profile() -> HandlerResult {
+    ok()
+}
+
+async fn switch_profile(State(shared): State<Shared>, Json(body): Json<Value>) -> HandlerResult {
+    let name = str_field(&body, "name")?;
+    let mut state = shared.state.lock().unwrap();
+    if !state.profiles.iter().any(|p| p == name) {
+        return Err(error(StatusCode::NOT_FOUND, "unknown profile"));
+    }
+    state.current_profile = name.to_string();
+    ok()
+}
+
+// ===== Engine control =====
+
+/// Only recorded: the mock keeps serving so a session can continue after "stopping"
+async fn stop_surge() -> HandlerResult {
+    ok()
+}
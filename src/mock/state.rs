@@ -0,0 +1,127 @@
+/// Mock Surge state
+///
+/// Loaded from fixture JSON (see `fixtures/surge-mock.json`). Collections are
+/// kept as raw JSON so fixtures are served exactly as written.
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Fixture bundled with the crate
+const DEFAULT_FIXTURE: &str = include_str!("../../fixtures/surge-mock.json");
+
+/// State served and mutated by the mock server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockState {
+    /// Outbound mode ("direct" / "proxy" / "rule")
+    #[serde(default = "default_outbound")]
+    pub outbound: String,
+    /// Global policy used in proxy mode (`/v1/outbound/global`)
+    #[serde(default)]
+    pub global_policy: String,
+    /// Proxy names (`/v1/policies`)
+    #[serde(default)]
+    pub proxies: Vec<String>,
+    /// Policy groups: name → members (`/v1/policy_groups` format)
+    #[serde(default)]
+    pub policy_groups: BTreeMap<String, Vec<Value>>,
+    /// Selected policy per group
+    #[serde(default)]
+    pub selections: BTreeMap<String, String>,
+    /// Latency (ms) per proxy reported by policy tests; other proxies fail
+    #[serde(default)]
+    pub policy_latencies: BTreeMap<String, u32>,
+    /// Policies whose test request fails with HTTP 500 (the whole request fails)
+    #[serde(default)]
+    pub failing_policy_tests: Vec<String>,
+    /// Rule lines in profile order (`/v1/rules`)
+    #[serde(default)]
+    pub rules: Vec<String>,
+    #[serde(default)]
+    pub recent_requests: Vec<Value>,
+    #[serde(default)]
+    pub active_connections: Vec<Value>,
+    #[serde(default)]
+    pub dns_cache: Vec<Value>,
+    /// DNS delay test result (`/v1/test/dns_delay` format)
+    #[serde(default)]
+    pub dns_delay: Value,
+    /// Feature switches (`/v1/features/<name>`)
+    #[serde(default)]
+    pub features: BTreeMap<String, bool>,
+    /// MITM CA certificate, base64-encoded DER (`/v1/mitm/ca`)
+    #[serde(default)]
+    pub mitm_ca: String,
+    /// Available modules (`{"name", "description"}` objects)
+    #[serde(default)]
+    pub modules: Vec<Value>,
+    /// Enabled module names
+    #[serde(default)]
+    pub enabled_modules: Vec<String>,
+    /// Event center entries (`/v1/events` format)
+    #[serde(default)]
+    pub events: Vec<Value>,
+    /// Traffic statistics (`/v1/traffic` format)
+    #[serde(default)]
+    pub traffic: Value,
+    /// Profile names
+    #[serde(default)]
+    pub profiles: Vec<String>,
+    #[serde(default)]
+    pub current_profile: String,
+    /// Profile text per profile name
+    #[serde(default)]
+    pub profile_contents: BTreeMap<String, String>,
+    /// Requests that changed state, in order (e.g. "POST /v1/requests/kill")
+    #[serde(skip)]
+    pub calls: Vec<String>,
+}
+
+fn default_outbound() -> String {
+    "rule".to_string()
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        serde_json::from_str(DEFAULT_FIXTURE).expect("bundled mock fixture is valid")
+    }
+}
+
+impl MockState {
+    /// Load a fixture file
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Whether a module with this name is available
+    pub fn has_module(&self, name: &str) -> bool {
+        self.modules
+            .iter()
+            .any(|m| m.get("name").and_then(Value::as_str) == Some(name))
+    }
+
+    /// Whether a proxy or policy group with this name exists
+    pub fn has_policy(&self, name: &str) -> bool {
+        self.proxies.iter().any(|p| p == name) || self.policy_groups.contains_key(name)
+    }
+
+    /// Test result of one proxy (`/v1/policies/test` format)
+    pub fn latency_result(&self, name: &str) -> Value {
+        match self.policy_latencies.get(name) {
+            Some(rtt) => json!({ "available": true, "rtt": rtt }),
+            None => json!({ "available": false, "error": "timeout" }),
+        }
+    }
+
+    /// Member names of a policy group
+    pub fn group_members(&self, group_name: &str) -> Option<Vec<String>> {
+        self.policy_groups.get(group_name).map(|members| {
+            members
+                .iter()
+                .filter_map(|m| m.get("name").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        })
+    }
+}
@@ -1,7 +1,14 @@
 /// UI application state and event handling
 use crate::application::SurgeClient;
+use crate::config::{Config, InstanceConfig, Recording};
+use crate::domain::backend::Operation;
 use crate::domain::entities::{AlertAction, AppSnapshot, ViewMode};
-use crate::domain::models::PolicyDetail;
+use crate::domain::models::{
+    profile_name, DiagnosticCheck, DnsDelay, Feature, LogLevel as SurgeLogLevel, PolicyDetail,
+    ProfileInfo, Rule,
+};
+use crate::domain::profile;
+use crate::domain::rule_match::{self, MatchQuery};
 use chrono::{DateTime, Local};
 use crossterm::event::{self, Event, KeyCode, KeyEvent};
 use ratatui::{
@@ -9,15 +16,72 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
     Frame, Terminal,
 };
 use std::collections::HashMap;
+use std::future::Future;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::task::{AbortHandle, JoinHandle};
 
 // Import Notification type
 use super::components::notifications::{Notification, NotificationLevel};
+use super::components::rules::{self, RuleSimulation};
+use super::components::scripting::{ScriptConsole, ScriptRun, MAX_TIMEOUT_SECS};
+
+/// UI loop tick: upper bound on how long a new snapshot waits to be drawn
+const UI_TICK: Duration = Duration::from_millis(100);
+
+/// Lines scrolled by PgUp/PgDn in the Profile view
+const PROFILE_PAGE_LINES: isize = 20;
+
+/// Background task that polls `SurgeClient` and sends snapshots to the UI
+struct Poller {
+    task: JoinHandle<()>,
+    /// Snapshots produced by the task
+    snapshot_rx: mpsc::Receiver<AppSnapshot>,
+    /// Wakes the task for an immediate refresh
+    refresh_tx: mpsc::Sender<()>,
+}
+
+impl Poller {
+    fn spawn(client: SurgeClient, interval: Duration) -> Self {
+        let (snapshot_tx, snapshot_rx) = mpsc::channel(1);
+        let (refresh_tx, mut refresh_rx) = mpsc::channel(1);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let snapshot = client.get_snapshot().await;
+                if snapshot_tx.send(snapshot).await.is_err() {
+                    break; // UI gone
+                }
+
+                // Wait for the next tick or an explicit refresh request
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    request = refresh_rx.recv() => {
+                        if request.is_none() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        Self {
+            task,
+            snapshot_rx,
+            refresh_tx,
+        }
+    }
+}
+
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
 
 /// Message type for background test tasks
 #[derive(Debug)]
@@ -28,11 +92,70 @@ enum TestMessage {
     Completed {
         group_name: String,
         results: Vec<PolicyDetail>,
+        /// surge-cli ran the test and could not use the configured URL
+        url_ignored: bool,
     },
     /// Test failed
     Failed { error: String },
 }
 
+/// Result of a user action run in the background, applied by the UI loop
+#[derive(Debug)]
+enum ActionResult {
+    /// Connection kill finished
+    ConnectionKilled(Result<(), String>),
+    /// Profile switch finished
+    ProfileSwitched {
+        name: String,
+        result: Result<(), String>,
+    },
+    /// Profile text for the Profile view, with the options it was loaded with
+    ProfileLoaded {
+        effective: bool,
+        show_sensitive: bool,
+        result: Result<String, String>,
+    },
+    /// Original profile text for the rule match simulator
+    RuleProfileLoaded {
+        input: String,
+        result: Result<String, String>,
+    },
+    /// Profile list for the profile picker
+    ProfilesListed {
+        profiles: Result<Vec<String>, String>,
+        active: Option<ProfileInfo>,
+    },
+    /// Policy list for the global policy picker
+    PoliciesListed(Result<Vec<String>, String>),
+    /// Global policy change finished
+    GlobalPolicySet {
+        policy: String,
+        result: Result<(), String>,
+    },
+    /// Feature switch finished
+    FeatureSet {
+        feature: Feature,
+        enabled: bool,
+        result: Result<(), String>,
+    },
+    /// Module switch finished
+    ModuleSet {
+        name: String,
+        enabled: bool,
+        result: Result<(), String>,
+    },
+    /// DNS cache flush finished
+    DnsFlushed(Result<(), String>),
+    /// Engine control action finished
+    EngineActionDone {
+        action: EngineAction,
+        result: Result<(), String>,
+    },
+    /// Action whose only visible effect is the refreshed snapshot
+    /// (policy selection, outbound mode, start Surge, reload config)
+    Applied(Result<(), String>),
+}
+
 // Notification helper functions
 impl Notification {
     fn new(message: String, level: NotificationLevel) -> Self {
@@ -66,8 +189,10 @@ pub struct App {
     snapshot: AppSnapshot,
     /// Whether to quit
     should_quit: bool,
-    /// Refresh interval
+    /// Refresh interval (background poller period)
     refresh_interval: Duration,
+    /// Background snapshot poller (started by `run`)
+    poller: Option<Poller>,
     /// Currently selected index (for list navigation)
     selected_index: usize,
     /// Selected policy index within a group (None = in group list; Some(idx) = inside group)
@@ -105,6 +230,117 @@ pub struct App {
     show_help: bool,
     /// Connection ID pending kill confirmation (shows confirm dialog when Some)
     show_kill_confirm: Option<u64>,
+    /// Loaded configuration (used to rebuild the client when switching instances)
+    config: Config,
+    /// Selectable Surge instances
+    instances: Vec<InstanceConfig>,
+    /// Index of the instance the client is connected to
+    active_instance: usize,
+    /// Highlighted row in the instance picker (shows picker when Some)
+    show_instance_picker: Option<usize>,
+    /// Global policy picker (shown when Some)
+    global_policy_picker: Option<GlobalPolicyPicker>,
+    /// Profile picker (shown when Some)
+    profile_picker: Option<ProfilePicker>,
+    /// Profile pending switch confirmation (shows confirm dialog over the picker when Some)
+    confirm_profile_switch: Option<String>,
+    /// Profile text shown in the Profile view (None until loaded)
+    profile_text: Option<String>,
+    /// Show the effective profile instead of the original one
+    profile_effective: bool,
+    /// Show sensitive profile values unmasked
+    profile_show_sensitive: bool,
+    /// Lines scrolled past the selected section (PgUp/PgDn)
+    profile_scroll: isize,
+    /// Scripting view state
+    script_console: ScriptConsole,
+    /// Finished script runs from the background task
+    script_rx: mpsc::Receiver<ScriptRun>,
+    /// Sender handed to script run tasks
+    script_tx: mpsc::Sender<ScriptRun>,
+    /// Last DNS delay test result (None until tested)
+    dns_delays: Option<Vec<DnsDelay>>,
+    /// Whether a DNS delay test is in flight
+    dns_delay_testing: bool,
+    /// Finished DNS delay tests from the background task
+    dns_delay_rx: mpsc::Receiver<Result<Vec<DnsDelay>, String>>,
+    /// Sender handed to DNS delay test tasks
+    dns_delay_tx: mpsc::Sender<Result<Vec<DnsDelay>, String>>,
+    /// Engine control menu (shown when Some)
+    engine_menu: Option<EngineMenu>,
+    /// Rule match simulator input (typing when Some)
+    rule_match_input: Option<String>,
+    /// Last rule match simulator result
+    rule_simulation: Option<RuleSimulation>,
+    /// Last diagnostics result (None until run)
+    diagnostics: Option<Vec<DiagnosticCheck>>,
+    /// When the last diagnostics run finished
+    diagnostics_finished_at: Option<DateTime<Local>>,
+    /// Whether a diagnostics run is in flight
+    diagnostics_running: bool,
+    /// Finished diagnostics runs from the background task
+    diagnostics_rx: mpsc::Receiver<Result<Vec<DiagnosticCheck>, String>>,
+    /// Sender handed to diagnostics tasks
+    diagnostics_tx: mpsc::Sender<Result<Vec<DiagnosticCheck>, String>>,
+    /// Finished user actions from background tasks
+    action_rx: mpsc::Receiver<ActionResult>,
+    /// Sender handed to user action tasks
+    action_tx: mpsc::Sender<ActionResult>,
+    /// Background tasks talking to the current instance (aborted on switch)
+    instance_tasks: Vec<AbortHandle>,
+}
+
+/// Engine control menu entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EngineAction {
+    Stop,
+    Restart,
+    SetLogLevel(SurgeLogLevel),
+}
+
+impl EngineAction {
+    /// Word the user must type before the action runs (None: Enter is enough)
+    fn confirm_word(&self) -> Option<&'static str> {
+        match self {
+            Self::Stop => Some("stop"),
+            Self::Restart => Some("restart"),
+            Self::SetLogLevel(_) => None,
+        }
+    }
+}
+
+/// Engine control menu state
+struct EngineMenu {
+    /// Actions available in the current mode
+    actions: Vec<EngineAction>,
+    /// Highlighted row
+    selected: usize,
+    /// Typed confirmation of the highlighted action (Some while confirming)
+    confirm_input: Option<String>,
+}
+
+/// Profile picker state
+struct ProfilePicker {
+    /// Available profile names
+    profiles: Vec<String>,
+    /// Current profile (None when it could not be determined)
+    active: Option<ProfileInfo>,
+    /// Highlighted row
+    selected: usize,
+}
+
+impl ProfilePicker {
+    fn is_active(&self, name: &str) -> bool {
+        self.active.as_ref().is_some_and(|p| p.matches(name))
+    }
+}
+
+/// Global policy picker state
+struct GlobalPolicyPicker {
+    /// Proxies followed by policy groups
+    policies: Vec<String>,
+    /// Highlighted row
+    selected: usize,
 }
 
 /// DevTools log entry
@@ -155,16 +391,24 @@ impl DevToolsLog {
 
 impl App {
     /// Create a new application
-    pub fn new(client: SurgeClient, refresh_interval_secs: u64) -> Self {
+    ///
+    /// `client` must be connected to `config.instance_list()[active_instance]`
+    pub fn new(client: SurgeClient, config: Config, active_instance: usize) -> Self {
         // Create background test message channel (buffer size 1)
         let (test_tx, test_rx) = mpsc::channel(1);
+        let (script_tx, script_rx) = mpsc::channel(1);
+        let (dns_delay_tx, dns_delay_rx) = mpsc::channel(1);
+        let (diagnostics_tx, diagnostics_rx) = mpsc::channel(1);
+        // Several actions may be in flight at once
+        let (action_tx, action_rx) = mpsc::channel(16);
 
         Self {
             client,
             current_view: ViewMode::Overview,
             snapshot: AppSnapshot::new(),
             should_quit: false,
-            refresh_interval: Duration::from_secs(refresh_interval_secs),
+            refresh_interval: Duration::from_secs(config.ui.refresh_interval),
+            poller: None,
             selected_index: 0,
             policy_detail_index: None,
             testing_policy_group: None,
@@ -183,6 +427,35 @@ impl App {
             grouped_app_index: 0,
             show_help: false,
             show_kill_confirm: None,
+            instances: config.instance_list(),
+            config,
+            active_instance,
+            show_instance_picker: None,
+            global_policy_picker: None,
+            profile_picker: None,
+            confirm_profile_switch: None,
+            profile_text: None,
+            profile_effective: false,
+            profile_show_sensitive: false,
+            profile_scroll: 0,
+            script_console: ScriptConsole::new(),
+            script_rx,
+            script_tx,
+            dns_delays: None,
+            dns_delay_testing: false,
+            dns_delay_rx,
+            dns_delay_tx,
+            engine_menu: None,
+            rule_match_input: None,
+            rule_simulation: None,
+            diagnostics: None,
+            diagnostics_finished_at: None,
+            diagnostics_running: false,
+            diagnostics_rx,
+            diagnostics_tx,
+            action_rx,
+            action_tx,
+            instance_tasks: Vec::new(),
         }
     }
 
@@ -211,8 +484,8 @@ impl App {
 
     /// Run the application
     pub async fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> anyhow::Result<()> {
-        // Initial load
-        self.refresh().await;
+        // Data is fetched by the background poller; the loop below never waits on the network
+        self.start_poller();
 
         loop {
             // Clean expired notifications
@@ -221,27 +494,14 @@ impl App {
             // Render UI
             terminal.draw(|f| self.render(f))?;
 
-            // Process background test messages (non-blocking)
-            let mut has_test_message = false;
-            while let Ok(msg) = self.test_rx.try_recv() {
-                self.handle_test_message(msg);
-                has_test_message = true;
-            }
-
-            // If test messages were processed, redraw immediately (don't wait for user input)
-            if has_test_message {
-                terminal.draw(|f| self.render(f))?;
-            }
+            // Apply background results
+            self.process_background();
 
-            // Handle events (non-blocking with timeout)
-            // Only refresh data on timeout (no keypress) to keep list stable during user interaction
-            if event::poll(self.refresh_interval)? {
+            // Handle events (short timeout so new data is drawn promptly)
+            if event::poll(UI_TICK)? {
                 if let Event::Key(key) = event::read()? {
-                    self.handle_key(key).await;
+                    self.handle_key(key);
                 }
-            } else {
-                // Only refresh on timeout to keep list stable while user is interacting
-                self.refresh().await;
             }
 
             // Check if we should quit
@@ -250,12 +510,68 @@ impl App {
             }
         }
 
+        // Stop background polling
+        self.poller = None;
+
         Ok(())
     }
 
-    /// Refresh data
-    async fn refresh(&mut self) {
-        self.snapshot = self.client.get_snapshot().await;
+    /// Apply everything background tasks and the poller finished (non-blocking)
+    fn process_background(&mut self) {
+        // Background test messages
+        while let Ok(msg) = self.test_rx.try_recv() {
+            self.handle_test_message(msg);
+        }
+
+        // Finished script runs
+        while let Ok(run) = self.script_rx.try_recv() {
+            self.handle_script_run(run);
+        }
+
+        // Finished DNS delay tests
+        while let Ok(result) = self.dns_delay_rx.try_recv() {
+            self.handle_dns_delay_result(result);
+        }
+
+        // Finished diagnostics runs
+        while let Ok(result) = self.diagnostics_rx.try_recv() {
+            self.handle_diagnostics_result(result);
+        }
+
+        // Finished user actions
+        while let Ok(result) = self.action_rx.try_recv() {
+            self.handle_action_result(result);
+        }
+
+        // Snapshots from the poller
+        while let Some(snapshot) = self
+            .poller
+            .as_mut()
+            .and_then(|p| p.snapshot_rx.try_recv().ok())
+        {
+            self.apply_snapshot(snapshot);
+        }
+    }
+
+    /// Start (or restart) the background poller for the current client
+    ///
+    /// Replacing the poller drops the old channel, so snapshots still in flight
+    /// from a previous client are discarded
+    fn start_poller(&mut self) {
+        self.poller = Some(Poller::spawn(self.client.clone(), self.refresh_interval));
+    }
+
+    /// Ask the poller for a snapshot now (e.g. after an action), without waiting for it
+    fn request_refresh(&self) {
+        if let Some(ref poller) = self.poller {
+            // A full buffer already means a refresh is pending
+            let _ = poller.refresh_tx.try_send(());
+        }
+    }
+
+    /// Apply a snapshot received from the poller
+    fn apply_snapshot(&mut self, snapshot: AppSnapshot) {
+        self.snapshot = snapshot;
 
         // Restore test results from cache (prevents loss after refresh)
         if !self.policy_test_cache.is_empty() {
@@ -276,6 +592,83 @@ impl App {
         }
     }
 
+    /// Run a task against the current instance in the background
+    ///
+    /// The task is aborted when switching instances, so its result never
+    /// reaches the new instance's state
+    fn spawn_instance_task(&mut self, task: impl Future<Output = ()> + Send + 'static) {
+        self.instance_tasks.retain(|handle| !handle.is_finished());
+        self.instance_tasks.push(tokio::spawn(task).abort_handle());
+    }
+
+    /// Abort the previous instance's tasks and drop results already sent
+    fn abort_instance_tasks(&mut self) {
+        for handle in self.instance_tasks.drain(..) {
+            handle.abort();
+        }
+        while self.test_rx.try_recv().is_ok() {}
+        while self.script_rx.try_recv().is_ok() {}
+        while self.dns_delay_rx.try_recv().is_ok() {}
+        while self.diagnostics_rx.try_recv().is_ok() {}
+        while self.action_rx.try_recv().is_ok() {}
+        self.script_console.running = false;
+        self.dns_delay_testing = false;
+        self.diagnostics_running = false;
+    }
+
+    /// Switch to another Surge instance (rebuilds the client, keeps the UI running)
+    fn switch_instance(&mut self, index: usize) {
+        let Some(instance) = self.instances.get(index).cloned() else {
+            return;
+        };
+
+        match SurgeClient::new(self.config.with_instance(&instance)) {
+            Ok(client) => {
+                self.client = client;
+                self.active_instance = index;
+                // Results from the previous instance no longer apply
+                self.abort_instance_tasks();
+                self.policy_test_cache.clear();
+                self.testing_policy_group = None;
+                self.dns_delays = None;
+                self.diagnostics = None;
+                self.diagnostics_finished_at = None;
+                self.rule_simulation = None;
+                self.rule_match_input = None;
+                self.script_console.last_run = None;
+                self.profile_text = None;
+                self.profile_scroll = 0;
+                self.profile_picker = None;
+                self.confirm_profile_switch = None;
+                self.global_policy_picker = None;
+                self.engine_menu = None;
+                self.show_kill_confirm = None;
+                self.snapshot = AppSnapshot::new();
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+                if self.current_view == ViewMode::Profile {
+                    self.load_profile();
+                }
+                self.add_devtools_log(
+                    LogLevel::Info,
+                    format!(
+                        "Switched to instance '{}' ({}:{})",
+                        instance.name, instance.surge.http_api_host, instance.surge.http_api_port
+                    ),
+                );
+                self.add_notification(Notification::success(
+                    self.t.notification_instance_switched(&instance.name),
+                ));
+                self.start_poller();
+            }
+            Err(e) => {
+                self.add_notification(Notification::error(
+                    self.t.notification_instance_switch_failed(&e.to_string()),
+                ));
+            }
+        }
+    }
+
     /// Handle background test messages
     fn handle_test_message(&mut self, msg: TestMessage) {
         match msg {
@@ -293,6 +686,7 @@ impl App {
             TestMessage::Completed {
                 group_name,
                 results,
+                url_ignored,
             } => {
                 let alive_count = results.iter().filter(|p| p.alive).count();
                 tracing::info!(
@@ -328,7 +722,7 @@ impl App {
                 // Debug: log policy names from test results
                 self.add_devtools_log(
                     LogLevel::Info,
-                    format!("=== Test results policy names (first 5) ==="),
+                    "=== Test results policy names (first 5) ===".to_string(),
                 );
                 for (i, name_info) in test_result_names.iter().enumerate() {
                     self.add_devtools_log(LogLevel::Info, format!("  [{}] {}", i, name_info));
@@ -402,6 +796,11 @@ impl App {
                     self.t
                         .notification_test_completed(alive_count, results.len()),
                 ));
+                if url_ignored {
+                    let message = self.t.notification_test_url_ignored().to_string();
+                    self.add_devtools_log(LogLevel::Warning, message.clone());
+                    self.add_notification(Notification::info(message));
+                }
             }
             TestMessage::Failed { error } => {
                 tracing::error!("Test failed: {}", error);
@@ -412,11 +811,602 @@ impl App {
         }
     }
 
+    /// Read the script file and evaluate it in the background
+    ///
+    /// The file is read on every run, so edits are picked up without reloading
+    fn run_script(&mut self) {
+        if self.script_console.running {
+            return;
+        }
+        if self.script_console.path.is_empty() {
+            self.add_notification(Notification::error(
+                self.t.notification_script_no_file().to_string(),
+            ));
+            return;
+        }
+
+        let path = self.script_console.script_path();
+        let mock_type = self.script_console.mock_type;
+        let timeout_secs = self.script_console.timeout_secs;
+        let client = self.client.clone();
+        let tx = self.script_tx.clone();
+        self.script_console.running = true;
+
+        self.spawn_instance_task(async move {
+            let started = std::time::Instant::now();
+            let outcome = match tokio::fs::read_to_string(&path).await {
+                Ok(script) => client
+                    .evaluate_script(&script, mock_type, timeout_secs)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(format!("{}: {}", path.display(), e)),
+            };
+            let _ = tx
+                .send(ScriptRun {
+                    outcome,
+                    elapsed: started.elapsed(),
+                    finished_at: Local::now(),
+                })
+                .await;
+        });
+    }
+
+    /// Show a finished script run
+    fn handle_script_run(&mut self, run: ScriptRun) {
+        self.script_console.running = false;
+        match run.outcome {
+            Ok(ref evaluation) => match evaluation.exception {
+                Some(ref exception) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_script_failed(exception),
+                    ));
+                }
+                None => {
+                    self.add_notification(Notification::success(
+                        self.t.notification_script_finished().to_string(),
+                    ));
+                }
+            },
+            Err(ref error) => {
+                self.add_devtools_log(LogLevel::Error, format!("Script run failed: {}", error));
+                self.add_notification(Notification::error(
+                    self.t.notification_script_failed(error),
+                ));
+            }
+        }
+        self.script_console.last_run = Some(run);
+    }
+
+    /// Test upstream DNS server delay in the background
+    fn test_dns_delay(&mut self) {
+        if self.dns_delay_testing || !self.snapshot.supports(Operation::DnsDelay) {
+            return;
+        }
+        let client = self.client.clone();
+        let tx = self.dns_delay_tx.clone();
+        self.dns_delay_testing = true;
+
+        self.spawn_instance_task(async move {
+            let result = client.test_dns_delay().await.map_err(|e| e.to_string());
+            let _ = tx.send(result).await;
+        });
+    }
+
+    /// Show a finished DNS delay test
+    fn handle_dns_delay_result(&mut self, result: Result<Vec<DnsDelay>, String>) {
+        self.dns_delay_testing = false;
+        match result {
+            Ok(delays) => {
+                self.add_devtools_log(
+                    LogLevel::Info,
+                    format!("DNS delay test: {} server(s)", delays.len()),
+                );
+                self.dns_delays = Some(delays);
+                self.add_notification(Notification::success(
+                    self.t.notification_dns_delay_completed().to_string(),
+                ));
+            }
+            Err(error) => {
+                self.add_notification(Notification::error(
+                    self.t.notification_dns_delay_failed(&error),
+                ));
+            }
+        }
+    }
+
+    /// Run the network diagnostics in the background
+    fn run_diagnostics(&mut self) {
+        if self.diagnostics_running || !self.client.supports(Operation::Diagnostics) {
+            return;
+        }
+        let client = self.client.clone();
+        let tx = self.diagnostics_tx.clone();
+        self.diagnostics_running = true;
+
+        self.spawn_instance_task(async move {
+            let result = client.run_diagnostics().await.map_err(|e| e.to_string());
+            let _ = tx.send(result).await;
+        });
+    }
+
+    /// Show a finished diagnostics run
+    fn handle_diagnostics_result(&mut self, result: Result<Vec<DiagnosticCheck>, String>) {
+        self.diagnostics_running = false;
+        match result {
+            Ok(checks) => {
+                self.add_devtools_log(
+                    LogLevel::Info,
+                    format!("Diagnostics: {} check(s)", checks.len()),
+                );
+                self.diagnostics = Some(checks);
+                self.diagnostics_finished_at = Some(Local::now());
+                self.add_notification(Notification::success(
+                    self.t.notification_diagnostics_completed().to_string(),
+                ));
+            }
+            Err(error) => {
+                self.add_notification(Notification::error(
+                    self.t.notification_diagnostics_failed(&error),
+                ));
+            }
+        }
+    }
+
+    /// Run a user action against the current instance in the background
+    ///
+    /// The result comes back through `action_rx` and is applied by `handle_action_result`
+    fn spawn_action(&mut self, action: impl Future<Output = ActionResult> + Send + 'static) {
+        let tx = self.action_tx.clone();
+        self.spawn_instance_task(async move {
+            let _ = tx.send(action.await).await;
+        });
+    }
+
+    /// Apply a finished user action
+    fn handle_action_result(&mut self, result: ActionResult) {
+        match result {
+            ActionResult::ConnectionKilled(Ok(())) => {
+                self.add_notification(Notification::success(
+                    self.t.notification_connection_killed().to_string(),
+                ));
+                // Refresh list
+                self.request_refresh();
+            }
+            ActionResult::ConnectionKilled(Err(e)) => {
+                self.add_notification(Notification::error(self.t.notification_kill_failed(&e)));
+            }
+            ActionResult::ProfileSwitched { name, result } => match result {
+                Ok(()) => {
+                    self.add_notification(Notification::success(
+                        self.t.notification_profile_switched(&name),
+                    ));
+                    self.request_refresh();
+                    if self.current_view == ViewMode::Profile {
+                        self.load_profile();
+                    }
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_profile_switch_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::ProfileLoaded {
+                effective,
+                show_sensitive,
+                result,
+            } => {
+                // A newer load with other options is on its way
+                if effective != self.profile_effective
+                    || show_sensitive != self.profile_show_sensitive
+                {
+                    return;
+                }
+                match result {
+                    Ok(text) => {
+                        // Keep the selected section when it still exists
+                        let sections = profile::sections(&text).len();
+                        if self.current_view == ViewMode::Profile && self.selected_index >= sections
+                        {
+                            self.selected_index = 0;
+                        }
+                        self.profile_text = Some(text);
+                    }
+                    Err(e) => {
+                        self.profile_text = None;
+                        self.add_notification(Notification::error(
+                            self.t.notification_profile_load_failed(&e),
+                        ));
+                    }
+                }
+            }
+            ActionResult::RuleProfileLoaded { input, result } => match result {
+                Ok(text) => self.show_rule_simulation(input, Some(profile::rules(&text))),
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_rule_match_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::ProfilesListed { profiles, active } => match profiles {
+                Ok(profiles) => {
+                    let selected = active
+                        .as_ref()
+                        .and_then(|a| profiles.iter().position(|p| a.matches(p)))
+                        .unwrap_or(0);
+                    self.profile_picker = Some(ProfilePicker {
+                        profiles,
+                        active,
+                        selected,
+                    });
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_profiles_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::PoliciesListed(result) => match result {
+                Ok(policies) => {
+                    // Start on the current global policy
+                    let selected = self
+                        .snapshot
+                        .global_policy
+                        .as_ref()
+                        .and_then(|current| policies.iter().position(|p| p == current))
+                        .unwrap_or(0);
+                    self.global_policy_picker = Some(GlobalPolicyPicker { policies, selected });
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_global_policy_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::GlobalPolicySet { policy, result } => match result {
+                Ok(()) => {
+                    self.add_notification(Notification::success(
+                        self.t.notification_global_policy_set(&policy),
+                    ));
+                    // Refresh to get real state
+                    self.request_refresh();
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_global_policy_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::FeatureSet {
+                feature,
+                enabled,
+                result,
+            } => match result {
+                Ok(()) => {
+                    let name = super::components::overview::feature_name(feature, self.t);
+                    let msg = if enabled {
+                        self.t.notification_feature_enabled(name)
+                    } else {
+                        self.t.notification_feature_disabled(name)
+                    };
+                    self.add_notification(Notification::success(msg));
+                    // Refresh to get real state
+                    self.request_refresh();
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_feature_toggle_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::ModuleSet {
+                name,
+                enabled,
+                result,
+            } => match result {
+                Ok(()) => {
+                    let msg = if enabled {
+                        self.t.notification_module_enabled(&name)
+                    } else {
+                        self.t.notification_module_disabled(&name)
+                    };
+                    self.add_notification(Notification::success(msg));
+                    // Refresh to get real state
+                    self.request_refresh();
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_module_toggle_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::DnsFlushed(result) => match result {
+                Ok(()) => {
+                    self.add_notification(Notification::success(
+                        self.t.notification_dns_flushed().to_string(),
+                    ));
+                    self.request_refresh();
+                }
+                Err(e) => {
+                    self.add_notification(Notification::error(
+                        self.t.notification_dns_flush_failed(&e),
+                    ));
+                }
+            },
+            ActionResult::EngineActionDone { action, result } => {
+                match result {
+                    Ok(()) => {
+                        let message = match action {
+                            EngineAction::Stop => self.t.notification_surge_stopped().to_string(),
+                            EngineAction::Restart => {
+                                self.t.notification_surge_restarted().to_string()
+                            }
+                            EngineAction::SetLogLevel(level) => {
+                                self.t.notification_log_level_set(level.as_str())
+                            }
+                        };
+                        self.add_notification(Notification::success(message));
+                    }
+                    Err(e) => self.add_notification(Notification::error(
+                        self.t.notification_engine_failed(&e),
+                    )),
+                }
+                self.request_refresh();
+            }
+            ActionResult::Applied(result) => {
+                if let Err(e) = result {
+                    self.add_devtools_log(LogLevel::Error, format!("Action failed: {}", e));
+                }
+                // Refresh to get real state
+                self.request_refresh();
+            }
+        }
+    }
+
+    /// Load the profile text for the Profile view in the background
+    fn load_profile(&mut self) {
+        let client = self.client.clone();
+        let effective = self.profile_effective;
+        let show_sensitive = self.profile_show_sensitive;
+        self.spawn_action(async move {
+            let result = client
+                .get_profile_text(effective, show_sensitive)
+                .await
+                .map_err(|e| e.to_string());
+            ActionResult::ProfileLoaded {
+                effective,
+                show_sensitive,
+                result,
+            }
+        });
+    }
+
+    /// Find the rule the input would hit (rule list, or the profile's `[Rule]`
+    /// section when the rule list is unavailable)
+    fn simulate_rule(&mut self, input: String) {
+        if MatchQuery::parse(&input).is_empty() {
+            return;
+        }
+
+        if self.snapshot.rules.is_empty() {
+            // The profile is fetched in the background; the result is shown when it arrives
+            let client = self.client.clone();
+            self.spawn_action(async move {
+                let result = client
+                    .get_profile_text(false, false)
+                    .await
+                    .map_err(|e| e.to_string());
+                ActionResult::RuleProfileLoaded { input, result }
+            });
+        } else {
+            self.show_rule_simulation(input, None);
+        }
+    }
+
+    /// Evaluate the input against the rule list (or the given profile rules) and show the result
+    fn show_rule_simulation(&mut self, input: String, profile_rules: Option<Vec<Rule>>) {
+        let query = MatchQuery::parse(&input);
+        let rules = profile_rules.as_deref().unwrap_or(&self.snapshot.rules);
+
+        let result = rule_match::evaluate(rules, &query);
+        let matched = result.index.map(|index| (index, rules[index].clone()));
+        let resolved_policy = matched
+            .as_ref()
+            .and_then(|(_, rule)| self.snapshot.resolve_final_policy(&rule.policy));
+
+        // Show the matched rule in the list
+        if let (Some(index), None) = (result.index, &profile_rules) {
+            self.search_query.clear();
+            self.selected_index = index;
+        }
+        self.rule_simulation = Some(RuleSimulation {
+            input,
+            from_profile: profile_rules.is_some(),
+            matched,
+            resolved_policy,
+            skipped_types: result.skipped_types,
+        });
+    }
+
+    /// Open the engine control menu with the actions available in the current mode
+    fn open_engine_menu(&mut self) {
+        let remote = self.client.is_remote();
+        let mut actions = Vec::new();
+        // Locally `killall` always works as the last resort
+        if !remote || self.snapshot.supports(Operation::StopSurge) {
+            actions.push(EngineAction::Stop);
+        }
+        if !remote {
+            actions.push(EngineAction::Restart);
+        }
+        if self.snapshot.supports(Operation::LogLevel) {
+            actions.extend(SurgeLogLevel::ALL.map(EngineAction::SetLogLevel));
+        }
+
+        if actions.is_empty() {
+            self.add_notification(Notification::error(
+                self.t.notification_remote_unsupported().to_string(),
+            ));
+            return;
+        }
+        self.engine_menu = Some(EngineMenu {
+            actions,
+            selected: 0,
+            confirm_input: None,
+        });
+    }
+
+    /// Run a confirmed engine control action in the background
+    fn run_engine_action(&mut self, action: EngineAction) {
+        if action == EngineAction::Restart {
+            self.add_notification(Notification::info(
+                self.t.notification_surge_restarting().to_string(),
+            ));
+        }
+        let client = self.client.clone();
+        self.spawn_action(async move {
+            let result = match action {
+                EngineAction::Stop => client.stop_surge().await,
+                EngineAction::Restart => client.restart_surge().await,
+                EngineAction::SetLogLevel(level) => client.set_log_level(level).await,
+            };
+            ActionResult::EngineActionDone {
+                action,
+                result: result.map_err(|e| e.to_string()),
+            }
+        });
+    }
+
+    /// Scroll the profile text, staying within the text
+    fn scroll_profile(&mut self, lines: isize) {
+        let Some(text) = self.profile_text.as_deref() else {
+            return;
+        };
+        let (min, max) =
+            super::components::profile::scroll_range(text, self.selected_index, &self.search_query);
+        self.profile_scroll = (self.profile_scroll + lines).clamp(min, max);
+    }
+
+    /// List profiles in the background and open the picker on the active one
+    fn open_profile_picker(&mut self) {
+        let client = self.client.clone();
+        self.spawn_action(async move {
+            let (profiles, current) =
+                tokio::join!(client.get_profiles(), client.get_current_profile(false));
+            ActionResult::ProfilesListed {
+                profiles: profiles.map_err(|e| e.to_string()),
+                active: current.ok(),
+            }
+        });
+    }
+
+    /// List policies in the background and open the global policy picker
+    fn open_global_policy_picker(&mut self) {
+        let client = self.client.clone();
+        self.spawn_action(async move {
+            ActionResult::PoliciesListed(client.get_policies().await.map_err(|e| e.to_string()))
+        });
+    }
+
+    /// Set the global policy and switch to proxy mode
+    fn proxy_all_via(&mut self, policy: String) {
+        use crate::domain::models::OutboundMode;
+
+        let client = self.client.clone();
+        let switch_mode = self.snapshot.outbound_mode != Some(OutboundMode::Proxy);
+        self.spawn_action(async move {
+            let mut result = client.set_global_policy(&policy).await;
+            if result.is_ok() && switch_mode {
+                result = client.set_outbound_mode(OutboundMode::Proxy).await;
+            }
+            ActionResult::GlobalPolicySet {
+                policy,
+                result: result.map_err(|e| e.to_string()),
+            }
+        });
+    }
+
+    /// Flip a feature switch (ignored while its state is unknown)
+    fn toggle_feature(&mut self, feature: Feature) {
+        if !self.snapshot.supports(Operation::Features) {
+            return;
+        }
+        let Some(current_status) = self.snapshot.feature(feature) else {
+            return;
+        };
+        let enabled = !current_status;
+
+        let client = self.client.clone();
+        self.spawn_action(async move {
+            let result = client
+                .set_feature(feature, enabled)
+                .await
+                .map_err(|e| e.to_string());
+            ActionResult::FeatureSet {
+                feature,
+                enabled,
+                result,
+            }
+        });
+    }
+
+    /// Enable or disable the module selected in the Modules view
+    fn toggle_selected_module(&mut self) {
+        if !self.snapshot.supports(Operation::Modules) {
+            return;
+        }
+        let Some(module) = self.snapshot.modules.get(self.selected_index) else {
+            return;
+        };
+        let name = module.name.clone();
+        let enabled = !module.enabled;
+
+        let client = self.client.clone();
+        self.spawn_action(async move {
+            let result = client
+                .set_module_enabled(&name, enabled)
+                .await
+                .map_err(|e| e.to_string());
+            ActionResult::ModuleSet {
+                name,
+                enabled,
+                result,
+            }
+        });
+    }
+
     /// Get the length of the current view's list (accounting for display limits and search)
     fn get_current_list_len(&self) -> usize {
         match self.current_view {
-            ViewMode::Overview => 0,
+            ViewMode::Overview => {
+                // Overview: feature toggle list
+                if self.snapshot.supports(Operation::Features) {
+                    Feature::ALL.len()
+                } else {
+                    0
+                }
+            }
+            ViewMode::Scripting | ViewMode::Diagnostics => 0,
             ViewMode::Policies => self.snapshot.policy_groups.len(),
+            ViewMode::Rules => rules::filtered(&self.snapshot.rules, &self.search_query).len(),
+            ViewMode::Modules => self.snapshot.modules.len(),
+            ViewMode::Events => {
+                // Events view: return filtered event count
+                if self.search_query.is_empty() {
+                    self.snapshot.events.len()
+                } else {
+                    let query_lower = self.search_query.to_lowercase();
+                    self.snapshot
+                        .events
+                        .iter()
+                        .filter(|e| e.content.to_lowercase().contains(&query_lower))
+                        .count()
+                }
+            }
+            ViewMode::Profile => self
+                .profile_text
+                .as_deref()
+                .map_or(0, |text| profile::sections(text).len()),
             ViewMode::Dns => {
                 // DNS view: return filtered DNS cache count
                 if self.search_query.is_empty() {
@@ -486,7 +1476,7 @@ impl App {
             .filter_map(|r| {
                 r.process_path
                     .as_ref()
-                    .map(|p| p.split('/').last().unwrap_or(p).to_string())
+                    .map(|p| p.split('/').next_back().unwrap_or(p).to_string())
             })
             .collect();
 
@@ -514,7 +1504,7 @@ impl App {
             let app_name = req
                 .process_path
                 .as_ref()
-                .map(|p| p.split('/').last().unwrap_or(p).to_string())
+                .map(|p| p.split('/').next_back().unwrap_or(p).to_string())
                 .unwrap_or_else(|| "Unknown".to_string());
             app_groups.entry(app_name).or_default().push(req);
         }
@@ -560,23 +1550,21 @@ impl App {
     }
 
     /// Handle keyboard events
-    async fn handle_key(&mut self, key: KeyEvent) {
+    fn handle_key(&mut self, key: KeyEvent) {
         // Kill confirmation popup handling
         if let Some(connection_id) = self.show_kill_confirm {
             match key.code {
                 KeyCode::Enter => {
                     // Execute kill connection
-                    if let Err(e) = self.client.kill_connection(connection_id).await {
-                        self.add_notification(Notification::error(
-                            self.t.notification_kill_failed(&e.to_string()),
-                        ));
-                    } else {
-                        self.add_notification(Notification::success(
-                            self.t.notification_connection_killed().to_string(),
-                        ));
-                        // Refresh list
-                        self.refresh().await;
-                    }
+                    let client = self.client.clone();
+                    self.spawn_action(async move {
+                        ActionResult::ConnectionKilled(
+                            client
+                                .kill_connection(connection_id)
+                                .await
+                                .map_err(|e| e.to_string()),
+                        )
+                    });
                     self.show_kill_confirm = None;
                     return;
                 }
@@ -592,6 +1580,157 @@ impl App {
             }
         }
 
+        // Instance picker handling
+        if let Some(picker_index) = self.show_instance_picker {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.show_instance_picker = Some(picker_index.saturating_sub(1));
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if picker_index + 1 < self.instances.len() {
+                        self.show_instance_picker = Some(picker_index + 1);
+                    }
+                }
+                KeyCode::Enter => {
+                    self.show_instance_picker = None;
+                    if picker_index != self.active_instance {
+                        self.switch_instance(picker_index);
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('w') => {
+                    self.show_instance_picker = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Profile switch confirmation handling
+        if let Some(name) = self.confirm_profile_switch.clone() {
+            match key.code {
+                KeyCode::Enter => {
+                    self.confirm_profile_switch = None;
+                    self.profile_picker = None;
+                    let client = self.client.clone();
+                    self.spawn_action(async move {
+                        let result = client
+                            .switch_profile(&name)
+                            .await
+                            .map_err(|e| e.to_string());
+                        ActionResult::ProfileSwitched { name, result }
+                    });
+                }
+                KeyCode::Esc => {
+                    // Back to the picker
+                    self.confirm_profile_switch = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Profile picker handling
+        if let Some(ref mut picker) = self.profile_picker {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    picker.selected = picker.selected.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if picker.selected + 1 < picker.profiles.len() {
+                        picker.selected += 1;
+                    }
+                }
+                KeyCode::Enter => {
+                    if let Some(name) = picker.profiles.get(picker.selected) {
+                        if !picker.is_active(name) {
+                            self.confirm_profile_switch = Some(name.clone());
+                        }
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('o') => {
+                    self.profile_picker = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Global policy picker handling
+        if let Some(ref mut picker) = self.global_policy_picker {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    picker.selected = picker.selected.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if picker.selected + 1 < picker.policies.len() {
+                        picker.selected += 1;
+                    }
+                }
+                KeyCode::Enter => {
+                    let policy = picker.policies.get(picker.selected).cloned();
+                    self.global_policy_picker = None;
+                    if let Some(policy) = policy {
+                        self.proxy_all_via(policy);
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('p') => {
+                    self.global_policy_picker = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Engine control menu handling; typing a confirmation blocks all other keys
+        if let Some(ref mut menu) = self.engine_menu {
+            let mut run = None;
+            if let Some(ref mut input) = menu.confirm_input {
+                match key.code {
+                    KeyCode::Char(c) => input.push(c),
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Enter => {
+                        // A wrong word keeps the prompt open
+                        let action = menu.actions[menu.selected];
+                        if action.confirm_word() == Some(input.trim()) {
+                            run = Some(action);
+                        }
+                    }
+                    KeyCode::Esc => menu.confirm_input = None,
+                    _ => {}
+                }
+            } else {
+                match key.code {
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        menu.selected = menu.selected.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        if menu.selected + 1 < menu.actions.len() {
+                            menu.selected += 1;
+                        }
+                    }
+                    KeyCode::Enter => {
+                        let action = menu.actions[menu.selected];
+                        if action.confirm_word().is_some() {
+                            menu.confirm_input = Some(String::new());
+                        } else {
+                            run = Some(action);
+                        }
+                    }
+                    KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('e') => {
+                        self.engine_menu = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(action) = run {
+                self.engine_menu = None;
+                self.run_engine_action(action);
+            }
+            return;
+        }
+
         // Popup mode handling - only allow ESC to close
         if self.show_help || self.show_notification_history || self.show_devtools {
             match key.code {
@@ -613,6 +1752,45 @@ impl App {
             }
         }
 
+        // Script path input handling - blocks all other keys like search mode
+        if let Some(ref mut input) = self.script_console.path_input {
+            match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    self.script_console.path = input.trim().to_string();
+                    self.script_console.path_input = None;
+                }
+                KeyCode::Esc => {
+                    self.script_console.path_input = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Rule match input handling - blocks all other keys like search mode
+        if let Some(ref mut input) = self.rule_match_input {
+            match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    let input = input.trim().to_string();
+                    self.rule_match_input = None;
+                    self.simulate_rule(input);
+                }
+                KeyCode::Esc => {
+                    self.rule_match_input = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
         // Search mode handling - completely block all other keys
         if self.search_mode {
             match key.code {
@@ -667,13 +1845,16 @@ impl App {
         match key.code {
             // Enter search mode
             KeyCode::Char('/') => {
-                // Allow search in Policies, Requests, ActiveConnections, and Dns views
+                // Allow search in list views and the profile text
                 let can_search = matches!(
                     self.current_view,
                     ViewMode::Policies
                         | ViewMode::Requests
                         | ViewMode::ActiveConnections
                         | ViewMode::Dns
+                        | ViewMode::Profile
+                        | ViewMode::Events
+                        | ViewMode::Rules
                 );
 
                 if can_search && !self.show_notification_history && !self.show_devtools {
@@ -707,6 +1888,8 @@ impl App {
                     self.show_notification_history = false;
                 } else if self.show_devtools {
                     self.show_devtools = false;
+                } else if self.current_view == ViewMode::Rules && self.rule_simulation.is_some() {
+                    self.rule_simulation = None;
                 } else if self.current_view == ViewMode::Policies
                     && self.policy_detail_index.is_some()
                 {
@@ -733,6 +1916,16 @@ impl App {
                 self.show_help = !self.show_help;
             }
 
+            // W key: open instance picker
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.show_instance_picker = Some(self.active_instance);
+            }
+
+            // O key: open profile picker
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.open_profile_picker();
+            }
+
             // View switching
             KeyCode::Char('1') => {
                 self.current_view = ViewMode::Overview;
@@ -759,6 +1952,88 @@ impl App {
                 self.selected_index = 0;
                 self.policy_detail_index = None;
             }
+            KeyCode::Char('6') => {
+                self.current_view = ViewMode::Modules;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+            }
+            KeyCode::Char('7') => {
+                self.current_view = ViewMode::Profile;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+                self.profile_scroll = 0;
+                self.load_profile();
+            }
+            KeyCode::Char('8') => {
+                self.current_view = ViewMode::Events;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+            }
+            KeyCode::Char('9') => {
+                self.current_view = ViewMode::Scripting;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+            }
+            KeyCode::Char('0') => {
+                self.current_view = ViewMode::Diagnostics;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+                // First visit: run right away
+                if self.diagnostics.is_none() {
+                    self.run_diagnostics();
+                }
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                self.current_view = ViewMode::Rules;
+                self.selected_index = 0;
+                self.policy_detail_index = None;
+            }
+
+            // Scripting view: mock context and timeout
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                if self.current_view == ViewMode::Scripting {
+                    self.script_console.mock_type = self.script_console.mock_type.next();
+                }
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                if self.current_view == ViewMode::Scripting
+                    && self.script_console.timeout_secs < MAX_TIMEOUT_SECS
+                {
+                    self.script_console.timeout_secs += 1;
+                }
+            }
+            KeyCode::Char('-') => {
+                if self.current_view == ViewMode::Scripting && self.script_console.timeout_secs > 1
+                {
+                    self.script_console.timeout_secs -= 1;
+                }
+            }
+
+            // Profile view: original/effective, reveal/mask, scroll
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                if self.current_view == ViewMode::Profile {
+                    self.profile_effective = !self.profile_effective;
+                    self.load_profile();
+                } else if self.current_view == ViewMode::Overview {
+                    self.open_engine_menu();
+                }
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                if self.current_view == ViewMode::Profile {
+                    self.profile_show_sensitive = !self.profile_show_sensitive;
+                    self.load_profile();
+                }
+            }
+            KeyCode::PageUp => {
+                if self.current_view == ViewMode::Profile {
+                    self.scroll_profile(-PROFILE_PAGE_LINES);
+                }
+            }
+            KeyCode::PageDown => {
+                if self.current_view == ViewMode::Profile {
+                    self.scroll_profile(PROFILE_PAGE_LINES);
+                }
+            }
 
             // Toggle grouping mode (for Requests and Connections views)
             KeyCode::Char('g') | KeyCode::Char('G') => {
@@ -789,7 +2064,7 @@ impl App {
                                 let app_name = conn
                                     .process_path
                                     .as_ref()
-                                    .map(|p| p.split('/').last().unwrap_or(p).to_string())
+                                    .map(|p| p.split('/').next_back().unwrap_or(p).to_string())
                                     .unwrap_or_else(|| "Unknown".to_string());
                                 app_groups.entry(app_name).or_default().push(conn);
                             }
@@ -806,12 +2081,8 @@ impl App {
 
                             if self.grouped_app_index < apps.len() {
                                 let (selected_app_name, _) = &apps[self.grouped_app_index];
-                                let app_connections: Vec<_> = app_groups
-                                    .get(selected_app_name)
-                                    .unwrap()
-                                    .iter()
-                                    .copied()
-                                    .collect();
+                                let app_connections: Vec<_> =
+                                    app_groups.get(selected_app_name).unwrap().to_vec();
 
                                 // Apply search filter
                                 let filtered: Vec<_> = if self.search_query.is_empty() {
@@ -881,6 +2152,10 @@ impl App {
 
             // List navigation
             KeyCode::Up => {
+                // Profile view: jumping to a section drops the page offset
+                if self.current_view == ViewMode::Profile {
+                    self.profile_scroll = 0;
+                }
                 if self.current_view == ViewMode::Policies {
                     if let Some(policy_idx) = self.policy_detail_index {
                         // Inside policy group: navigate policy list
@@ -901,6 +2176,10 @@ impl App {
                 }
             }
             KeyCode::Down | KeyCode::Char('j') => {
+                // Profile view: jumping to a section drops the page offset
+                if self.current_view == ViewMode::Profile {
+                    self.profile_scroll = 0;
+                }
                 if self.current_view == ViewMode::Policies {
                     if let Some(policy_idx) = self.policy_detail_index {
                         // Inside policy group: navigate policy list
@@ -933,13 +2212,16 @@ impl App {
                         self.current_view,
                         ViewMode::Requests | ViewMode::ActiveConnections
                     )
+                    && self.grouped_app_index > 0
                 {
-                    if self.grouped_app_index > 0 {
-                        self.grouped_app_index -= 1;
-                        self.selected_index = 0; // Reset request index when switching apps
-                    }
+                    self.grouped_app_index -= 1;
+                    self.selected_index = 0; // Reset request index when switching apps
                 }
             }
+            KeyCode::Char('l') if self.current_view == ViewMode::Scripting => {
+                // Scripting view: edit the script path
+                self.script_console.path_input = Some(self.script_console.path.clone());
+            }
             KeyCode::Right | KeyCode::Char('l') => {
                 if self.grouped_mode
                     && matches!(
@@ -955,24 +2237,39 @@ impl App {
                 }
             }
 
-            // Enter key: enter policy group or switch policy
+            // Enter key: enter policy group or switch policy; toggle feature or module; run script;
+            // match a rule
             KeyCode::Enter => {
-                if self.current_view == ViewMode::Policies {
+                if self.current_view == ViewMode::Scripting {
+                    self.run_script();
+                } else if self.current_view == ViewMode::Rules {
+                    self.rule_match_input = Some(String::new());
+                } else if self.current_view == ViewMode::Overview {
+                    if let Some(feature) = Feature::ALL.get(self.selected_index) {
+                        self.toggle_feature(*feature);
+                    }
+                } else if self.current_view == ViewMode::Modules {
+                    self.toggle_selected_module();
+                } else if self.current_view == ViewMode::Policies {
                     if let Some(policy_idx) = self.policy_detail_index {
                         // Inside policy group: switch to selected policy
                         if self.selected_index < self.snapshot.policy_groups.len() {
                             let group = &self.snapshot.policy_groups[self.selected_index];
                             if policy_idx < group.policies.len() {
-                                let selected_policy = &group.policies[policy_idx];
-                                // Call API to switch policy
-                                let _ = self
-                                    .client
-                                    .select_policy_group(&group.name, &selected_policy.name)
-                                    .await;
+                                let group_name = group.name.clone();
+                                let policy = group.policies[policy_idx].name.clone();
+                                // Call API to switch policy (refreshes when done)
+                                let client = self.client.clone();
+                                self.spawn_action(async move {
+                                    ActionResult::Applied(
+                                        client
+                                            .select_policy_group(&group_name, &policy)
+                                            .await
+                                            .map_err(|e| e.to_string()),
+                                    )
+                                });
                                 // Exit policy group detail view
                                 self.policy_detail_index = None;
-                                // Refresh data
-                                self.refresh().await;
                             }
                         }
                     } else {
@@ -999,67 +2296,80 @@ impl App {
 
             // T key: test all policy latencies (async background task, non-blocking)
             KeyCode::Char('t') | KeyCode::Char('T') => {
-                if self.current_view == ViewMode::Policies {
-                    if self.selected_index < self.snapshot.policy_groups.len() {
-                        let group_name = self.snapshot.policy_groups[self.selected_index]
-                            .name
-                            .clone();
-                        let client = self.client.clone();
-                        let tx = self.test_tx.clone();
-
-                        // Start background test task
-                        tokio::spawn(async move {
-                            // Send test started message
-                            let _ = tx.send(TestMessage::Started).await;
-
-                            tracing::info!(
-                                "Background test task started: testing policy group {}",
-                                group_name
-                            );
-
-                            // Execute test in background (non-blocking)
-                            match client.test_all_policies_with_latency().await {
-                                Ok(policy_details) => {
-                                    // Send test completed message
-                                    let _ = tx
-                                        .send(TestMessage::Completed {
-                                            group_name,
-                                            results: policy_details,
-                                        })
-                                        .await;
-                                }
-                                Err(e) => {
-                                    // Send test failed message
-                                    let _ = tx
-                                        .send(TestMessage::Failed {
-                                            error: e.to_string(),
-                                        })
-                                        .await;
-                                }
+                if self.current_view == ViewMode::Diagnostics {
+                    self.run_diagnostics();
+                } else if self.current_view == ViewMode::Policies
+                    && !self.snapshot.supports(Operation::TestPolicyLatency)
+                {
+                    if self.snapshot.remote_mode {
+                        self.add_notification(Notification::error(
+                            self.t.notification_remote_unsupported().to_string(),
+                        ));
+                    }
+                } else if self.current_view == ViewMode::Policies
+                    && self.selected_index < self.snapshot.policy_groups.len()
+                {
+                    let group_name = self.snapshot.policy_groups[self.selected_index]
+                        .name
+                        .clone();
+                    let policy_names = self.snapshot.group_leaf_policies(&group_name);
+                    let client = self.client.clone();
+                    let tx = self.test_tx.clone();
+
+                    // Start background test task
+                    self.spawn_instance_task(async move {
+                        // Send test started message
+                        let _ = tx.send(TestMessage::Started).await;
+
+                        tracing::info!(
+                            "Background test task started: testing policy group {}",
+                            group_name
+                        );
+
+                        // Execute test in background (non-blocking)
+                        match client.test_policy_latency(&group_name, &policy_names).await {
+                            Ok(report) => {
+                                // Send test completed message
+                                let _ = tx
+                                    .send(TestMessage::Completed {
+                                        group_name,
+                                        results: report.results,
+                                        url_ignored: report.url_ignored,
+                                    })
+                                    .await;
                             }
-                        });
+                            Err(e) => {
+                                // Send test failed message
+                                let _ = tx
+                                    .send(TestMessage::Failed {
+                                        error: e.to_string(),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
 
-                        tracing::info!("Test task started, UI continues to respond");
-                    }
+                    tracing::info!("Test task started, UI continues to respond");
                 }
             }
 
             // F key: flush DNS cache (DNS view only)
             KeyCode::Char('f') | KeyCode::Char('F') => {
-                if self.current_view == ViewMode::Dns && self.snapshot.http_api_available {
-                    match self.client.flush_dns().await {
-                        Ok(_) => {
-                            self.add_notification(Notification::success(
-                                self.t.notification_dns_flushed().to_string(),
-                            ));
-                            self.refresh().await;
-                        }
-                        Err(e) => {
-                            self.add_notification(Notification::error(
-                                self.t.notification_dns_flush_failed(&e.to_string()),
-                            ));
-                        }
-                    }
+                if self.current_view == ViewMode::Dns && self.snapshot.supports(Operation::FlushDns)
+                {
+                    let client = self.client.clone();
+                    self.spawn_action(async move {
+                        ActionResult::DnsFlushed(
+                            client.flush_dns().await.map_err(|e| e.to_string()),
+                        )
+                    });
+                }
+            }
+
+            // D key: test DNS delay (DNS view only)
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                if self.current_view == ViewMode::Dns {
+                    self.test_dns_delay();
                 }
             }
 
@@ -1073,79 +2383,50 @@ impl App {
                         OutboundMode::Proxy => OutboundMode::Rule,
                         OutboundMode::Rule => OutboundMode::Direct,
                     };
-                    if self
-                        .client
-                        .set_outbound_mode(next_mode.clone())
-                        .await
-                        .is_ok()
-                    {
-                        // Refresh to get real state
-                        self.refresh().await;
-                    }
+                    let client = self.client.clone();
+                    self.spawn_action(async move {
+                        ActionResult::Applied(
+                            client
+                                .set_outbound_mode(next_mode)
+                                .await
+                                .map_err(|e| e.to_string()),
+                        )
+                    });
                 }
             }
 
-            // Toggle MITM status (Overview view only)
-            KeyCode::Char('i') | KeyCode::Char('I') => {
-                if self.current_view == ViewMode::Overview && self.snapshot.http_api_available {
-                    if let Some(current_status) = self.snapshot.mitm_enabled {
-                        let new_status = !current_status;
-                        match self.client.set_mitm_status(new_status).await {
-                            Ok(_) => {
-                                // Show notification
-                                let msg = if new_status {
-                                    self.t.notification_mitm_enabled()
-                                } else {
-                                    self.t.notification_mitm_disabled()
-                                };
-                                self.add_notification(Notification::success(msg.to_string()));
-                                // Refresh to get real state
-                                self.refresh().await;
-                            }
-                            Err(e) => {
-                                self.add_notification(Notification::error(
-                                    self.t.notification_feature_toggle_failed(&e.to_string()),
-                                ));
-                            }
-                        }
-                    }
+            // P key: pick the global policy (Overview view only)
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                if self.current_view == ViewMode::Overview
+                    && self.snapshot.supports(Operation::GlobalPolicy)
+                {
+                    self.open_global_policy_picker();
                 }
             }
 
-            // Toggle Capture status (Overview view only)
+            // Dedicated feature shortcuts (Overview view only)
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                if self.current_view == ViewMode::Overview {
+                    self.toggle_feature(Feature::Mitm);
+                }
+            }
             KeyCode::Char('c') | KeyCode::Char('C') => {
-                if self.current_view == ViewMode::Overview && self.snapshot.http_api_available {
-                    if let Some(current_status) = self.snapshot.capture_enabled {
-                        let new_status = !current_status;
-                        match self.client.set_capture_status(new_status).await {
-                            Ok(_) => {
-                                // Show notification
-                                let msg = if new_status {
-                                    self.t.notification_capture_enabled()
-                                } else {
-                                    self.t.notification_capture_disabled()
-                                };
-                                self.add_notification(Notification::success(msg.to_string()));
-                                // Refresh to get real state
-                                self.refresh().await;
-                            }
-                            Err(e) => {
-                                self.add_notification(Notification::error(
-                                    self.t.notification_feature_toggle_failed(&e.to_string()),
-                                ));
-                            }
-                        }
-                    }
+                if self.current_view == ViewMode::Overview {
+                    self.toggle_feature(Feature::Capture);
                 }
             }
 
             // Alert actions
             KeyCode::Char('s') | KeyCode::Char('S') => {
-                // Start Surge
+                // Start Surge (local only; remote alerts never offer this action)
                 if let Some(alert) = self.snapshot.alerts.first() {
                     if matches!(alert.action, AlertAction::StartSurge) {
-                        let _ = self.client.start_surge().await;
-                        self.refresh().await;
+                        let client = self.client.clone();
+                        self.spawn_action(async move {
+                            ActionResult::Applied(
+                                client.start_surge().await.map_err(|e| e.to_string()),
+                            )
+                        });
                     }
                 }
             }
@@ -1154,13 +2435,18 @@ impl App {
                 // Handle Alert ReloadConfig action first
                 if let Some(alert) = self.snapshot.alerts.first() {
                     if matches!(alert.action, AlertAction::ReloadConfig) {
-                        let _ = self.client.reload_config().await;
-                        self.refresh().await;
+                        let client = self.client.clone();
+                        self.spawn_action(async move {
+                            ActionResult::Applied(
+                                client.reload_config().await.map_err(|e| e.to_string()),
+                            )
+                        });
                         return;
                     }
                 }
                 // Otherwise treat as manual refresh
-                self.refresh().await;
+                self.client.retry_mitm_ca();
+                self.request_refresh();
             }
 
             _ => {}
@@ -1199,6 +2485,12 @@ impl App {
                     ViewMode::Requests => ("3", self.t.view_requests()),
                     ViewMode::ActiveConnections => ("4", self.t.view_connections()),
                     ViewMode::Dns => ("5", self.t.view_dns()),
+                    ViewMode::Modules => ("6", self.t.view_modules()),
+                    ViewMode::Profile => ("7", self.t.view_profile()),
+                    ViewMode::Events => ("8", self.t.view_events()),
+                    ViewMode::Scripting => ("9", self.t.view_scripting()),
+                    ViewMode::Diagnostics => ("0", self.t.view_diagnostics()),
+                    ViewMode::Rules => ("u", self.t.view_rules()),
                 };
 
                 // btop style: [number] title
@@ -1279,13 +2571,34 @@ impl App {
         if self.show_kill_confirm.is_some() {
             self.render_kill_confirm(f, area);
         }
+        if self.show_instance_picker.is_some() {
+            self.render_instance_picker(f, area);
+        }
+        if self.global_policy_picker.is_some() {
+            self.render_global_policy_picker(f, area);
+        }
+        if self.profile_picker.is_some() {
+            self.render_profile_picker(f, area);
+        }
+        if self.confirm_profile_switch.is_some() {
+            self.render_profile_switch_confirm(f, area);
+        }
+        if self.engine_menu.is_some() {
+            self.render_engine_menu(f, area);
+        }
     }
 
     /// Render main view content
     fn render_main_view(&self, f: &mut Frame, area: Rect) {
         match self.current_view {
             ViewMode::Overview => {
-                super::components::overview::render(f, area, &self.snapshot, self.t);
+                super::components::overview::render(
+                    f,
+                    area,
+                    &self.snapshot,
+                    self.selected_index,
+                    self.t,
+                );
             }
             ViewMode::Policies => {
                 super::components::policies::render(
@@ -1337,6 +2650,72 @@ impl App {
                     self.selected_index,
                     &self.search_query,
                     self.search_mode,
+                    self.dns_delays.as_deref(),
+                    self.dns_delay_testing,
+                    self.t,
+                );
+            }
+            ViewMode::Modules => {
+                super::components::modules::render(
+                    f,
+                    area,
+                    &self.snapshot.modules,
+                    self.selected_index,
+                    self.snapshot.supports(Operation::Modules),
+                    self.t,
+                );
+            }
+            ViewMode::Events => {
+                super::components::events::render(
+                    f,
+                    area,
+                    &self.snapshot.events,
+                    self.selected_index,
+                    &self.search_query,
+                    self.search_mode,
+                    self.t,
+                );
+            }
+            ViewMode::Profile => {
+                super::components::profile::render(
+                    f,
+                    area,
+                    self.profile_text.as_deref(),
+                    self.selected_index,
+                    self.profile_scroll,
+                    &self.search_query,
+                    self.search_mode,
+                    self.profile_effective,
+                    self.profile_show_sensitive,
+                    self.t,
+                );
+            }
+            ViewMode::Scripting => {
+                super::components::scripting::render(f, area, &self.script_console, self.t);
+            }
+            ViewMode::Diagnostics => {
+                super::components::diagnostics::render(
+                    f,
+                    area,
+                    self.diagnostics.as_deref(),
+                    self.diagnostics_finished_at,
+                    self.diagnostics_running,
+                    self.client.supports(Operation::Diagnostics),
+                    self.t,
+                );
+            }
+            ViewMode::Rules => {
+                super::components::rules::render(
+                    f,
+                    area,
+                    &self.snapshot.rules,
+                    &self.snapshot.recent_requests,
+                    self.selected_index,
+                    &self.search_query,
+                    self.search_mode,
+                    self.snapshot.supports(Operation::Rules),
+                    self.rule_match_input.as_deref(),
+                    self.rule_simulation.as_ref(),
                     self.t,
                 );
             }
@@ -1362,9 +2741,33 @@ impl App {
         } else {
             self.t.ui_status_stopped().to_string()
         };
+        let status_text = match self.config.recording {
+            Some(Recording::Replay(_)) => format!("{} {}", status_text, self.t.ui_status_replay()),
+            Some(Recording::Record(_)) => {
+                format!("{} {}", status_text, self.t.ui_status_recording())
+            }
+            None if self.snapshot.remote_mode => {
+                format!("{} {}", status_text, self.t.ui_status_remote())
+            }
+            None => status_text,
+        };
 
         // Build shortcut hints (simplified)
-        let mut spans = vec![
+        let mut spans = vec![];
+
+        // Active instance (only meaningful with several instances)
+        if self.instances.len() > 1 {
+            if let Some(instance) = self.instances.get(self.active_instance) {
+                spans.push(Span::styled(
+                    format!(" [{}]", instance.name),
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+        }
+
+        spans.extend([
             Span::styled(
                 format!(" {} ", status_text),
                 if self.snapshot.surge_running {
@@ -1374,7 +2777,7 @@ impl App {
                 },
             ),
             Span::raw("  "),
-        ];
+        ]);
 
         // Show only the most basic shortcut hints
         spans.push(Span::raw(self.t.key_quit()));
@@ -1598,6 +3001,8 @@ impl App {
         lines.push(Line::from(self.t.help_shortcut_toggle_outbound()));
         lines.push(Line::from(self.t.help_shortcut_notification_history()));
         lines.push(Line::from(self.t.help_shortcut_devtools()));
+        lines.push(Line::from(self.t.help_shortcut_switch_instance()));
+        lines.push(Line::from(self.t.help_shortcut_switch_profile()));
         lines.push(Line::from(self.t.help_shortcut_help()));
         lines.push(Line::from(""));
 
@@ -1613,14 +3018,21 @@ impl App {
         match self.current_view {
             ViewMode::Overview => {
                 lines.push(Line::from(self.t.help_shortcut_toggle_outbound()));
-                if self.snapshot.http_api_available {
+                if self.snapshot.supports(Operation::GlobalPolicy) {
+                    lines.push(Line::from(self.t.help_shortcut_select_global_policy()));
+                }
+                if self.snapshot.supports(Operation::Features) {
+                    lines.push(Line::from(self.t.help_shortcut_toggle_feature()));
                     lines.push(Line::from(self.t.help_shortcut_toggle_mitm()));
                     lines.push(Line::from(self.t.help_shortcut_toggle_capture()));
                 }
+                lines.push(Line::from(self.t.help_shortcut_engine_control()));
             }
             ViewMode::Policies => {
                 lines.push(Line::from(self.t.help_shortcut_search()));
-                lines.push(Line::from(self.t.help_shortcut_test_latency()));
+                if self.snapshot.supports(Operation::TestPolicyLatency) {
+                    lines.push(Line::from(self.t.help_shortcut_test_latency()));
+                }
                 lines.push(Line::from(self.t.help_shortcut_enter_select_policy()));
                 lines.push(Line::from(self.t.help_shortcut_esc_back()));
             }
@@ -1633,9 +3045,40 @@ impl App {
             }
             ViewMode::Dns => {
                 lines.push(Line::from(self.t.help_shortcut_search()));
-                if self.snapshot.http_api_available {
+                if self.snapshot.supports(Operation::FlushDns) {
                     lines.push(Line::from(self.t.help_shortcut_flush_dns()));
                 }
+                if self.snapshot.supports(Operation::DnsDelay) {
+                    lines.push(Line::from(self.t.help_shortcut_test_dns()));
+                }
+            }
+            ViewMode::Modules => {
+                if self.snapshot.supports(Operation::Modules) {
+                    lines.push(Line::from(self.t.help_shortcut_toggle_module()));
+                }
+            }
+            ViewMode::Events => {
+                lines.push(Line::from(self.t.help_shortcut_search()));
+            }
+            ViewMode::Rules => {
+                lines.push(Line::from(self.t.help_shortcut_search()));
+                lines.push(Line::from(self.t.help_shortcut_rule_match()));
+            }
+            ViewMode::Profile => {
+                lines.push(Line::from(self.t.help_shortcut_profile_sections()));
+                lines.push(Line::from(self.t.help_shortcut_profile_scroll()));
+                lines.push(Line::from(self.t.help_shortcut_search()));
+                lines.push(Line::from(self.t.help_shortcut_toggle_effective()));
+                lines.push(Line::from(self.t.help_shortcut_toggle_sensitive()));
+            }
+            ViewMode::Scripting => {
+                lines.push(Line::from(self.t.help_shortcut_run_script()));
+                lines.push(Line::from(self.t.help_shortcut_script_file()));
+                lines.push(Line::from(self.t.help_shortcut_script_mock()));
+                lines.push(Line::from(self.t.help_shortcut_script_timeout()));
+            }
+            ViewMode::Diagnostics => {
+                lines.push(Line::from(self.t.help_shortcut_run_diagnostics()));
             }
         }
 
@@ -1701,14 +3144,20 @@ impl App {
                 // Connection details
                 if let Some(ref url) = conn.url {
                     lines.push(Line::from(vec![
-                        Span::styled(self.t.confirm_kill_label_target(), Style::default().add_modifier(Modifier::BOLD)),
+                        Span::styled(
+                            self.t.confirm_kill_label_target(),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
                         Span::raw(url),
                     ]));
                 }
 
                 if let Some(ref process) = conn.process_path {
                     lines.push(Line::from(vec![
-                        Span::styled(self.t.confirm_kill_label_process(), Style::default().add_modifier(Modifier::BOLD)),
+                        Span::styled(
+                            self.t.confirm_kill_label_process(),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
                         Span::raw(process),
                     ]));
                 }
@@ -1716,7 +3165,10 @@ impl App {
                 let upload_kb = conn.out_bytes / 1024;
                 let download_kb = conn.in_bytes / 1024;
                 lines.push(Line::from(vec![
-                    Span::styled(self.t.confirm_kill_label_traffic(), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::styled(
+                        self.t.confirm_kill_label_traffic(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
                     Span::styled(
                         format!("↑{}KB ↓{}KB", upload_kb, download_kb),
                         Style::default().fg(Color::Green),
@@ -1744,6 +3196,346 @@ impl App {
         }
     }
 
+    /// Render instance picker popup
+    fn render_instance_picker(&self, f: &mut Frame, area: Rect) {
+        let Some(picker_index) = self.show_instance_picker else {
+            return;
+        };
+
+        // Centered popup: 60% width, 50% height
+        let popup_area = self.centered_rect(60, 50, area);
+
+        let items: Vec<ListItem> = self
+            .instances
+            .iter()
+            .enumerate()
+            .map(|(i, instance)| {
+                let is_active = i == self.active_instance;
+                let scheme = if instance.surge.tls { "https" } else { "http" };
+                let mut spans = vec![
+                    Span::styled(
+                        if is_active { "✓ " } else { "  " },
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        format!("{:<16}", instance.name),
+                        if is_active {
+                            Style::default()
+                                .fg(Color::Green)
+                                .add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::Cyan)
+                        },
+                    ),
+                    Span::styled(
+                        format!(
+                            " {}://{}:{}",
+                            scheme, instance.surge.http_api_host, instance.surge.http_api_port
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ];
+                if is_active {
+                    spans.push(Span::styled(
+                        format!(" {}", self.t.instance_active()),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.instance_picker_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+
+        let mut state = ListState::default();
+        state.select(Some(picker_index));
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    /// Render profile picker popup
+    fn render_profile_picker(&self, f: &mut Frame, area: Rect) {
+        let Some(ref picker) = self.profile_picker else {
+            return;
+        };
+
+        // Centered popup: 50% width, 50% height
+        let popup_area = self.centered_rect(50, 50, area);
+
+        let items: Vec<ListItem> = picker
+            .profiles
+            .iter()
+            .map(|name| {
+                let is_active = picker.is_active(name);
+                let mut spans = vec![
+                    Span::styled(
+                        if is_active { "✓ " } else { "  " },
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        name.clone(),
+                        if is_active {
+                            Style::default()
+                                .fg(Color::Green)
+                                .add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::Cyan)
+                        },
+                    ),
+                ];
+                if is_active {
+                    spans.push(Span::styled(
+                        format!(" {}", self.t.profile_active()),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.profile_picker_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    /// Render profile switch confirmation dialog
+    fn render_profile_switch_confirm(&self, f: &mut Frame, area: Rect) {
+        let Some(ref name) = self.confirm_profile_switch else {
+            return;
+        };
+
+        // Small popup: 50% width, 25% height
+        let popup_area = self.centered_rect(50, 25, area);
+
+        let mut lines = vec![Line::from(vec![Span::styled(
+            self.t.confirm_profile_switch_message(name),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )])];
+        if let Some(active) = self.profile_picker.as_ref().and_then(|p| p.active.as_ref()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(
+                self.t
+                    .confirm_profile_switch_current(profile_name(&active.name)),
+            ));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            self.t.confirm_profile_switch_hint(),
+            Style::default().fg(Color::DarkGray),
+        )]));
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.confirm_profile_switch_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .wrap(ratatui::widgets::Wrap { trim: false });
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_widget(paragraph, popup_area);
+    }
+
+    /// Render engine control menu popup (and the typed confirmation over it)
+    fn render_engine_menu(&self, f: &mut Frame, area: Rect) {
+        let Some(ref menu) = self.engine_menu else {
+            return;
+        };
+
+        // Centered popup: 40% width, 50% height
+        let popup_area = self.centered_rect(40, 50, area);
+
+        let items: Vec<ListItem> = menu
+            .actions
+            .iter()
+            .map(|action| {
+                let line = match action {
+                    EngineAction::Stop => Line::from(Span::styled(
+                        self.t.engine_action_stop(),
+                        Style::default().fg(Color::Red),
+                    )),
+                    EngineAction::Restart => Line::from(Span::styled(
+                        self.t.engine_action_restart(),
+                        Style::default().fg(Color::Yellow),
+                    )),
+                    EngineAction::SetLogLevel(level) => Line::from(vec![
+                        Span::raw(format!("{}: ", self.t.engine_log_level())),
+                        Span::styled(level.as_str(), Style::default().fg(Color::Cyan)),
+                    ]),
+                };
+                ListItem::new(line)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.engine_menu_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+
+        let mut state = ListState::default();
+        state.select(Some(menu.selected));
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_stateful_widget(list, popup_area, &mut state);
+
+        // Typed confirmation
+        let Some(ref input) = menu.confirm_input else {
+            return;
+        };
+        let action = menu.actions[menu.selected];
+        let word = action.confirm_word().unwrap_or_default();
+        let message = match action {
+            EngineAction::Restart => self.t.confirm_engine_restart_message(),
+            _ => self.t.confirm_engine_stop_message(),
+        };
+
+        // Small popup: 50% width, 25% height
+        let popup_area = self.centered_rect(50, 25, area);
+        let lines = vec![
+            Line::from(Span::styled(
+                message,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(self.t.confirm_engine_type_word(word)),
+            Line::from(vec![
+                Span::raw("> "),
+                Span::styled(input.as_str(), Style::default().fg(Color::Yellow)),
+                Span::styled("█", Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                self.t.confirm_engine_hint(),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.confirm_engine_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .wrap(ratatui::widgets::Wrap { trim: false });
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_widget(paragraph, popup_area);
+    }
+
+    /// Render global policy picker popup
+    fn render_global_policy_picker(&self, f: &mut Frame, area: Rect) {
+        let Some(ref picker) = self.global_policy_picker else {
+            return;
+        };
+
+        // Centered popup: 50% width, 60% height
+        let popup_area = self.centered_rect(50, 60, area);
+
+        let items: Vec<ListItem> = picker
+            .policies
+            .iter()
+            .map(|policy| {
+                let is_current = self.snapshot.global_policy.as_deref() == Some(policy.as_str());
+                let is_group = self
+                    .snapshot
+                    .policy_groups
+                    .iter()
+                    .any(|g| &g.name == policy);
+                let mut spans = vec![
+                    Span::styled(
+                        if is_current { "✓ " } else { "  " },
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        policy.clone(),
+                        if is_current {
+                            Style::default()
+                                .fg(Color::Green)
+                                .add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::Cyan)
+                        },
+                    ),
+                ];
+                if is_group {
+                    spans.push(Span::styled(
+                        format!(" [{}]", self.t.global_policy_group()),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                if is_current {
+                    spans.push(Span::styled(
+                        format!(" {}", self.t.global_policy_current()),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.t.global_policy_picker_title())
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+
+        let mut state = ListState::default();
+        state.select(Some(picker.selected));
+
+        f.render_widget(ratatui::widgets::Clear, popup_area);
+        f.render_stateful_widget(list, popup_area, &mut state);
+    }
+
     /// Calculate a centered rectangular area
     fn centered_rect(&self, percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         let popup_layout = Layout::default()
@@ -1765,3 +3557,150 @@ impl App {
             .split(popup_layout[1])[1]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SurgeConfig;
+    use crate::mock::{MockServer, MockState};
+    use ratatui::backend::TestBackend;
+
+    fn instance(name: &str, port: u16) -> InstanceConfig {
+        InstanceConfig {
+            name: name.to_string(),
+            surge: SurgeConfig {
+                http_api_host: "127.0.0.1".to_string(),
+                http_api_port: port,
+                http_api_key: "key".to_string(),
+                ..SurgeConfig::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn switch_instance_clears_per_instance_view_state() {
+        let config = Config {
+            instances: vec![instance("home", 6171), instance("office", 6172)],
+            ..Config::default()
+        };
+        let client = SurgeClient::new(config.with_instance(&config.instances[0])).unwrap();
+        let mut app = App::new(client, config, 0);
+
+        app.profile_text = Some("[General]".to_string());
+        app.profile_scroll = 3;
+        app.rule_match_input = Some("example.com".to_string());
+        app.script_console.last_run = Some(ScriptRun {
+            outcome: Err("boom".to_string()),
+            elapsed: Duration::from_millis(5),
+            finished_at: Local::now(),
+        });
+        app.confirm_profile_switch = Some("Work".to_string());
+
+        app.switch_instance(1);
+
+        assert_eq!(app.active_instance, 1);
+        assert!(app.profile_text.is_none());
+        assert_eq!(app.profile_scroll, 0);
+        assert!(app.rule_match_input.is_none());
+        assert!(app.script_console.last_run.is_none());
+        assert!(app.confirm_profile_switch.is_none());
+    }
+
+    /// App connected to a fresh mock server, drawn on an in-memory terminal
+    async fn mock_app() -> (App, Terminal<TestBackend>, MockServer) {
+        let server = MockServer::start("127.0.0.1:0".parse().unwrap(), "key", MockState::default())
+            .await
+            .unwrap();
+        let mut config = Config::default();
+        config.surge.http_api_host = server.addr().ip().to_string();
+        config.surge.http_api_port = server.addr().port();
+        config.surge.http_api_key = "key".to_string();
+        // Keep surge-cli and pgrep away from the machine running the tests
+        config.surge.remote = Some(true);
+        let client = SurgeClient::new(config.clone()).unwrap();
+        let mut app = App::new(client, config, 0);
+        app.start_poller();
+        let terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+        (app, terminal, server)
+    }
+
+    /// Apply background results and redraw until `done` holds
+    async fn run_until(
+        app: &mut App,
+        terminal: &mut Terminal<TestBackend>,
+        done: impl Fn(&App) -> bool,
+    ) {
+        for _ in 0..100 {
+            app.process_background();
+            terminal.draw(|f| app.render(f)).unwrap();
+            if done(app) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("UI did not reach the expected state");
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::from(code));
+    }
+
+    #[tokio::test]
+    async fn module_toggle_reaches_surge() {
+        let (mut app, mut terminal, server) = mock_app().await;
+        run_until(&mut app, &mut terminal, |app| {
+            !app.snapshot.modules.is_empty()
+        })
+        .await;
+
+        press(&mut app, KeyCode::Char('6'));
+        // "Block Ads" is first and enabled
+        press(&mut app, KeyCode::Enter);
+        run_until(&mut app, &mut terminal, |app| {
+            app.snapshot
+                .modules
+                .iter()
+                .any(|m| m.name == "Block Ads" && !m.enabled)
+        })
+        .await;
+
+        let state = server.state();
+        assert_eq!(state.calls, ["POST /v1/modules"]);
+        assert!(!state.enabled_modules.contains(&"Block Ads".to_string()));
+    }
+
+    #[tokio::test]
+    async fn policy_select_reaches_surge() {
+        let (mut app, mut terminal, server) = mock_app().await;
+        run_until(&mut app, &mut terminal, |app| {
+            app.snapshot
+                .policy_groups
+                .iter()
+                .any(|g| g.selected.is_some())
+        })
+        .await;
+
+        press(&mut app, KeyCode::Char('2'));
+        let proxy = app
+            .snapshot
+            .policy_groups
+            .iter()
+            .position(|g| g.name == "Proxy")
+            .unwrap();
+        for _ in 0..proxy {
+            press(&mut app, KeyCode::Down);
+        }
+        // Enter the group (on "Auto", its selection), move to "HK-01" and select it
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Enter);
+        run_until(&mut app, &mut terminal, |app| {
+            app.snapshot.policy_groups[proxy].selected.as_deref() == Some("HK-01")
+        })
+        .await;
+
+        let state = server.state();
+        assert_eq!(state.calls, ["POST /v1/policy_groups/select"]);
+        assert_eq!(state.selections["Proxy"], "HK-01");
+    }
+}
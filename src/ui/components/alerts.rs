@@ -27,6 +27,7 @@ pub fn render(f: &mut Frame, area: Rect, alerts: &[Alert], t: &'static dyn Trans
     let message = match alert.message.as_str() {
         "surge_not_running" => t.alert_surge_not_running().to_string(),
         "http_api_disabled" => t.alert_http_api_disabled().to_string(),
+        "surge_unreachable" => t.alert_surge_unreachable().to_string(),
         _ => alert.message.clone(), // Dynamic messages are passed through as-is
     };
 
@@ -0,0 +1,172 @@
+/// Diagnostics component - network checks grouped by section with pass/fail markers
+use crate::domain::models::{DiagnosticCheck, DiagnosticSection, DiagnosticStatus};
+use crate::i18n::Translate;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Display name of a section
+fn section_name(section: DiagnosticSection, t: &'static dyn Translate) -> &'static str {
+    match section {
+        DiagnosticSection::Dns => t.diagnostics_section_dns(),
+        DiagnosticSection::Router => t.diagnostics_section_router(),
+        DiagnosticSection::Internet => t.diagnostics_section_internet(),
+        DiagnosticSection::Proxy => t.diagnostics_section_proxy(),
+        DiagnosticSection::Other => t.diagnostics_section_other(),
+    }
+}
+
+/// Marker and color of a status
+fn status_marker(status: DiagnosticStatus) -> (&'static str, Color) {
+    match status {
+        DiagnosticStatus::Pass => ("✓", Color::Green),
+        DiagnosticStatus::Fail => ("✗", Color::Red),
+        DiagnosticStatus::Info => ("·", Color::DarkGray),
+    }
+}
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    checks: Option<&[DiagnosticCheck]>,
+    finished_at: Option<DateTime<Local>>,
+    running: bool,
+    supported: bool,
+    t: &'static dyn Translate,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(t.diagnostics_title());
+
+    let mut lines = Vec::new();
+    if !supported {
+        lines.push(Line::from(Span::styled(
+            t.diagnostics_unsupported(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else if running {
+        lines.push(Line::from(Span::styled(
+            t.diagnostics_running(),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    match checks {
+        Some(checks) if supported => {
+            if !running {
+                lines.push(summary_line(checks, finished_at, t));
+            }
+            lines.push(Line::from(""));
+            lines.extend(section_lines(checks, t));
+        }
+        _ if supported && !running => {
+            lines.push(Line::from(Span::styled(
+                t.diagnostics_not_run(),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        _ => {}
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+/// "All checks passed" or the failed sections, with the time of the run
+fn summary_line(
+    checks: &[DiagnosticCheck],
+    finished_at: Option<DateTime<Local>>,
+    t: &'static dyn Translate,
+) -> Line<'static> {
+    let failed: Vec<&str> = DiagnosticSection::ALL
+        .iter()
+        .filter(|section| {
+            checks
+                .iter()
+                .any(|c| c.section == **section && c.status == DiagnosticStatus::Fail)
+        })
+        .map(|section| section_name(*section, t))
+        .collect();
+
+    let mut spans = vec![if failed.is_empty() {
+        Span::styled(
+            format!("✓ {}", t.diagnostics_all_passed()),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(
+            format!("✗ {}", t.diagnostics_failed_sections(&failed.join(", "))),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )
+    }];
+    if let Some(time) = finished_at {
+        spans.push(Span::styled(
+            format!("  ({})", time.format("%H:%M:%S")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Checks grouped by section; a section header carries the worst status of its checks
+fn section_lines(checks: &[DiagnosticCheck], t: &'static dyn Translate) -> Vec<Line<'static>> {
+    let name_width = checks
+        .iter()
+        .map(|c| c.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for section in DiagnosticSection::ALL {
+        let section_checks: Vec<&DiagnosticCheck> =
+            checks.iter().filter(|c| c.section == section).collect();
+        if section_checks.is_empty() {
+            continue;
+        }
+
+        let status = if section_checks
+            .iter()
+            .any(|c| c.status == DiagnosticStatus::Fail)
+        {
+            DiagnosticStatus::Fail
+        } else if section_checks
+            .iter()
+            .any(|c| c.status == DiagnosticStatus::Pass)
+        {
+            DiagnosticStatus::Pass
+        } else {
+            DiagnosticStatus::Info
+        };
+        let (marker, color) = status_marker(status);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", marker), Style::default().fg(color)),
+            Span::styled(
+                section_name(section, t),
+                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            ),
+        ]));
+
+        for check in section_checks {
+            let (marker, color) = status_marker(check.status);
+            lines.push(Line::from(vec![
+                Span::styled(format!("    {} ", marker), Style::default().fg(color)),
+                Span::styled(
+                    format!("{:<width$}  ", check.name, width = name_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(check.detail.clone(), Style::default().fg(color)),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
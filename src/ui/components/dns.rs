@@ -1,5 +1,5 @@
-/// DNS component - DNS cache list
-use crate::domain::models::DnsRecord;
+/// DNS component - DNS cache list and upstream server delays
+use crate::domain::models::{DnsDelay, DnsRecord};
 use crate::i18n::Translate;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -9,6 +9,7 @@ use ratatui::{
     Frame,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     f: &mut Frame,
     area: Rect,
@@ -16,6 +17,8 @@ pub fn render(
     selected: usize,
     search_query: &str,
     search_mode: bool,
+    delays: Option<&[DnsDelay]>,
+    testing: bool,
     t: &'static dyn Translate,
 ) {
     // Filter records by search query
@@ -44,7 +47,141 @@ pub fn render(
         search_mode,
         t,
     );
-    render_dns_detail(f, chunks[1], &filtered_records, selected, t);
+    // Right side: record detail | upstream servers
+    let servers = upstream_servers(records, delays);
+    let side = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length((servers.len().max(1) as u16 + 2).min(chunks[1].height / 2)),
+        ])
+        .split(chunks[1]);
+
+    render_dns_detail(f, side[0], &filtered_records, selected, t);
+    render_servers(f, side[1], &servers, delays.is_some(), testing, t);
+}
+
+/// Upstream server: test result and cache statistics
+struct UpstreamServer<'a> {
+    /// Server address (empty: overall result)
+    name: &'a str,
+    delay: Option<&'a DnsDelay>,
+    /// Cached records resolved by this server
+    records: usize,
+    /// Average lookup time of those records (ms)
+    avg_time_cost_ms: Option<f64>,
+}
+
+/// Tested servers first, then servers only seen in the cache
+fn upstream_servers<'a>(
+    records: &'a [DnsRecord],
+    delays: Option<&'a [DnsDelay]>,
+) -> Vec<UpstreamServer<'a>> {
+    let mut names: Vec<&str> = delays
+        .unwrap_or_default()
+        .iter()
+        .map(|d| d.server.as_str())
+        .collect();
+    for record in records {
+        if let Some(ref server) = record.server {
+            if !names.contains(&server.as_str()) {
+                names.push(server);
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let resolved: Vec<&DnsRecord> = records
+                .iter()
+                .filter(|r| r.server.as_deref() == Some(name))
+                .collect();
+            let time_costs: Vec<f64> = resolved.iter().filter_map(|r| r.time_cost).collect();
+            let avg_time_cost_ms = (!time_costs.is_empty())
+                .then(|| time_costs.iter().sum::<f64>() / time_costs.len() as f64 * 1000.0);
+            UpstreamServer {
+                name,
+                delay: delays.unwrap_or_default().iter().find(|d| d.server == name),
+                records: resolved.len(),
+                avg_time_cost_ms,
+            }
+        })
+        .collect()
+}
+
+fn render_servers(
+    f: &mut Frame,
+    area: Rect,
+    servers: &[UpstreamServer],
+    tested: bool,
+    testing: bool,
+    t: &'static dyn Translate,
+) {
+    let title = if testing {
+        format!(" {} - {} ", t.dns_servers_title(), t.dns_delay_testing())
+    } else {
+        format!(" {} ", t.dns_servers_title())
+    };
+
+    let mut lines: Vec<Line> = servers
+        .iter()
+        .map(|server| {
+            let name = if server.name.is_empty() {
+                t.dns_server_all()
+            } else {
+                server.name
+            };
+            let delay = match server.delay {
+                Some(DnsDelay {
+                    delay: Some(delay), ..
+                }) => {
+                    let ms = delay * 1000.0;
+                    Span::styled(format!("{:>6.0} ms", ms), latency_style(ms))
+                }
+                Some(DnsDelay {
+                    error: Some(error), ..
+                }) => Span::styled(format!("{:>9}", error), Style::default().fg(Color::Red)),
+                _ => Span::styled(format!("{:>9}", "-"), Style::default().fg(Color::DarkGray)),
+            };
+            let mut spans = vec![
+                Span::styled(format!("{:<20}", name), Style::default().fg(Color::Cyan)),
+                delay,
+            ];
+            if server.records > 0 {
+                spans.push(Span::styled(
+                    format!(
+                        "  {}",
+                        t.dns_server_records(server.records, server.avg_time_cost_ms)
+                    ),
+                    Style::default().fg(Color::Gray),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    if !tested && !testing {
+        lines.push(Line::from(Span::styled(
+            t.dns_delay_not_tested(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+/// Latency colours: cyan (<100ms) / yellow (100-300ms) / red (>300ms)
+fn latency_style(ms: f64) -> Style {
+    if ms < 100.0 {
+        Style::default().fg(Color::Cyan)
+    } else if ms < 300.0 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Red)
+    }
 }
 
 fn render_dns_list(
@@ -89,6 +226,10 @@ fn render_dns_list(
             Span::styled("f", Style::default().fg(Color::Yellow)),
             Span::raw("]"),
             Span::raw(t.action_flush()),
+            Span::raw(" ["),
+            Span::styled("d", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_test()),
             Span::raw(" "),
         ])
     };
@@ -173,6 +314,27 @@ fn render_dns_detail(
 
         lines.push(Line::from(""));
 
+        // Upstream server and lookup time
+        if let Some(ref server) = record.server {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", t.dns_label_server()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(server.as_str(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+        if let Some(time_cost) = record.time_cost {
+            let ms = time_cost * 1000.0;
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", t.dns_label_time_cost()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("{:.0} ms", ms), latency_style(ms)),
+            ]));
+        }
+
         // TTL (expiresTime is a Unix timestamp; convert to remaining seconds)
         if let Some(expires_time) = record.ttl {
             use std::time::{SystemTime, UNIX_EPOCH};
@@ -0,0 +1,223 @@
+/// Events component - event center list with severity and timestamps
+use crate::domain::models::{Event, EventLevel};
+use crate::i18n::Translate;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    events: &[Event],
+    selected: usize,
+    search_query: &str,
+    search_mode: bool,
+    t: &'static dyn Translate,
+) {
+    // Filter events by search query
+    let filtered_events: Vec<_> = if search_query.is_empty() {
+        events.iter().collect()
+    } else {
+        let query_lower = search_query.to_lowercase();
+        events
+            .iter()
+            .filter(|e| e.content.to_lowercase().contains(&query_lower))
+            .collect()
+    };
+
+    // Split area: event list | detail panel
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    render_event_list(
+        f,
+        chunks[0],
+        &filtered_events,
+        selected,
+        search_query,
+        search_mode,
+        t,
+    );
+    render_event_detail(f, chunks[1], &filtered_events, selected, t);
+}
+
+fn render_event_list(
+    f: &mut Frame,
+    area: Rect,
+    events: &[&Event],
+    selected: usize,
+    search_query: &str,
+    search_mode: bool,
+    t: &'static dyn Translate,
+) {
+    let title = if search_mode {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.events_list_title()),
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("█] "),
+        ])
+    } else if !search_query.is_empty() {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.events_list_title()),
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("] "),
+        ])
+    } else {
+        // Show shortcut key hints (btop style)
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.events_list_title()),
+            Span::raw(" ["),
+            Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_select()),
+            Span::raw(" ["),
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_search()),
+            Span::raw(" "),
+        ])
+    };
+
+    let items: Vec<ListItem> = if events.is_empty() {
+        vec![ListItem::new(Span::styled(
+            t.events_no_events(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        events
+            .iter()
+            .map(|event| {
+                let (marker, color) = level_style(event.level);
+                let time = event_time(event)
+                    .map(|time| time.format("%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|| "--".to_string());
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<15}", time),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{} ", marker), Style::default().fg(color)),
+                    Span::styled(&event.content, Style::default().fg(color)),
+                ]))
+            })
+            .collect()
+    };
+
+    let mut state = ListState::default();
+    if !events.is_empty() {
+        state.select(Some(selected));
+    }
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_event_detail(
+    f: &mut Frame,
+    area: Rect,
+    events: &[&Event],
+    selected: usize,
+    t: &'static dyn Translate,
+) {
+    let mut lines = vec![];
+
+    if let Some(event) = events.get(selected) {
+        let (marker, color) = level_style(event.level);
+        let level_text = match event.level {
+            EventLevel::Info => t.event_level_info(),
+            EventLevel::Warning => t.event_level_warning(),
+            EventLevel::Error => t.event_level_error(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.event_label_level()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{} {}", marker, level_text),
+                Style::default().fg(color),
+            ),
+        ]));
+
+        // Local time when parseable, otherwise the raw value
+        let time_text = match event_time(event) {
+            Some(time) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => event.date.clone().unwrap_or_else(|| "--".to_string()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.event_label_time()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(time_text),
+        ]));
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.event_label_identifier()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(&event.identifier, Style::default().fg(Color::DarkGray)),
+        ]));
+
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![Span::styled(
+            format!("{}: ", t.event_label_content()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        lines.push(Line::from(event.content.as_str()));
+    } else {
+        lines.push(Line::from(Span::styled(
+            t.events_no_events(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.events_detail_title()),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Marker and colour of a severity
+fn level_style(level: EventLevel) -> (&'static str, Color) {
+    match level {
+        EventLevel::Info => ("ℹ", Color::Blue),
+        EventLevel::Warning => ("⚠", Color::Yellow),
+        EventLevel::Error => ("✖", Color::Red),
+    }
+}
+
+/// Event time in the local timezone
+fn event_time(event: &Event) -> Option<DateTime<Local>> {
+    let date = event.date.as_deref()?;
+    DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(|time| time.with_timezone(&Local))
+}
@@ -1,7 +1,13 @@
 /// UI component modules
 pub mod alerts;
+pub mod diagnostics;
 pub mod dns;
+pub mod events;
+pub mod modules;
 pub mod notifications;
 pub mod overview;
 pub mod policies;
+pub mod profile;
 pub mod requests;
+pub mod rules;
+pub mod scripting;
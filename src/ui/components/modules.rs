@@ -0,0 +1,160 @@
+/// Modules component - module list with enabled state
+use crate::domain::models::Module;
+use crate::i18n::Translate;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    modules: &[Module],
+    selected: usize,
+    can_toggle: bool,
+    t: &'static dyn Translate,
+) {
+    // Split area: module list | detail panel
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    render_module_list(f, chunks[0], modules, selected, can_toggle, t);
+    render_module_detail(f, chunks[1], modules, selected, t);
+}
+
+fn render_module_list(
+    f: &mut Frame,
+    area: Rect,
+    modules: &[Module],
+    selected: usize,
+    can_toggle: bool,
+    t: &'static dyn Translate,
+) {
+    // Show shortcut key hints (btop style)
+    let mut title = vec![
+        Span::raw(" "),
+        Span::raw(t.modules_list_title()),
+        Span::raw(" ["),
+        Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.action_select()),
+    ];
+    if can_toggle {
+        title.extend([
+            Span::raw(" ["),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_toggle()),
+        ]);
+    }
+    title.push(Span::raw(" "));
+
+    let items: Vec<ListItem> = if modules.is_empty() {
+        vec![ListItem::new(Span::styled(
+            t.modules_no_modules(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        modules
+            .iter()
+            .map(|module| {
+                let (marker, color) = if module.enabled {
+                    ("✓", Color::Green)
+                } else {
+                    ("○", Color::DarkGray)
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{} ", marker), Style::default().fg(color)),
+                    Span::styled(&module.name, Style::default().fg(Color::Cyan)),
+                ]))
+            })
+            .collect()
+    };
+
+    let mut state = ListState::default();
+    if !modules.is_empty() {
+        state.select(Some(selected));
+    }
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Line::from(title)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_module_detail(
+    f: &mut Frame,
+    area: Rect,
+    modules: &[Module],
+    selected: usize,
+    t: &'static dyn Translate,
+) {
+    let mut lines = vec![];
+
+    if let Some(module) = modules.get(selected) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.module_label_name()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(&module.name, Style::default().fg(Color::Cyan)),
+        ]));
+
+        let (status_text, status_color) = if module.enabled {
+            (t.status_enabled(), Color::Green)
+        } else {
+            (t.status_disabled(), Color::Gray)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.module_label_status()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(status_text, Style::default().fg(status_color)),
+        ]));
+
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![Span::styled(
+            format!("{}: ", t.module_label_description()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        match module.description {
+            Some(ref description) => lines.push(Line::from(description.as_str())),
+            None => lines.push(Line::from(Span::styled(
+                t.module_no_description(),
+                Style::default().fg(Color::DarkGray),
+            ))),
+        }
+    } else {
+        lines.push(Line::from(Span::styled(
+            t.modules_no_modules(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.modules_detail_title()),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
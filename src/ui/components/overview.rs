@@ -1,15 +1,149 @@
 /// Overview component - system summary
+use crate::domain::backend::Operation;
 use crate::domain::entities::AppSnapshot;
+use crate::domain::models::{CaCertificate, Feature, TrafficStats};
 use crate::i18n::Translate;
+use chrono::Utc;
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Sparkline},
     Frame,
 };
 
-pub fn render(f: &mut Frame, area: Rect, snapshot: &AppSnapshot, t: &'static dyn Translate) {
+/// Height of the traffic panel (borders included)
+const TRAFFIC_PANEL_HEIGHT: u16 = 10;
+
+/// The MITM CA is flagged this many days before it expires
+const CA_EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// Display name of a feature
+pub fn feature_name(feature: Feature, t: &'static dyn Translate) -> &'static str {
+    match feature {
+        Feature::Mitm => t.feature_mitm(),
+        Feature::Capture => t.feature_capture(),
+        Feature::Rewrite => t.feature_rewrite(),
+        Feature::Scripting => t.feature_scripting(),
+        Feature::SystemProxy => t.feature_system_proxy(),
+        Feature::EnhancedMode => t.feature_enhanced_mode(),
+    }
+}
+
+/// One row of the feature list; unsupported features are greyed out
+fn feature_line(
+    feature: Feature,
+    enabled: Option<bool>,
+    selected: bool,
+    t: &'static dyn Translate,
+) -> Line<'static> {
+    let (status_text, status_color) = match enabled {
+        Some(true) => (t.status_enabled(), Color::Green),
+        Some(false) => (t.status_disabled(), Color::Gray),
+        None => (t.status_unsupported(), Color::DarkGray),
+    };
+    let name_style = if enabled.is_some() {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let mut spans = vec![
+        Span::raw(if selected { "▶ " } else { "  " }),
+        Span::styled(format!("{:<16}", feature_name(feature, t)), name_style),
+        Span::styled(status_text, Style::default().fg(status_color)),
+    ];
+
+    // Dedicated shortcuts
+    let shortcut = match feature {
+        Feature::Mitm => Some("i"),
+        Feature::Capture => Some("c"),
+        _ => None,
+    };
+    if let (Some(key), Some(_)) = (shortcut, enabled) {
+        spans.extend([
+            Span::raw("  ["),
+            Span::styled(key, Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_toggle()),
+        ]);
+    }
+
+    let line = Line::from(spans);
+    if selected {
+        line.style(Style::default().bg(Color::DarkGray))
+    } else {
+        line
+    }
+}
+
+/// MITM CA details; validity turns yellow close to expiry and red once expired
+fn ca_lines(ca: &CaCertificate, t: &'static dyn Translate) -> Vec<Line<'static>> {
+    let days = ca.days_until_expiry(Utc::now());
+    let (warning, color) = if days < 0 {
+        (Some(t.ca_expired().to_string()), Color::Red)
+    } else if days <= CA_EXPIRY_WARNING_DAYS {
+        (Some(t.ca_expires_in(days)), Color::Yellow)
+    } else {
+        (None, Color::Green)
+    };
+
+    let label = |text: &str| Span::raw(format!("  {:<10}", text));
+    let mut validity = vec![
+        label(t.ca_label_validity()),
+        Span::styled(
+            format!(
+                "{} → {}",
+                ca.not_before.format("%Y-%m-%d"),
+                ca.not_after.format("%Y-%m-%d")
+            ),
+            Style::default().fg(color),
+        ),
+    ];
+    if let Some(warning) = warning {
+        validity.push(Span::styled(
+            format!("  ⚠ {}", warning),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    vec![
+        Line::from(vec![Span::styled(
+            t.overview_mitm_ca(),
+            Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]),
+        Line::from(vec![
+            label(t.ca_label_subject()),
+            Span::styled(ca.subject.clone(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(validity),
+        Line::from(vec![
+            label(t.ca_label_fingerprint()),
+            Span::styled(ca.fingerprint.clone(), Style::default().fg(Color::DarkGray)),
+        ]),
+    ]
+}
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    snapshot: &AppSnapshot,
+    selected_index: usize,
+    t: &'static dyn Translate,
+) {
+    // Split area: summary | traffic panel (once traffic has been sampled)
+    let area = match snapshot.traffic {
+        Some(ref traffic) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(TRAFFIC_PANEL_HEIGHT)])
+                .split(area);
+            render_traffic(f, chunks[1], snapshot, traffic, t);
+            chunks[0]
+        }
+        None => area,
+    };
+
     let mut lines = vec![];
 
     // Surge status
@@ -30,6 +164,10 @@ pub fn render(f: &mut Frame, area: Rect, snapshot: &AppSnapshot, t: &'static dyn
             Style::default().add_modifier(Modifier::BOLD),
         ),
         Span::styled(surge_status_text, Style::default().fg(surge_status_color)),
+        Span::raw("  ["),
+        Span::styled("e", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.action_engine_control()),
     ]));
 
     // HTTP API status
@@ -73,61 +211,79 @@ pub fn render(f: &mut Frame, area: Rect, snapshot: &AppSnapshot, t: &'static dyn
         ]));
     }
 
-    // MITM status (switchable)
-    if snapshot.http_api_available {
-        if let Some(mitm_enabled) = snapshot.mitm_enabled {
-            let status_text = if mitm_enabled {
-                t.status_enabled()
-            } else {
-                t.status_disabled()
-            };
-            let status_color = if mitm_enabled {
-                Color::Green
-            } else {
-                Color::Gray
-            };
+    // Global policy (selectable)
+    if let Some(ref policy) = snapshot.global_policy {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", t.overview_global_policy()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(policy.as_str(), Style::default().fg(Color::Cyan)),
+            Span::raw("  ["),
+            Span::styled("p", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_select()),
+        ]));
+    }
+
+    // Modules (enabled / available)
+    if snapshot.supports(Operation::Modules) && !snapshot.modules.is_empty() {
+        let enabled: Vec<&str> = snapshot
+            .modules
+            .iter()
+            .filter(|m| m.enabled)
+            .map(|m| m.name.as_str())
+            .collect();
 
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("{}: ", t.feature_mitm()),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(status_text, Style::default().fg(status_color)),
-                Span::raw("  ["),
-                Span::styled("i", Style::default().fg(Color::Yellow)),
-                Span::raw("]"),
-                Span::raw(t.action_toggle()),
-            ]));
+        let mut spans = vec![
+            Span::styled(
+                format!("{}: ", t.overview_modules()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                t.overview_modules_enabled(enabled.len(), snapshot.modules.len()),
+                Style::default().fg(Color::Cyan),
+            ),
+        ];
+        if !enabled.is_empty() {
+            spans.push(Span::styled(
+                format!("  ({})", enabled.join(", ")),
+                Style::default().fg(Color::Green),
+            ));
         }
+        spans.extend([
+            Span::raw("  ["),
+            Span::styled("6", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.view_modules()),
+        ]);
+        lines.push(Line::from(spans));
+    }
 
-        // Capture status (switchable)
-        if let Some(capture_enabled) = snapshot.capture_enabled {
-            let status_text = if capture_enabled {
-                t.status_enabled()
-            } else {
-                t.status_disabled()
-            };
-            let status_color = if capture_enabled {
-                Color::Green
-            } else {
-                Color::Gray
-            };
+    lines.push(Line::from("")); // blank line
 
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("{}: ", t.feature_capture()),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(status_text, Style::default().fg(status_color)),
-                Span::raw("  ["),
-                Span::styled("c", Style::default().fg(Color::Yellow)),
-                Span::raw("]"),
-                Span::raw(t.action_toggle()),
-            ]));
+    // Feature toggles (navigable, Enter toggles the selected one)
+    if snapshot.supports(Operation::Features) {
+        lines.push(Line::from(vec![Span::styled(
+            t.overview_features(),
+            Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]));
+        for (i, feature) in Feature::ALL.iter().enumerate() {
+            lines.push(feature_line(
+                *feature,
+                snapshot.feature(*feature),
+                i == selected_index,
+                t,
+            ));
         }
+        lines.push(Line::from(""));
     }
 
-    lines.push(Line::from("")); // blank line
+    // MITM CA certificate
+    if let Some(ref ca) = snapshot.mitm_ca {
+        lines.extend(ca_lines(ca, t));
+        lines.push(Line::from(""));
+    }
 
     // Statistics
     lines.push(Line::from(vec![Span::styled(
@@ -170,3 +326,155 @@ pub fn render(f: &mut Frame, area: Rect, snapshot: &AppSnapshot, t: &'static dyn
 
     f.render_widget(paragraph, area);
 }
+
+fn render_traffic(
+    f: &mut Frame,
+    area: Rect,
+    snapshot: &AppSnapshot,
+    traffic: &TrafficStats,
+    t: &'static dyn Translate,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(t.overview_traffic());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Split inner area: speed sparklines | per-interface totals
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(inner);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(columns[0]);
+
+    let upload = snapshot.traffic_history.upload();
+    let download = snapshot.traffic_history.download();
+    let speeds = [
+        (
+            "↑",
+            t.traffic_upload(),
+            traffic.upload_speed,
+            &upload,
+            Color::Magenta,
+        ),
+        (
+            "↓",
+            t.traffic_download(),
+            traffic.download_speed,
+            &download,
+            Color::Green,
+        ),
+    ];
+
+    for (i, (arrow, label, speed, history, color)) in speeds.into_iter().enumerate() {
+        let peak = history.iter().max().copied().unwrap_or(0);
+        let label_line = Line::from(vec![
+            Span::styled(
+                format!("{} {}: ", arrow, label),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}/s", format_bytes(speed)),
+                Style::default().fg(color),
+            ),
+            Span::styled(
+                format!("  ({} {}/s)", t.traffic_peak(), format_bytes(peak)),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        f.render_widget(Paragraph::new(label_line), rows[i * 2]);
+
+        // Newest samples on the right
+        let width = rows[i * 2 + 1].width as usize;
+        let data = &history[history.len().saturating_sub(width)..];
+        let sparkline = Sparkline::default()
+            .data(data)
+            .style(Style::default().fg(color));
+        f.render_widget(sparkline, rows[i * 2 + 1]);
+    }
+
+    render_traffic_totals(f, columns[1], traffic, t);
+}
+
+fn render_traffic_totals(
+    f: &mut Frame,
+    area: Rect,
+    traffic: &TrafficStats,
+    t: &'static dyn Translate,
+) {
+    let mut lines = vec![Line::from(vec![Span::styled(
+        t.traffic_totals(),
+        Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+    )])];
+
+    if traffic.interfaces.is_empty() {
+        // Estimated from active connections: only their current totals are known
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {}  ", t.traffic_active_connections())),
+            Span::styled(
+                format!("↑ {}", format_bytes(traffic.upload)),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("↓ {}", format_bytes(traffic.download)),
+                Style::default().fg(Color::Green),
+            ),
+        ]));
+        lines.push(Line::from(Span::styled(
+            t.traffic_estimated(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        let name_width = traffic
+            .interfaces
+            .keys()
+            .map(|n| n.len())
+            .max()
+            .unwrap_or(0);
+        for (name, counters) in &traffic.interfaces {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}  ", name, width = name_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("↑ {}", format_bytes(counters.out_bytes)),
+                    Style::default().fg(Color::Magenta),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    format!("↓ {}", format_bytes(counters.in_bytes)),
+                    Style::default().fg(Color::Green),
+                ),
+            ]));
+        }
+    }
+
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+/// Human-readable byte count (1024-based)
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
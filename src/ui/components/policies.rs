@@ -1,3 +1,4 @@
+use crate::domain::backend::Operation;
 /// Policies component - displays policies and policy groups
 use crate::domain::entities::AppSnapshot;
 use crate::i18n::Translate;
@@ -8,9 +9,9 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
-use std::collections::HashSet;
 use unicode_width::UnicodeWidthStr;
 
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     f: &mut Frame,
     area: Rect,
@@ -54,40 +55,7 @@ pub fn render(
     );
 }
 
-/// Recursively find the final real policy selected in a policy group (not another group)
-///
-/// Example: Proxy → US_Servers → us-bwg-la-dc1-vmess
-/// Returns: Some("us-bwg-la-dc1-vmess")
-fn resolve_final_policy(
-    snapshot: &AppSnapshot,
-    policy_name: &str,
-    visited: &mut HashSet<String>,
-) -> Option<String> {
-    // Prevent circular references
-    if visited.contains(policy_name) || visited.len() > 10 {
-        return None;
-    }
-    visited.insert(policy_name.to_string());
-
-    // Check if this is a policy group
-    if let Some(group) = snapshot
-        .policy_groups
-        .iter()
-        .find(|g| g.name == policy_name)
-    {
-        // It is a group: recursively find its selected policy
-        if let Some(selected) = &group.selected {
-            return resolve_final_policy(snapshot, selected, visited);
-        } else {
-            // Group has no selected policy
-            return None;
-        }
-    }
-
-    // Not a group: this is a real policy
-    Some(policy_name.to_string())
-}
-
+#[allow(clippy::too_many_arguments)]
 fn render_policy_groups(
     f: &mut Frame,
     area: Rect,
@@ -163,9 +131,8 @@ fn render_policy_groups(
 
                 // Recursively find the final real policy (handles nested groups)
                 if let Some(selected_policy_name) = &group.selected {
-                    let mut visited = HashSet::new();
                     if let Some(final_policy_name) =
-                        resolve_final_policy(snapshot, selected_policy_name, &mut visited)
+                        snapshot.resolve_final_policy(selected_policy_name)
                     {
                         // Look up test results for the final resolved policy
                         if let Some(policy_detail) = snapshot
@@ -238,7 +205,7 @@ fn render_policy_groups(
             Span::raw(" "),
         ])
     } else {
-        Line::from(vec![
+        let mut spans = vec![
             Span::raw(" "),
             Span::raw(t.policy_group_title()),
             Span::raw(" ["),
@@ -249,16 +216,21 @@ fn render_policy_groups(
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
             Span::raw("]"),
             Span::raw(t.action_enter()),
-            Span::raw(" ["),
-            Span::styled("t", Style::default().fg(Color::Yellow)),
-            Span::raw("]"),
-            Span::raw(t.action_test()),
+        ];
+        if snapshot.supports(Operation::TestPolicyLatency) {
+            spans.push(Span::raw(" ["));
+            spans.push(Span::styled("t", Style::default().fg(Color::Yellow)));
+            spans.push(Span::raw("]"));
+            spans.push(Span::raw(t.action_test()));
+        }
+        spans.extend([
             Span::raw(" ["),
             Span::styled("/", Style::default().fg(Color::Yellow)),
             Span::raw("]"),
             Span::raw(t.action_search()),
             Span::raw(" "),
-        ])
+        ]);
+        Line::from(spans)
     };
 
     let highlight_style = if in_detail_mode {
@@ -284,6 +256,7 @@ fn render_policy_groups(
     f.render_stateful_widget(list, area, &mut state);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_policy_group_policies(
     f: &mut Frame,
     area: Rect,
@@ -375,10 +348,9 @@ fn render_policy_group_policies(
             // Look up latency data (supports nested policy groups)
             let (status_text, status_color) = {
                 // First try to resolve the final policy recursively (handles nesting)
-                let mut visited = HashSet::new();
-                let final_policy_name =
-                    resolve_final_policy(snapshot, &policy_item.name, &mut visited)
-                        .unwrap_or_else(|| policy_item.name.clone());
+                let final_policy_name = snapshot
+                    .resolve_final_policy(&policy_item.name)
+                    .unwrap_or_else(|| policy_item.name.clone());
 
                 // Look up test results for the final resolved policy
                 if let Some(detail) = snapshot
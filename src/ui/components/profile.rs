@@ -0,0 +1,299 @@
+/// Profile component - profile text with section jump list
+use crate::domain::profile::{self, ProfileSection, MASK};
+use crate::i18n::Translate;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    text: Option<&str>,
+    selected_section: usize,
+    scroll_offset: isize,
+    search_query: &str,
+    search_mode: bool,
+    effective: bool,
+    show_sensitive: bool,
+    t: &'static dyn Translate,
+) {
+    // Split area: section list | profile text
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .split(area);
+
+    let Some(text) = text else {
+        let paragraph = Paragraph::new(Span::styled(
+            t.profile_not_loaded(),
+            Style::default().fg(Color::DarkGray),
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.view_profile()),
+        );
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let lines = shown_lines(text, search_query);
+    let sections = profile::sections(text);
+    render_section_list(f, chunks[0], &sections, &lines, selected_section, t);
+
+    // Scroll to the first shown line of the selected section
+    let target = section_start(&sections, &lines, selected_section) as isize;
+    let max_scroll = lines.len().saturating_sub(1) as isize;
+    let scroll = (target + scroll_offset).clamp(0, max_scroll.max(0)) as usize;
+
+    render_profile_text(
+        f,
+        chunks[1],
+        &lines,
+        scroll,
+        search_query,
+        search_mode,
+        effective,
+        show_sensitive,
+        t,
+    );
+}
+
+/// Scroll offsets that keep the view inside the text, relative to the start
+/// of the selected section
+pub fn scroll_range(text: &str, selected_section: usize, search_query: &str) -> (isize, isize) {
+    let lines = shown_lines(text, search_query);
+    let sections = profile::sections(text);
+    let target = section_start(&sections, &lines, selected_section) as isize;
+    let max_scroll = lines.len().saturating_sub(1) as isize;
+    (-target, (max_scroll - target).max(-target))
+}
+
+/// Lines matching the search query (keeping line numbers)
+fn shown_lines<'a>(text: &'a str, search_query: &str) -> Vec<(usize, &'a str)> {
+    let query_lower = search_query.to_lowercase();
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| query_lower.is_empty() || line.to_lowercase().contains(&query_lower))
+        .collect()
+}
+
+/// Index in `lines` of the first shown line of the selected section
+fn section_start(
+    sections: &[ProfileSection],
+    lines: &[(usize, &str)],
+    selected_section: usize,
+) -> usize {
+    sections
+        .get(selected_section)
+        .map(|s| {
+            lines
+                .iter()
+                .position(|(n, _)| *n >= s.line)
+                .unwrap_or(lines.len())
+        })
+        .unwrap_or(0)
+}
+
+fn render_section_list(
+    f: &mut Frame,
+    area: Rect,
+    sections: &[ProfileSection],
+    lines: &[(usize, &str)],
+    selected: usize,
+    t: &'static dyn Translate,
+) {
+    let items: Vec<ListItem> = if sections.is_empty() {
+        vec![ListItem::new(Span::styled(
+            t.profile_no_sections(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        sections
+            .iter()
+            .enumerate()
+            .map(|(i, section)| {
+                // Sections without a matching line are dimmed while searching
+                let end = sections.get(i + 1).map_or(usize::MAX, |next| next.line);
+                let has_lines = lines.iter().any(|(n, _)| *n >= section.line && *n < end);
+                let color = if has_lines {
+                    Color::Yellow
+                } else {
+                    Color::DarkGray
+                };
+                ListItem::new(Span::styled(
+                    format!("[{}]", section.name),
+                    Style::default().fg(color),
+                ))
+            })
+            .collect()
+    };
+
+    let mut state = ListState::default();
+    if !sections.is_empty() {
+        state.select(Some(selected));
+    }
+
+    let title = Line::from(vec![
+        Span::raw(" "),
+        Span::raw(t.profile_sections_title()),
+        Span::raw(" ["),
+        Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+        Span::raw("] "),
+    ]);
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_profile_text(
+    f: &mut Frame,
+    area: Rect,
+    lines: &[(usize, &str)],
+    scroll: usize,
+    search_query: &str,
+    search_mode: bool,
+    effective: bool,
+    show_sensitive: bool,
+    t: &'static dyn Translate,
+) {
+    let variant = if effective {
+        t.profile_effective()
+    } else {
+        t.profile_original()
+    };
+
+    let mut title = vec![Span::raw(" "), Span::raw(variant)];
+    if search_mode {
+        title.extend([
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("█]"),
+        ]);
+    } else if !search_query.is_empty() {
+        title.extend([
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("]"),
+        ]);
+    } else {
+        // Show shortcut key hints (btop style)
+        title.extend([
+            Span::raw(" ["),
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_search()),
+            Span::raw(" ["),
+            Span::styled("e", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(if effective {
+                t.profile_original()
+            } else {
+                t.profile_effective()
+            }),
+            Span::raw(" ["),
+            Span::styled("v", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(if show_sensitive {
+                t.action_mask()
+            } else {
+                t.action_reveal()
+            }),
+        ]);
+    }
+    title.push(Span::raw(" "));
+
+    let number_width = lines.last().map_or(1, |(n, _)| (n + 1).to_string().len());
+    let text: Vec<Line> = if lines.is_empty() {
+        vec![Line::from(Span::styled(
+            t.profile_no_matches(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        lines
+            .iter()
+            .skip(scroll)
+            .take(area.height as usize)
+            .map(|(n, line)| {
+                let mut spans = vec![Span::styled(
+                    format!("{:>width$} ", n + 1, width = number_width),
+                    Style::default().fg(Color::DarkGray),
+                )];
+                spans.extend(highlight_line(line));
+                Line::from(spans)
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(Line::from(title)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+/// INI-style highlighting of one profile line
+fn highlight_line(line: &str) -> Vec<Span<'_>> {
+    if profile::section_header(line).is_some() {
+        return vec![Span::styled(
+            line,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )];
+    }
+    if profile::is_comment(line) {
+        return vec![Span::styled(line, Style::default().fg(Color::DarkGray))];
+    }
+
+    match line.split_once('=') {
+        // key = value
+        Some((key, value)) if !key.contains(',') => {
+            let mut spans = vec![
+                Span::styled(key, Style::default().fg(Color::Cyan)),
+                Span::raw("="),
+            ];
+            spans.extend(highlight_value(value));
+            spans
+        }
+        // Rule-like entry: TYPE,value,policy
+        _ => match line.split_once(',') {
+            Some((kind, rest)) => vec![
+                Span::styled(kind, Style::default().fg(Color::Green)),
+                Span::raw(","),
+                Span::raw(rest),
+            ],
+            None => vec![Span::raw(line)],
+        },
+    }
+}
+
+/// Value with masked parts highlighted
+fn highlight_value(value: &str) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    let mut parts = value.split(MASK).peekable();
+    while let Some(part) = parts.next() {
+        if !part.is_empty() {
+            spans.push(Span::raw(part));
+        }
+        if parts.peek().is_some() {
+            spans.push(Span::styled(MASK, Style::default().fg(Color::Magenta)));
+        }
+    }
+    spans
+}
@@ -9,6 +9,7 @@ use ratatui::{
     Frame,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     f: &mut Frame,
     area: Rect,
@@ -80,6 +81,7 @@ pub fn render(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_request_list(
     f: &mut Frame,
     area: Rect,
@@ -544,6 +546,7 @@ fn format_note(note: &str, _t: &'static dyn Translate) -> Vec<Span<'static>> {
 }
 
 /// Render grouped view (requests grouped by application)
+#[allow(clippy::too_many_arguments)]
 fn render_grouped_view(
     f: &mut Frame,
     area: Rect,
@@ -565,7 +568,7 @@ fn render_grouped_view(
             .as_ref()
             .map(|p| {
                 // Extract app name (strip path prefix)
-                p.split('/').last().unwrap_or(p).to_string()
+                p.split('/').next_back().unwrap_or(p).to_string()
             })
             .unwrap_or_else(|| "Unknown".to_string());
         app_groups.entry(app_name).or_default().push(req);
@@ -603,12 +606,7 @@ fn render_grouped_view(
     // Get selected app and its requests
     if app_selected < apps.len() {
         let (selected_app_name, _) = &apps[app_selected];
-        let app_requests: Vec<_> = app_groups
-            .get(selected_app_name)
-            .unwrap()
-            .iter()
-            .copied()
-            .collect();
+        let app_requests: Vec<_> = app_groups.get(selected_app_name).unwrap().to_vec();
 
         // Render request list for this app (filtering happens internally)
         render_app_request_list(
@@ -726,6 +724,7 @@ fn render_app_list(
 }
 
 /// Render request list for an application
+#[allow(clippy::too_many_arguments)]
 fn render_app_request_list(
     f: &mut Frame,
     area: Rect,
@@ -803,7 +802,7 @@ fn render_app_request_list(
 
     // Filter requests by search query
     let filtered_requests: Vec<_> = if search_query.is_empty() {
-        requests.iter().copied().collect()
+        requests.to_vec()
     } else {
         let query_lower = search_query.to_lowercase();
         requests
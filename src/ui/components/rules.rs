@@ -0,0 +1,456 @@
+/// Rules component - ordered rule list with hit counts from recent requests
+use crate::domain::models::{Request, Rule};
+use crate::domain::rule_match::SUPPORTED_RULE_TYPES;
+use crate::i18n::Translate;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+/// Share of recent requests above which FINAL is highlighted
+const FINAL_SHARE_WARNING: f64 = 0.5;
+
+/// Rule match simulator result
+#[derive(Debug, Clone)]
+pub struct RuleSimulation {
+    /// What was typed
+    pub input: String,
+    /// Rules came from the profile's `[Rule]` section, not the rule list
+    pub from_profile: bool,
+    /// First matching rule and its position
+    pub matched: Option<(usize, Rule)>,
+    /// Final policy after following policy group selections
+    pub resolved_policy: Option<String>,
+    /// Unsupported rule types skipped before the match
+    pub skipped_types: Vec<String>,
+}
+
+/// Recent requests matched by one rule
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleHits<'a> {
+    pub count: usize,
+    /// Host of the newest matching request
+    pub last_host: Option<&'a str>,
+    last_start: f64,
+}
+
+/// Hit count per rule (same order as `rules`)
+///
+/// A request counts for the first rule whose type and value match its `rule`
+/// field, the one Surge would have stopped at.
+pub fn hits<'a>(rules: &[Rule], requests: &'a [Request]) -> Vec<RuleHits<'a>> {
+    let keys: Vec<String> = rules.iter().map(Rule::request_key).collect();
+    let mut hits = vec![RuleHits::default(); rules.len()];
+
+    for request in requests {
+        let Some(ref rule) = request.rule else {
+            continue;
+        };
+        let Some(index) = keys.iter().position(|k| k.eq_ignore_ascii_case(rule)) else {
+            continue;
+        };
+        let hit = &mut hits[index];
+        hit.count += 1;
+        let start = request.start_date.unwrap_or(0.0);
+        if hit.last_host.is_none() || start >= hit.last_start {
+            hit.last_start = start;
+            hit.last_host = request.remote_host.as_deref().or(request.url.as_deref());
+        }
+    }
+    hits
+}
+
+/// Rules matching the search query, with their position in the full list
+pub fn filtered<'a>(rules: &'a [Rule], search_query: &str) -> Vec<(usize, &'a Rule)> {
+    let query_lower = search_query.to_lowercase();
+    rules
+        .iter()
+        .enumerate()
+        .filter(|(_, rule)| {
+            query_lower.is_empty()
+                || rule.rule_type.to_lowercase().contains(&query_lower)
+                || rule.policy.to_lowercase().contains(&query_lower)
+                || rule
+                    .value
+                    .as_ref()
+                    .is_some_and(|v| v.to_lowercase().contains(&query_lower))
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    rules: &[Rule],
+    requests: &[Request],
+    selected: usize,
+    search_query: &str,
+    search_mode: bool,
+    supported: bool,
+    match_input: Option<&str>,
+    simulation: Option<&RuleSimulation>,
+    t: &'static dyn Translate,
+) {
+    let hits = hits(rules, requests);
+    let filtered = filtered(rules, search_query);
+
+    // Split area: rule list | detail panel
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(area);
+
+    render_rule_list(
+        f,
+        chunks[0],
+        &filtered,
+        &hits,
+        selected,
+        search_query,
+        search_mode,
+        supported,
+        t,
+    );
+    // Right side: simulator | rule detail
+    let side = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .split(chunks[1]);
+
+    render_simulator(f, side[0], match_input, simulation, t);
+    render_rule_detail(f, side[1], rules, &filtered, &hits, requests, selected, t);
+}
+
+fn render_simulator(
+    f: &mut Frame,
+    area: Rect,
+    match_input: Option<&str>,
+    simulation: Option<&RuleSimulation>,
+    t: &'static dyn Translate,
+) {
+    let label = |text: &str| {
+        Span::styled(
+            format!("{}: ", text),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    };
+
+    let mut lines = vec![];
+
+    if let Some(input) = match_input {
+        lines.push(Line::from(vec![
+            label(t.rules_match_input()),
+            Span::raw(format!("{}█", input)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            t.rules_match_hint(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else if let Some(simulation) = simulation {
+        lines.push(Line::from(vec![
+            label(t.rules_match_input()),
+            Span::styled(simulation.input.as_str(), Style::default().fg(Color::Cyan)),
+        ]));
+        match simulation.matched {
+            Some((index, ref rule)) => {
+                let mut rule_text = format!("#{} {}", index + 1, rule.rule_type);
+                if let Some(ref value) = rule.value {
+                    rule_text.push_str(&format!(" {}", value));
+                }
+                lines.push(Line::from(vec![
+                    label(t.rules_match_rule()),
+                    Span::styled(rule_text, Style::default().fg(Color::Yellow)),
+                ]));
+
+                let mut policy = vec![
+                    label(t.rules_label_policy()),
+                    Span::styled(rule.policy.as_str(), Style::default().fg(Color::Green)),
+                ];
+                if let Some(ref resolved) = simulation.resolved_policy {
+                    if resolved != &rule.policy {
+                        policy.push(Span::raw(" → "));
+                        policy.push(Span::styled(
+                            resolved.as_str(),
+                            Style::default()
+                                .fg(Color::Green)
+                                .add_modifier(Modifier::BOLD),
+                        ));
+                    }
+                }
+                lines.push(Line::from(policy));
+            }
+            None => lines.push(Line::from(Span::styled(
+                t.rules_match_none(),
+                Style::default().fg(Color::Red),
+            ))),
+        }
+        if simulation.from_profile {
+            lines.push(Line::from(Span::styled(
+                t.rules_match_from_profile(),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        if !simulation.skipped_types.is_empty() {
+            lines.push(Line::from(Span::styled(
+                t.rules_match_skipped(&simulation.skipped_types.join(", ")),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+    } else {
+        lines.push(Line::from(Span::styled(
+            t.rules_match_prompt(),
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.push(Line::from(Span::styled(
+            t.rules_match_supported(&SUPPORTED_RULE_TYPES.join(", ")),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.rules_match_title()),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_rule_list(
+    f: &mut Frame,
+    area: Rect,
+    rules: &[(usize, &Rule)],
+    hits: &[RuleHits],
+    selected: usize,
+    search_query: &str,
+    search_mode: bool,
+    supported: bool,
+    t: &'static dyn Translate,
+) {
+    let title = if search_mode {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.rules_list_title()),
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("█] "),
+        ])
+    } else if !search_query.is_empty() {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.rules_list_title()),
+            Span::raw(" [Search: "),
+            Span::raw(search_query),
+            Span::raw("] "),
+        ])
+    } else {
+        // Show shortcut key hints (btop style)
+        Line::from(vec![
+            Span::raw(" "),
+            Span::raw(t.rules_list_title()),
+            Span::raw(" ["),
+            Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_select()),
+            Span::raw(" ["),
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_search()),
+            Span::raw(" ["),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw("]"),
+            Span::raw(t.action_match()),
+            Span::raw(" "),
+        ])
+    };
+
+    let items: Vec<ListItem> = if rules.is_empty() {
+        let message = if supported {
+            t.rules_no_rules()
+        } else {
+            t.rules_unsupported()
+        };
+        vec![ListItem::new(Span::styled(
+            message,
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        rules
+            .iter()
+            .map(|&(index, rule)| {
+                let hit = hits[index];
+                // Rules without hits are dimmed so dead rules stand out
+                let (type_color, value_color) = if hit.count == 0 {
+                    (Color::DarkGray, Color::DarkGray)
+                } else {
+                    (Color::Yellow, Color::Cyan)
+                };
+                let value = rule.value.as_deref().unwrap_or("");
+                let value = if value.chars().count() > 32 {
+                    format!("{}...", value.chars().take(29).collect::<String>())
+                } else {
+                    value.to_string()
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>3} ", index + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:<16}", rule.rule_type),
+                        Style::default().fg(type_color),
+                    ),
+                    Span::styled(format!("{:<32}", value), Style::default().fg(value_color)),
+                    Span::raw(" → "),
+                    Span::styled(
+                        format!("{:<12}", rule.policy),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        format!("{:>5}", hit.count),
+                        Style::default().fg(if hit.count == 0 {
+                            Color::DarkGray
+                        } else {
+                            Color::White
+                        }),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let mut state = ListState::default();
+    if !rules.is_empty() {
+        state.select(Some(selected));
+    }
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_rule_detail(
+    f: &mut Frame,
+    area: Rect,
+    all_rules: &[Rule],
+    rules: &[(usize, &Rule)],
+    hits: &[RuleHits],
+    requests: &[Request],
+    selected: usize,
+    t: &'static dyn Translate,
+) {
+    let label = |text: &str| {
+        Span::styled(
+            format!("{}: ", text),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    };
+    let total = requests.len();
+    let share = |count: usize| {
+        if total == 0 {
+            0.0
+        } else {
+            count as f64 / total as f64
+        }
+    };
+
+    let mut lines = vec![];
+
+    if let Some(&(index, rule)) = rules.get(selected) {
+        let hit = hits[index];
+
+        lines.push(Line::from(vec![
+            label(t.rules_label_type()),
+            Span::styled(&rule.rule_type, Style::default().fg(Color::Yellow)),
+        ]));
+        if let Some(ref value) = rule.value {
+            lines.push(Line::from(vec![
+                label(t.rules_label_value()),
+                Span::styled(value.as_str(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+        lines.push(Line::from(vec![
+            label(t.rules_label_policy()),
+            Span::styled(&rule.policy, Style::default().fg(Color::Green)),
+        ]));
+        if !rule.options.is_empty() {
+            lines.push(Line::from(vec![
+                label(t.rules_label_options()),
+                Span::raw(rule.options.join(", ")),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![
+            label(t.rules_label_hits()),
+            Span::raw(t.rules_hits_value(hit.count, share(hit.count) * 100.0)),
+        ]));
+        lines.push(Line::from(vec![
+            label(t.rules_label_last_host()),
+            match hit.last_host {
+                Some(host) => Span::styled(host, Style::default().fg(Color::Cyan)),
+                None => Span::styled(
+                    t.rules_never_matched(),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            },
+        ]));
+    }
+
+    // Summary over all rules (not just the search results)
+    if !all_rules.is_empty() {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        let dead = hits.iter().filter(|h| h.count == 0).count();
+        lines.push(Line::from(Span::styled(
+            t.rules_summary(all_rules.len(), dead, total),
+            Style::default().fg(Color::Gray),
+        )));
+
+        let final_hits: usize = all_rules
+            .iter()
+            .zip(hits)
+            .filter(|(rule, _)| rule.rule_type == "FINAL")
+            .map(|(_, hit)| hit.count)
+            .sum();
+        let final_share = share(final_hits);
+        if total > 0 {
+            let color = if final_share >= FINAL_SHARE_WARNING {
+                Color::Red
+            } else {
+                Color::Gray
+            };
+            lines.push(Line::from(Span::styled(
+                t.rules_final_share(final_share * 100.0),
+                Style::default().fg(color),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.rules_detail_title()),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
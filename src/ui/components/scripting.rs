@@ -0,0 +1,245 @@
+/// Scripting component - script console for `/v1/scripting/evaluate`
+use crate::domain::models::{ScriptEvaluation, ScriptMockType};
+use crate::i18n::Translate;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default evaluation timeout (seconds)
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+/// Longest evaluation timeout selectable with +/- (seconds)
+pub const MAX_TIMEOUT_SECS: u64 = 60;
+
+/// Script console state
+pub struct ScriptConsole {
+    /// Script file as typed (`~/` is expanded when loading)
+    pub path: String,
+    /// Path being edited (shown instead of `path` when Some)
+    pub path_input: Option<String>,
+    pub mock_type: ScriptMockType,
+    pub timeout_secs: u64,
+    /// Whether an evaluation is in flight
+    pub running: bool,
+    /// Last finished run
+    pub last_run: Option<ScriptRun>,
+}
+
+impl ScriptConsole {
+    pub fn new() -> Self {
+        Self {
+            path: String::new(),
+            path_input: None,
+            mock_type: ScriptMockType::Generic,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            running: false,
+            last_run: None,
+        }
+    }
+
+    /// File to read, with a leading `~/` expanded
+    pub fn script_path(&self) -> PathBuf {
+        match self.path.strip_prefix("~/") {
+            Some(rest) => {
+                let home = std::env::var("HOME").unwrap_or_default();
+                PathBuf::from(format!("{}/{}", home, rest))
+            }
+            None => PathBuf::from(&self.path),
+        }
+    }
+}
+
+impl Default for ScriptConsole {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Outcome of one script run
+pub struct ScriptRun {
+    /// Evaluation, or why the script could not be read or evaluated
+    pub outcome: Result<ScriptEvaluation, String>,
+    pub elapsed: Duration,
+    pub finished_at: DateTime<Local>,
+}
+
+pub fn render(f: &mut Frame, area: Rect, console: &ScriptConsole, t: &'static dyn Translate) {
+    // Split area: settings | result and logs
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(0)])
+        .split(area);
+
+    render_settings(f, chunks[0], console, t);
+
+    let output = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    render_result(f, output[0], console, t);
+    render_logs(f, output[1], console, t);
+}
+
+fn render_settings(f: &mut Frame, area: Rect, console: &ScriptConsole, t: &'static dyn Translate) {
+    // Show shortcut key hints (btop style)
+    let title = Line::from(vec![
+        Span::raw(" "),
+        Span::raw(t.script_settings_title()),
+        Span::raw(" ["),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.action_run()),
+        Span::raw(" ["),
+        Span::styled("l", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.script_label_file()),
+        Span::raw(" ["),
+        Span::styled("x", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.script_label_mock()),
+        Span::raw(" ["),
+        Span::styled("+/-", Style::default().fg(Color::Yellow)),
+        Span::raw("]"),
+        Span::raw(t.script_label_timeout()),
+        Span::raw(" "),
+    ]);
+
+    let label = |text: &str| {
+        Span::styled(
+            format!("{}: ", text),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    };
+
+    let file = match (&console.path_input, console.path.is_empty()) {
+        (Some(input), _) => Span::styled(format!("{}█", input), Style::default().fg(Color::Yellow)),
+        (None, true) => Span::styled(t.script_no_file(), Style::default().fg(Color::DarkGray)),
+        (None, false) => Span::styled(console.path.as_str(), Style::default().fg(Color::Cyan)),
+    };
+
+    let status = if console.running {
+        Span::styled(
+            t.script_status_running(),
+            Style::default().fg(Color::Yellow),
+        )
+    } else {
+        match console.last_run {
+            Some(ref run) => {
+                let color = match run.outcome {
+                    Ok(ref evaluation) if evaluation.exception.is_none() => Color::Green,
+                    _ => Color::Red,
+                };
+                Span::styled(
+                    t.script_status_finished(
+                        run.elapsed.as_millis(),
+                        &run.finished_at.format("%H:%M:%S").to_string(),
+                    ),
+                    Style::default().fg(color),
+                )
+            }
+            None => Span::styled(t.script_status_idle(), Style::default().fg(Color::DarkGray)),
+        }
+    };
+
+    let lines = vec![
+        Line::from(vec![label(t.script_label_file()), file]),
+        Line::from(vec![
+            label(t.script_label_mock()),
+            Span::styled(console.mock_type.as_str(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(vec![
+            label(t.script_label_timeout()),
+            Span::raw(format!("{}s", console.timeout_secs)),
+        ]),
+        Line::from(vec![label(t.script_label_status()), status]),
+    ];
+
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+fn render_result(f: &mut Frame, area: Rect, console: &ScriptConsole, t: &'static dyn Translate) {
+    let mut lines = vec![];
+
+    match console.last_run.as_ref().map(|run| &run.outcome) {
+        Some(Ok(evaluation)) => {
+            if let Some(ref exception) = evaluation.exception {
+                lines.push(Line::from(Span::styled(
+                    format!("{}: ", t.script_exception()),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )));
+                lines.extend(
+                    exception.lines().map(|line| {
+                        Line::from(Span::styled(line, Style::default().fg(Color::Red)))
+                    }),
+                );
+                lines.push(Line::from(""));
+            }
+            let result = serde_json::to_string_pretty(&evaluation.result)
+                .unwrap_or_else(|_| evaluation.result.to_string());
+            lines.extend(result.lines().map(|line| Line::from(line.to_string())));
+        }
+        Some(Err(error)) => {
+            lines.push(Line::from(Span::styled(
+                format!("{}: ", t.script_error()),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(Span::styled(
+                error.as_str(),
+                Style::default().fg(Color::Red),
+            )));
+        }
+        None => lines.push(Line::from(Span::styled(
+            t.script_status_idle(),
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t.script_result_title()),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_logs(f: &mut Frame, area: Rect, console: &ScriptConsole, t: &'static dyn Translate) {
+    let logs = match console.last_run.as_ref().map(|run| &run.outcome) {
+        Some(Ok(evaluation)) => evaluation.log_lines(),
+        _ => Vec::new(),
+    };
+
+    let lines: Vec<Line> = if logs.is_empty() {
+        vec![Line::from(Span::styled(
+            t.script_no_logs(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        // Keep the end of long output in view
+        let height = area.height.saturating_sub(2) as usize;
+        logs[logs.len().saturating_sub(height)..]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(t.script_logs_title()),
+    );
+
+    f.render_widget(paragraph, area);
+}
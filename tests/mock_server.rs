@@ -0,0 +1,221 @@
+/// `SurgeClient` against the mock HTTP API
+///
+/// Needs the `mock` feature, which the dev-dependency on this crate enables for tests
+use surge_tui::domain::backend::Operation;
+use surge_tui::domain::models::OutboundMode;
+use surge_tui::infrastructure::SurgeHttpClient;
+use surge_tui::mock::{MockServer, MockState};
+use surge_tui::{Config, SurgeClient};
+
+const KEY: &str = "test-key";
+
+async fn start_mock() -> MockServer {
+    MockServer::start("127.0.0.1:0".parse().unwrap(), KEY, MockState::default())
+        .await
+        .unwrap()
+}
+
+fn client_for(server: &MockServer, key: &str) -> SurgeClient {
+    let mut config = Config::default();
+    config.surge.http_api_host = server.addr().ip().to_string();
+    config.surge.http_api_port = server.addr().port();
+    config.surge.http_api_key = key.to_string();
+    // Keep surge-cli and pgrep away from the machine running the tests
+    config.surge.remote = Some(true);
+    SurgeClient::new(config).unwrap()
+}
+
+#[tokio::test]
+async fn snapshot_reads_fixture() {
+    let server = start_mock().await;
+    let client = client_for(&server, KEY);
+
+    let snapshot = client.get_snapshot().await;
+
+    assert!(snapshot.surge_running);
+    assert!(snapshot.http_api_available);
+    assert_eq!(snapshot.outbound_mode, Some(OutboundMode::Rule));
+    assert_eq!(snapshot.policy_groups.len(), 3);
+    let proxy = snapshot
+        .policy_groups
+        .iter()
+        .find(|g| g.name == "Proxy")
+        .unwrap();
+    assert_eq!(proxy.selected.as_deref(), Some("Auto"));
+    assert_eq!(snapshot.active_connections.len(), 2);
+    assert_eq!(snapshot.rules.len(), 7);
+}
+
+#[tokio::test]
+async fn actions_change_mock_state() {
+    let server = start_mock().await;
+    let client = client_for(&server, KEY);
+
+    client.select_policy_group("Proxy", "JP-01").await.unwrap();
+    client.kill_connection(201).await.unwrap();
+    client
+        .set_outbound_mode(OutboundMode::Direct)
+        .await
+        .unwrap();
+
+    let state = server.state();
+    assert_eq!(
+        state.calls,
+        [
+            "POST /v1/policy_groups/select",
+            "POST /v1/requests/kill",
+            "POST /v1/outbound",
+        ]
+    );
+    assert_eq!(state.selections["Proxy"], "JP-01");
+    assert_eq!(state.active_connections.len(), 1);
+    assert_eq!(state.outbound, "direct");
+}
+
+#[tokio::test]
+async fn changes_made_elsewhere_are_read_back() {
+    let server = start_mock().await;
+    let client = client_for(&server, KEY);
+
+    server.update(|state| state.outbound = "proxy".to_string());
+
+    assert_eq!(
+        client.get_outbound_mode().await.unwrap(),
+        OutboundMode::Proxy
+    );
+}
+
+#[tokio::test]
+async fn latency_test_reports_each_policy() {
+    let server = start_mock().await;
+    let client = client_for(&server, KEY);
+
+    let names = ["HK-01".to_string(), "HK-02".to_string()];
+    let mut results = client
+        .test_policy_latency("Auto", &names)
+        .await
+        .unwrap()
+        .results;
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].alive);
+    assert_eq!(results[0].latency, Some(48));
+    assert!(!results[1].alive);
+    // One request for the whole list
+    assert_eq!(server.state().calls, ["POST /v1/policies/test"]);
+}
+
+#[tokio::test]
+async fn missing_endpoint_is_no_longer_polled() {
+    let server = start_mock().await;
+    // Older Surge versions answer /v1/traffic with 404
+    let traffic = server.state().traffic;
+    server.update(|state| state.traffic = serde_json::Value::Null);
+    let client = client_for(&server, KEY);
+
+    let snapshot = client.get_snapshot().await;
+    assert!(!snapshot.supports(Operation::Traffic));
+
+    // Not requested again: the speed stays an estimate from connections
+    server.update(|state| state.traffic = traffic);
+    let snapshot = client.get_snapshot().await;
+    assert!(!snapshot.supports(Operation::Traffic));
+    assert!(snapshot.traffic.is_some_and(|t| t.interfaces.is_empty()));
+}
+
+#[tokio::test]
+async fn failed_mitm_ca_download_waits_for_retry() {
+    let server = start_mock().await;
+    let ca = server.state().mitm_ca;
+    server.update(|state| state.mitm_ca = "not base64".to_string());
+    let client = client_for(&server, KEY);
+
+    assert!(client.get_snapshot().await.mitm_ca.is_none());
+
+    // The failure is remembered instead of re-requested every refresh
+    server.update(|state| state.mitm_ca = ca);
+    assert!(client.get_snapshot().await.mitm_ca.is_none());
+
+    client.retry_mitm_ca();
+    assert!(client.get_snapshot().await.mitm_ca.is_some());
+}
+
+fn http_client_for(server: &MockServer, key: &str) -> SurgeHttpClient {
+    let addr = server.addr();
+    SurgeHttpClient::new(addr.ip().to_string(), addr.port(), key.to_string(), None).unwrap()
+}
+
+#[tokio::test]
+async fn group_without_selection_has_none() {
+    let server = start_mock().await;
+    let client = http_client_for(&server, KEY);
+
+    let auto = client.get_policy_group_selected("Auto").await.unwrap();
+    let proxy = client.get_policy_group_selected("Proxy").await.unwrap();
+
+    assert_eq!(auto, None);
+    assert_eq!(proxy.as_deref(), Some("Auto"));
+}
+
+#[tokio::test]
+async fn selection_lookup_reports_rejected_key() {
+    let server = start_mock().await;
+    let client = http_client_for(&server, "wrong-key");
+
+    let error = client.get_policy_group_selected("Proxy").await.unwrap_err();
+
+    assert!(error.is_client_error());
+}
+
+#[tokio::test]
+async fn wrong_key_is_rejected() {
+    let server = start_mock().await;
+    let client = client_for(&server, "wrong-key");
+
+    let error = client.get_outbound_mode().await.unwrap_err();
+
+    assert!(error.is_client_error());
+    assert!(server.state().calls.is_empty());
+}
+
+#[tokio::test]
+async fn failed_latency_test_falls_back_to_group_results() {
+    let server = start_mock().await;
+    server.update(|state| {
+        state.failing_policy_tests = vec!["JP-01".to_string()];
+        state.policy_latencies.remove("US-01");
+    });
+    let client = client_for(&server, KEY);
+
+    let names = [
+        "JP-01".to_string(),
+        "NOWHERE".to_string(),
+        "US-01".to_string(),
+    ];
+    let mut results = client
+        .test_policy_latency("Proxy", &names)
+        .await
+        .unwrap()
+        .results;
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].latency, Some(95));
+    // Neither tested nor in any group
+    assert!(!results[1].alive);
+    assert!(!results[2].alive);
+}
+
+#[tokio::test]
+async fn latency_test_fails_without_group_results() {
+    let server = start_mock().await;
+    server.update(|state| {
+        state.failing_policy_tests = vec!["JP-01".to_string()];
+        state.policy_groups.clear();
+    });
+    let client = client_for(&server, KEY);
+
+    let names = ["JP-01".to_string()];
+    assert!(client.test_policy_latency("Proxy", &names).await.is_err());
+}